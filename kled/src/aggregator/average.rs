@@ -0,0 +1,27 @@
+use crate::core;
+
+#[derive(Default)]
+pub struct Average {
+    sum: f64,
+    count: usize,
+}
+
+impl<Item> core::Aggregator<Item> for Average
+where
+    Item: Into<f64>,
+{
+    type Output = f64;
+
+    fn update(&mut self, item: Item) {
+        self.sum += item.into();
+        self.count += 1;
+    }
+
+    fn output(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum / self.count as f64)
+        }
+    }
+}
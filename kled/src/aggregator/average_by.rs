@@ -0,0 +1,30 @@
+use crate::core;
+
+#[derive(new)]
+pub struct AverageBy<ValueFn> {
+    value_fn: ValueFn,
+    #[new(default)]
+    sum: f64,
+    #[new(default)]
+    count: usize,
+}
+
+impl<Item, ValueFn> core::Aggregator<Item> for AverageBy<ValueFn>
+where
+    ValueFn: FnMut(Item) -> f64,
+{
+    type Output = f64;
+
+    fn update(&mut self, item: Item) {
+        self.sum += (self.value_fn)(item);
+        self.count += 1;
+    }
+
+    fn output(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum / self.count as f64)
+        }
+    }
+}
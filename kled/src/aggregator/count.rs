@@ -0,0 +1,18 @@
+use crate::core;
+
+#[derive(Default)]
+pub struct Count {
+    count: usize,
+}
+
+impl<Item> core::Aggregator<Item> for Count {
+    type Output = usize;
+
+    fn update(&mut self, _: Item) {
+        self.count += 1;
+    }
+
+    fn output(&self) -> Option<usize> {
+        Some(self.count)
+    }
+}
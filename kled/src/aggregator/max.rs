@@ -0,0 +1,32 @@
+use crate::core;
+
+pub struct Max<Item> {
+    max: Option<Item>,
+}
+
+impl<Item> Default for Max<Item> {
+    fn default() -> Self {
+        Self { max: None }
+    }
+}
+
+impl<Item> core::Aggregator<Item> for Max<Item>
+where
+    Item: PartialOrd + Clone,
+{
+    type Output = Item;
+
+    fn update(&mut self, item: Item) {
+        let is_max = match self.max {
+            Some(ref max) => item > *max,
+            None => true,
+        };
+        if is_max {
+            self.max = Some(item);
+        }
+    }
+
+    fn output(&self) -> Option<Item> {
+        self.max.clone()
+    }
+}
@@ -0,0 +1,32 @@
+use crate::core;
+
+#[derive(new)]
+pub struct MaxByKey<Item, Key, KeyFn> {
+    key_fn: KeyFn,
+    #[new(default)]
+    max: Option<(Key, Item)>,
+}
+
+impl<Item, Key, KeyFn> core::Aggregator<Item> for MaxByKey<Item, Key, KeyFn>
+where
+    Item: Clone,
+    Key: PartialOrd,
+    KeyFn: FnMut(&Item) -> Key,
+{
+    type Output = Item;
+
+    fn update(&mut self, item: Item) {
+        let key = (self.key_fn)(&item);
+        let is_max = match self.max {
+            Some((ref max, _)) => key > *max,
+            None => true,
+        };
+        if is_max {
+            self.max = Some((key, item));
+        }
+    }
+
+    fn output(&self) -> Option<Item> {
+        self.max.as_ref().map(|(_, item)| item.clone())
+    }
+}
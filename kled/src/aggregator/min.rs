@@ -0,0 +1,32 @@
+use crate::core;
+
+pub struct Min<Item> {
+    min: Option<Item>,
+}
+
+impl<Item> Default for Min<Item> {
+    fn default() -> Self {
+        Self { min: None }
+    }
+}
+
+impl<Item> core::Aggregator<Item> for Min<Item>
+where
+    Item: PartialOrd + Clone,
+{
+    type Output = Item;
+
+    fn update(&mut self, item: Item) {
+        let is_min = match self.min {
+            Some(ref min) => item < *min,
+            None => true,
+        };
+        if is_min {
+            self.min = Some(item);
+        }
+    }
+
+    fn output(&self) -> Option<Item> {
+        self.min.clone()
+    }
+}
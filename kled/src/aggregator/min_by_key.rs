@@ -0,0 +1,32 @@
+use crate::core;
+
+#[derive(new)]
+pub struct MinByKey<Item, Key, KeyFn> {
+    key_fn: KeyFn,
+    #[new(default)]
+    min: Option<(Key, Item)>,
+}
+
+impl<Item, Key, KeyFn> core::Aggregator<Item> for MinByKey<Item, Key, KeyFn>
+where
+    Item: Clone,
+    Key: PartialOrd,
+    KeyFn: FnMut(&Item) -> Key,
+{
+    type Output = Item;
+
+    fn update(&mut self, item: Item) {
+        let key = (self.key_fn)(&item);
+        let is_min = match self.min {
+            Some((ref min, _)) => key < *min,
+            None => true,
+        };
+        if is_min {
+            self.min = Some((key, item));
+        }
+    }
+
+    fn output(&self) -> Option<Item> {
+        self.min.as_ref().map(|(_, item)| item.clone())
+    }
+}
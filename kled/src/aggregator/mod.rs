@@ -0,0 +1,10 @@
+reexport_all! {
+    mod average;
+    mod average_by;
+    mod count;
+    mod max;
+    mod max_by_key;
+    mod min;
+    mod min_by_key;
+    mod sum;
+}
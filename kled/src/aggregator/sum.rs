@@ -0,0 +1,24 @@
+use crate::core;
+use std::ops::Add;
+
+/// Sums up all items starting from `Item::default()`, which is therefore also
+/// the output if no item was received, unlike `Min` and `Max`.
+#[derive(Default)]
+pub struct Sum<Item> {
+    sum: Item,
+}
+
+impl<Item> core::Aggregator<Item> for Sum<Item>
+where
+    Item: Add<Output = Item> + Clone + Default,
+{
+    type Output = Item;
+
+    fn update(&mut self, item: Item) {
+        self.sum = std::mem::take(&mut self.sum) + item;
+    }
+
+    fn output(&self) -> Option<Item> {
+        Some(self.sum.clone())
+    }
+}
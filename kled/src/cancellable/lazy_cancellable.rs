@@ -33,6 +33,11 @@ where
         }
         data.subscription = Some(cancellable);
     }
+
+    /// Whether the cancellable was cancelled before it was set.
+    pub async fn is_cancelled(&self) -> bool {
+        self.data.lock().await.cancelled
+    }
 }
 
 #[derive(Clone)]
@@ -0,0 +1,11 @@
+/// Accumulates items into a single value, as used by the aggregate operators
+/// such as `sum` and `running_sum`.
+pub trait Aggregator<Item> {
+    type Output;
+
+    fn update(&mut self, item: Item);
+
+    /// Returns the aggregate of all items so far or `None` if there is no such
+    /// value, e.g. because no item was received yet.
+    fn output(&self) -> Option<Self::Output>;
+}
@@ -1,7 +1,15 @@
-use crate::{core, flow, subscriber};
+use crate::{aggregator, core, flow, subscriber, Never};
 use crate::flow::operators::*;
+use crate::processor::UnicastProcessor;
 use crate::subscription::LazySubscription;
 use async_trait::async_trait;
+use futures::channel::oneshot;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::iter;
+use std::ops::Add;
+use std::time::Duration;
 
 /// A backpressured source of `Item`s to which a [`Subscriber`] may subscribe.
 ///
@@ -59,6 +67,324 @@ where
         subscription
     }
 
+    async fn collect<C>(self) -> Result<C, flow::Error<Error>>
+    where
+        Self: Sized,
+        C: Default + Extend<Item> + Send + 'static,
+    {
+        self.collect_with_batch_size(usize::MAX).await
+    }
+
+    async fn collect_with_batch_size<C>(self, batch_size: usize) -> Result<C, flow::Error<Error>>
+    where
+        Self: Sized,
+        C: Default + Extend<Item> + Send + 'static,
+    {
+        self.fold_with_batch_size(
+            C::default(),
+            |mut collection, item| {
+                collection.extend(iter::once(item));
+                collection
+            },
+            batch_size,
+        )
+        .await
+    }
+
+    async fn fold<Acc, BinaryOp>(
+        self,
+        initial_value: Acc,
+        binary_op: BinaryOp,
+    ) -> Result<Acc, flow::Error<Error>>
+    where
+        Self: Sized,
+        Acc: Send + 'static,
+        BinaryOp: FnMut(Acc, Item) -> Acc + Send + 'static,
+    {
+        self.fold_with_batch_size(initial_value, binary_op, usize::MAX)
+            .await
+    }
+
+    async fn fold_with_batch_size<Acc, BinaryOp>(
+        self,
+        initial_value: Acc,
+        binary_op: BinaryOp,
+        batch_size: usize,
+    ) -> Result<Acc, flow::Error<Error>>
+    where
+        Self: Sized,
+        Acc: Send + 'static,
+        BinaryOp: FnMut(Acc, Item) -> Acc + Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        self.subscribe(subscriber::FoldSubscriber::new(
+            initial_value,
+            binary_op,
+            batch_size,
+            sender,
+        ))
+        .await;
+        // The sender is only dropped without a result if the flow dropped the
+        // subscriber before terminating.
+        receiver.await.unwrap_or(Err(flow::Error::Disconnected))
+    }
+
+    async fn reduce<BinaryOp>(self, binary_op: BinaryOp) -> Result<Option<Item>, flow::Error<Error>>
+    where
+        Self: Sized,
+        BinaryOp: FnMut(Item, Item) -> Item + Send + 'static,
+    {
+        self.reduce_with_batch_size(binary_op, usize::MAX).await
+    }
+
+    async fn reduce_with_batch_size<BinaryOp>(
+        self,
+        mut binary_op: BinaryOp,
+        batch_size: usize,
+    ) -> Result<Option<Item>, flow::Error<Error>>
+    where
+        Self: Sized,
+        BinaryOp: FnMut(Item, Item) -> Item + Send + 'static,
+    {
+        self.fold_with_batch_size(
+            None,
+            move |value, item| match value {
+                Some(value) => Some(binary_op(value, item)),
+                None => Some(item),
+            },
+            batch_size,
+        )
+        .await
+    }
+
+    async fn to_map<Key, KeyFn>(self, key_fn: KeyFn) -> Result<HashMap<Key, Item>, flow::Error<Error>>
+    where
+        Self: Sized,
+        Key: Eq + Hash + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        self.to_map_with_batch_size(key_fn, usize::MAX).await
+    }
+
+    async fn to_map_with_batch_size<Key, KeyFn>(
+        self,
+        mut key_fn: KeyFn,
+        batch_size: usize,
+    ) -> Result<HashMap<Key, Item>, flow::Error<Error>>
+    where
+        Self: Sized,
+        Key: Eq + Hash + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        self.fold_with_batch_size(
+            HashMap::new(),
+            move |mut map, item| {
+                map.insert(key_fn(&item), item);
+                map
+            },
+            batch_size,
+        )
+        .await
+    }
+
+    async fn to_vec(self) -> Result<Vec<Item>, flow::Error<Error>>
+    where
+        Self: Sized,
+    {
+        self.to_vec_with_batch_size(usize::MAX).await
+    }
+
+    async fn to_vec_with_batch_size(self, batch_size: usize) -> Result<Vec<Item>, flow::Error<Error>>
+    where
+        Self: Sized,
+    {
+        self.collect_with_batch_size(batch_size).await
+    }
+
+    fn all<PredicateFn>(self, predicate_fn: PredicateFn) -> Any<Self, Subscription, Item, Error, PredicateFn>
+    where
+        Self: Sized,
+        PredicateFn: FnMut(&Item) -> bool + Send + 'static,
+    {
+        Any::new(self, predicate_fn, false)
+    }
+
+    fn amb_with<Other, OtherSubscription>(self, other: Other) -> Amb<Item, Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Flow<OtherSubscription, Item, Error> + Send + 'static,
+        OtherSubscription: core::Subscription + Send + Sync + 'static,
+    {
+        Amb::amb2(self, other)
+    }
+
+    fn any<PredicateFn>(self, predicate_fn: PredicateFn) -> Any<Self, Subscription, Item, Error, PredicateFn>
+    where
+        Self: Sized,
+        PredicateFn: FnMut(&Item) -> bool + Send + 'static,
+    {
+        Any::new(self, predicate_fn, true)
+    }
+
+    fn average(self) -> Aggregate<Self, Subscription, Item, Error, aggregator::Average, f64>
+    where
+        Self: Sized,
+        Item: Into<f64>,
+    {
+        Aggregate::new(self, aggregator::Average::default())
+    }
+
+    fn average_by<ValueFn>(
+        self,
+        value_fn: ValueFn,
+    ) -> Aggregate<Self, Subscription, Item, Error, aggregator::AverageBy<ValueFn>, f64>
+    where
+        Self: Sized,
+        ValueFn: FnMut(Item) -> f64 + Send + 'static,
+    {
+        Aggregate::new(self, aggregator::AverageBy::new(value_fn))
+    }
+
+    fn combine_latest<Other, OtherSubscription, OtherItem>(
+        self,
+        other: Other,
+    ) -> CombineLatestTuple<(Item, OtherItem), Error>
+    where
+        Self: Sized + Send + 'static,
+        Item: Clone,
+        Other: core::Flow<OtherSubscription, OtherItem, Error> + Send + 'static,
+        OtherSubscription: core::Subscription + Send + Sync + 'static,
+        OtherItem: Clone + Send + 'static,
+    {
+        CombineLatest::combine_latest2(self, other)
+    }
+
+    fn combine_latest3<FlowB, SubscriptionB, B, FlowC, SubscriptionC, C>(
+        self,
+        b: FlowB,
+        c: FlowC,
+    ) -> CombineLatestTuple<(Item, B, C), Error>
+    where
+        Self: Sized + Send + 'static,
+        Item: Clone,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        B: Clone + Send + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+        C: Clone + Send + 'static,
+    {
+        CombineLatest::combine_latest3(self, b, c)
+    }
+
+    fn combine_latest4<FlowB, SubscriptionB, B, FlowC, SubscriptionC, C, FlowD, SubscriptionD, D>(
+        self,
+        b: FlowB,
+        c: FlowC,
+        d: FlowD,
+    ) -> CombineLatestTuple<(Item, B, C, D), Error>
+    where
+        Self: Sized + Send + 'static,
+        Item: Clone,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        B: Clone + Send + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+        C: Clone + Send + 'static,
+        FlowD: core::Flow<SubscriptionD, D, Error> + Send + 'static,
+        SubscriptionD: core::Subscription + Send + Sync + 'static,
+        D: Clone + Send + 'static,
+    {
+        CombineLatest::combine_latest4(self, b, c, d)
+    }
+
+    fn concat_with<Other, OtherSubscription>(self, other: Other) -> Concat<Item, Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Flow<OtherSubscription, Item, Error> + Send + 'static,
+        OtherSubscription: core::Subscription + Send + Sync + 'static,
+    {
+        Concat::concat2(self, other)
+    }
+
+    fn contains(self, item: Item) -> Any<Self, Subscription, Item, Error, BoxedPredicate<Item>>
+    where
+        Self: Sized,
+        Item: PartialEq + Send + 'static,
+    {
+        Any::new(self, Box::new(move |other| *other == item), true)
+    }
+
+    fn count(self) -> Aggregate<Self, Subscription, Item, Error, aggregator::Count, usize>
+    where
+        Self: Sized,
+    {
+        Aggregate::new(self, aggregator::Count::default())
+    }
+
+    fn debounce<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Debounce<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Debounce::new(self, Box::new(move |_| duration), scheduler)
+    }
+
+    fn debounce_with<DurationFn, Scheduler>(
+        self,
+        duration_fn: DurationFn,
+        scheduler: Scheduler,
+    ) -> Debounce<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        DurationFn: FnMut(&Item) -> Duration + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Debounce::new(self, Box::new(duration_fn), scheduler)
+    }
+
+    fn default_if_empty(self, default: Item) -> WithItems<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        WithItems::new(self, vec![], vec![], Some(default))
+    }
+
+    fn delay<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+        delay_error: bool,
+    ) -> Delay<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Dematerialize::new(DelayRaw::new(
+            Materialize::new(self),
+            duration,
+            scheduler,
+            delay_error,
+        ))
+    }
+
+    fn delay_subscription<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> DelaySubscription<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        DelaySubscription::new(self, duration, scheduler)
+    }
+
     fn dematerialize(
         self,
     ) -> Dematerialize<Self, Subscription, Item, Error>
@@ -68,24 +394,288 @@ where
         Dematerialize::new(self)
     }
 
+    fn do_on_cancel<CancelFn>(
+        self,
+        cancel_fn: CancelFn,
+    ) -> DoOnCancel<Self, Subscription, Item, Error, CancelFn>
+    where
+        Self: Sized,
+        CancelFn: Fn() + Send + Sync + 'static,
+    {
+        DoOnCancel::new(self, cancel_fn)
+    }
+
+    fn do_on_complete<CompleteFn>(
+        self,
+        complete_fn: CompleteFn,
+    ) -> DoOnComplete<Self, Subscription, Item, Error, CompleteFn>
+    where
+        Self: Sized,
+        CompleteFn: FnMut() + Send,
+    {
+        DoOnComplete::new(self, complete_fn)
+    }
+
+    fn do_on_error<ErrorFn>(
+        self,
+        error_fn: ErrorFn,
+    ) -> DoOnError<Self, Subscription, Item, Error, ErrorFn>
+    where
+        Self: Sized,
+        ErrorFn: FnMut(&flow::Error<Error>) + Send,
+    {
+        DoOnError::new(self, error_fn)
+    }
+
+    fn do_on_next<NextFn>(
+        self,
+        next_fn: NextFn,
+    ) -> DoOnNext<Self, Subscription, Item, Error, NextFn>
+    where
+        Self: Sized,
+        NextFn: FnMut(&Item) + Send,
+    {
+        DoOnNext::new(self, next_fn)
+    }
+
+    fn do_on_request<RequestFn>(
+        self,
+        request_fn: RequestFn,
+    ) -> DoOnRequest<Self, Subscription, Item, Error, RequestFn>
+    where
+        Self: Sized,
+        RequestFn: Fn(usize) + Send + Sync + 'static,
+    {
+        DoOnRequest::new(self, request_fn)
+    }
+
+    fn do_on_subscribe<SubscribeFn>(
+        self,
+        subscribe_fn: SubscribeFn,
+    ) -> DoOnSubscribe<Self, Subscription, Item, Error, SubscribeFn>
+    where
+        Self: Sized,
+        SubscribeFn: FnMut() + Send,
+    {
+        DoOnSubscribe::new(self, subscribe_fn)
+    }
+
+    fn element_at(self, index: usize) -> ElementAt<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        ElementAt::new(self, index, None)
+    }
+
+    fn end_with<IntoIter>(self, items: IntoIter) -> WithItems<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+        IntoIter: IntoIterator<Item = Item>,
+    {
+        WithItems::new(self, vec![], items.into_iter().collect(), None)
+    }
+
+    fn filter_async<Predicate, Fut, Scheduler>(
+        self,
+        predicate: Predicate,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Subscription, Item, Error, Item, Scheduler>
+    where
+        Self: Sized,
+        Predicate: FnMut(&Item) -> Fut + Send + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(concurrency > 0, "Flow::filter_async: concurrency must be positive");
+        MapAsync::new(self, filter_async_fn(predicate), concurrency, true, scheduler)
+    }
+
+    fn filter_async_unordered<Predicate, Fut, Scheduler>(
+        self,
+        predicate: Predicate,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Subscription, Item, Error, Item, Scheduler>
+    where
+        Self: Sized,
+        Predicate: FnMut(&Item) -> Fut + Send + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(concurrency > 0, "Flow::filter_async_unordered: concurrency must be positive");
+        MapAsync::new(self, filter_async_fn(predicate), concurrency, false, scheduler)
+    }
+
+    fn finally<FinallyFn>(
+        self,
+        finally_fn: FinallyFn,
+    ) -> Finally<Self, Subscription, Item, Error, FinallyFn>
+    where
+        Self: Sized,
+        FinallyFn: FnOnce() + Send + 'static,
+    {
+        Finally::new(self, finally_fn)
+    }
+
+    fn first(self) -> ElementAt<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        ElementAt::new(self, 0, None)
+    }
+
+    fn first_or(self, default: Item) -> ElementAt<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        ElementAt::new(self, 0, Some(default))
+    }
+
+    fn group_by<Key, KeyFn>(self, key_fn: KeyFn) -> GroupBy<Self, Subscription, Item, Error, Key, KeyFn>
+    where
+        Self: Sized,
+        Error: Clone,
+        Key: Clone + Eq + Hash + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        GroupBy::new(self, key_fn, None)
+    }
+
+    fn group_by_with_eviction<Key, KeyFn, Scheduler>(
+        self,
+        key_fn: KeyFn,
+        eviction: Duration,
+        scheduler: Scheduler,
+    ) -> GroupBy<Self, Subscription, Item, Error, Key, KeyFn>
+    where
+        Self: Sized,
+        Error: Clone,
+        Key: Clone + Eq + Hash + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        GroupBy::new(self, key_fn, Some(Eviction::new(eviction, scheduler)))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_empty(self) -> Any<Self, Subscription, Item, Error, BoxedPredicate<Item>>
+    where
+        Self: Sized,
+    {
+        Any::new(self, Box::new(|_| false), false)
+    }
+
+    fn last(self) -> Last<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        Last::new(self)
+    }
+
     fn map<ItemOut, UnaryOp>(
         self,
         unary_op: UnaryOp,
     ) -> Map<Self, Subscription, Item, Error, ItemOut, UnaryOp>
     where
         Self: Sized,
-        UnaryOp: FnMut(Item) -> ItemOut + Send + 'static,
+        UnaryOp: FnMut(Item) -> ItemOut + Send + 'static,
+    {
+        Map::new(self, unary_op)
+    }
+
+    fn map_async<ItemOut, UnaryOp, Fut, Scheduler>(
+        self,
+        unary_op: UnaryOp,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Subscription, Item, Error, ItemOut, Scheduler>
+    where
+        Self: Sized,
+        ItemOut: Send + 'static,
+        UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(concurrency > 0, "Flow::map_async: concurrency must be positive");
+        MapAsync::new(self, map_async_fn(unary_op), concurrency, true, scheduler)
+    }
+
+    fn map_async_unordered<ItemOut, UnaryOp, Fut, Scheduler>(
+        self,
+        unary_op: UnaryOp,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Subscription, Item, Error, ItemOut, Scheduler>
+    where
+        Self: Sized,
+        ItemOut: Send + 'static,
+        UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(concurrency > 0, "Flow::map_async_unordered: concurrency must be positive");
+        MapAsync::new(self, map_async_fn(unary_op), concurrency, false, scheduler)
+    }
+
+    fn materialize(
+        self,
+    ) -> Materialize<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        Materialize::new(self)
+    }
+
+    fn max(self) -> Aggregate<Self, Subscription, Item, Error, aggregator::Max<Item>, Item>
+    where
+        Self: Sized,
+        Item: PartialOrd + Clone,
+    {
+        Aggregate::new(self, aggregator::Max::default())
+    }
+
+    fn max_by_key<Key, KeyFn>(
+        self,
+        key_fn: KeyFn,
+    ) -> Aggregate<Self, Subscription, Item, Error, aggregator::MaxByKey<Item, Key, KeyFn>, Item>
+    where
+        Self: Sized,
+        Item: Clone,
+        Key: PartialOrd + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        Aggregate::new(self, aggregator::MaxByKey::new(key_fn))
+    }
+
+    fn merge_with<Other, OtherSubscription>(self, other: Other) -> Merge<Item, Error, Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Flow<OtherSubscription, Item, Error> + Send + 'static,
+        OtherSubscription: core::Subscription + Send + Sync + 'static,
+    {
+        Merge::merge2(self, other)
+    }
+
+    fn min(self) -> Aggregate<Self, Subscription, Item, Error, aggregator::Min<Item>, Item>
+    where
+        Self: Sized,
+        Item: PartialOrd + Clone,
     {
-        Map::new(self, unary_op)
+        Aggregate::new(self, aggregator::Min::default())
     }
 
-    fn materialize(
+    fn min_by_key<Key, KeyFn>(
         self,
-    ) -> Materialize<Self, Subscription, Item, Error>
+        key_fn: KeyFn,
+    ) -> Aggregate<Self, Subscription, Item, Error, aggregator::MinByKey<Item, Key, KeyFn>, Item>
     where
         Self: Sized,
+        Item: Clone,
+        Key: PartialOrd + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
     {
-        Materialize::new(self)
+        Aggregate::new(self, aggregator::MinByKey::new(key_fn))
     }
 
     fn observe_on<Scheduler>(
@@ -141,6 +731,209 @@ where
         OnBackpressureLatest::new(self)
     }
 
+    fn on_error_complete(self) -> OnErrorComplete<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        OnErrorComplete::new(self)
+    }
+
+    fn on_error_resume_next<Fallback, FallbackSubscription, ResumeFn>(
+        self,
+        resume_fn: ResumeFn,
+    ) -> OnErrorResumeNext<Self, Subscription, Item, Error, Fallback, FallbackSubscription, ResumeFn>
+    where
+        Self: Sized,
+        Fallback: core::Flow<FallbackSubscription, Item, Error>,
+        FallbackSubscription: core::Subscription + Send + Sync + 'static,
+        ResumeFn: FnMut(flow::Error<Error>) -> Fallback + Send,
+    {
+        OnErrorResumeNext::new(self, resume_fn)
+    }
+
+    fn on_error_return<ItemFn>(
+        self,
+        item_fn: ItemFn,
+    ) -> OnErrorReturn<Self, Subscription, Item, Error, ItemFn>
+    where
+        Self: Sized,
+        ItemFn: FnMut(flow::Error<Error>) -> Item + Send,
+    {
+        OnErrorReturn::new(self, item_fn)
+    }
+
+    fn pairwise(self) -> Pairwise<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+        Item: Clone,
+    {
+        Pairwise::new(self)
+    }
+
+    fn repeat(self, count: usize) -> Repeat<Self, Subscription, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+    {
+        Repeat::new(self, count)
+    }
+
+    fn repeat_forever(self) -> Repeat<Self, Subscription, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+    {
+        Repeat::forever(self)
+    }
+
+    fn repeat_when<RepeatWhen, Notifier, NotifierSubscription, NotifierItem>(
+        self,
+        repeat_when: RepeatWhen,
+    ) -> Repeat<Self, Subscription, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        RepeatWhen: FnOnce(UnicastProcessor<Never, (), Error>) -> Notifier + Send + 'static,
+        Notifier: core::Flow<NotifierSubscription, NotifierItem, Error> + Send + 'static,
+        NotifierSubscription: core::Subscription + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Repeat::when(self, repeat_when)
+    }
+
+    fn retry(self, count: usize) -> Retry<Self, Subscription, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+    {
+        Retry::new(self, count)
+    }
+
+    fn retry_if<Predicate>(self, predicate: Predicate) -> Retry<Self, Subscription, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        Predicate: FnMut(&Error) -> bool + Send + 'static,
+    {
+        Retry::with_predicate(self, predicate)
+    }
+
+    fn retry_when<RetryWhen, Notifier, NotifierSubscription, NotifierItem>(
+        self,
+        retry_when: RetryWhen,
+    ) -> Retry<Self, Subscription, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        RetryWhen: FnOnce(UnicastProcessor<Never, Error, Error>) -> Notifier + Send + 'static,
+        Notifier: core::Flow<NotifierSubscription, NotifierItem, Error> + Send + 'static,
+        NotifierSubscription: core::Subscription + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Retry::when(self, retry_when)
+    }
+
+    fn retry_with_backoff<Scheduler>(
+        self,
+        initial: Duration,
+        max: Duration,
+        factor: f64,
+        jitter: f64,
+        scheduler: Scheduler,
+    ) -> Retry<Self, Subscription, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(factor >= 1.0, "Flow::retry_with_backoff: factor must be at least one");
+        assert!(
+            (0.0..=1.0).contains(&jitter),
+            "Flow::retry_with_backoff: jitter must be between zero and one"
+        );
+        Retry::with_backoff(self, initial, max, factor, jitter, scheduler)
+    }
+
+    fn running_average(
+        self,
+    ) -> RunningAggregate<Self, Subscription, Item, Error, aggregator::Average, f64>
+    where
+        Self: Sized,
+        Item: Into<f64>,
+    {
+        RunningAggregate::new(self, aggregator::Average::default())
+    }
+
+    fn running_average_by<ValueFn>(
+        self,
+        value_fn: ValueFn,
+    ) -> RunningAggregate<Self, Subscription, Item, Error, aggregator::AverageBy<ValueFn>, f64>
+    where
+        Self: Sized,
+        ValueFn: FnMut(Item) -> f64 + Send + 'static,
+    {
+        RunningAggregate::new(self, aggregator::AverageBy::new(value_fn))
+    }
+
+    fn running_count(
+        self,
+    ) -> RunningAggregate<Self, Subscription, Item, Error, aggregator::Count, usize>
+    where
+        Self: Sized,
+    {
+        RunningAggregate::new(self, aggregator::Count::default())
+    }
+
+    fn running_max(
+        self,
+    ) -> RunningAggregate<Self, Subscription, Item, Error, aggregator::Max<Item>, Item>
+    where
+        Self: Sized,
+        Item: PartialOrd + Clone,
+    {
+        RunningAggregate::new(self, aggregator::Max::default())
+    }
+
+    fn running_min(
+        self,
+    ) -> RunningAggregate<Self, Subscription, Item, Error, aggregator::Min<Item>, Item>
+    where
+        Self: Sized,
+        Item: PartialOrd + Clone,
+    {
+        RunningAggregate::new(self, aggregator::Min::default())
+    }
+
+    fn running_sum(
+        self,
+    ) -> RunningAggregate<Self, Subscription, Item, Error, aggregator::Sum<Item>, Item>
+    where
+        Self: Sized,
+        Item: Add<Output = Item> + Clone + Default,
+    {
+        RunningAggregate::new(self, aggregator::Sum::default())
+    }
+
+    fn sample<Scheduler>(
+        self,
+        period: Duration,
+        scheduler: Scheduler,
+    ) -> Sample<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Sample::new(self, period, scheduler)
+    }
+
+    fn sample_with<Sampler, SamplerSubscription, SamplerItem>(
+        self,
+        sampler: Sampler,
+    ) -> SampleWith<Self, Subscription, Item, Error, Sampler, SamplerSubscription, SamplerItem>
+    where
+        Self: Sized,
+        Item: Send + 'static,
+        Error: Send + 'static,
+        Sampler: core::Flow<SamplerSubscription, SamplerItem, Error>,
+        SamplerSubscription: core::Subscription + Send + Sync + 'static,
+        SamplerItem: Send + 'static,
+    {
+        SampleWith::new(self, sampler)
+    }
+
     fn scan<ItemOut, BinaryOp>(
         self,
         initial_value: ItemOut,
@@ -154,6 +947,45 @@ where
         Scan::new(self, initial_value, binary_op)
     }
 
+    fn sequence_equal<Other, OtherSubscription, OtherItem, EqFn>(
+        self,
+        other: Other,
+        eq_fn: EqFn,
+    ) -> SequenceEqual<Item, OtherItem, Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Flow<OtherSubscription, OtherItem, Error> + Send + 'static,
+        OtherSubscription: core::Subscription + Send + Sync + 'static,
+        OtherItem: Send + 'static,
+        EqFn: FnMut(&Item, &OtherItem) -> bool + Send + 'static,
+    {
+        SequenceEqual::new(self, other, eq_fn)
+    }
+
+    fn single(self) -> Single<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        Single::new(self)
+    }
+
+    fn sliding(self, count: usize) -> Sliding<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+        Item: Sync,
+    {
+        assert!(count > 0, "Flow::sliding: count must be greater than zero");
+        Sliding::new(self, count)
+    }
+
+    fn start_with<IntoIter>(self, items: IntoIter) -> WithItems<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+        IntoIter: IntoIterator<Item = Item>,
+    {
+        WithItems::new(self, items.into_iter().collect(), vec![], None)
+    }
+
     fn subscribe_on<Scheduler>(
         self,
         scheduler: Scheduler,
@@ -164,4 +996,255 @@ where
     {
         SubscribeOn::new(self, scheduler)
     }
+
+    fn sum(self) -> Aggregate<Self, Subscription, Item, Error, aggregator::Sum<Item>, Item>
+    where
+        Self: Sized,
+        Item: Add<Output = Item> + Clone + Default,
+    {
+        Aggregate::new(self, aggregator::Sum::default())
+    }
+
+    fn switch_if_empty<Fallback, FallbackSubscription>(
+        self,
+        fallback: Fallback,
+    ) -> SwitchIfEmpty<Self, Subscription, Item, Error, Fallback, FallbackSubscription>
+    where
+        Self: Sized,
+        Fallback: core::Flow<FallbackSubscription, Item, Error> + Send + 'static,
+        FallbackSubscription: core::Subscription + Send + Sync + 'static,
+    {
+        SwitchIfEmpty::new(self, fallback)
+    }
+
+    fn throttle_first<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> ThrottleFirst<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        ThrottleFirst::new(self, duration, scheduler)
+    }
+
+    fn throttle_last<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Sample<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Sample::new(self, duration, scheduler)
+    }
+
+    fn throttle_latest<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+        emit_last: bool,
+    ) -> ThrottleLatest<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        ThrottleLatest::new(self, duration, scheduler, emit_last)
+    }
+
+    fn time_interval<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> TimeInterval<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        TimeInterval::new(self, scheduler)
+    }
+
+    fn timeout<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Timeout<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timeout::new(self, duration, Box::new(move |_| duration), scheduler, None)
+    }
+
+    fn timeout_first<Scheduler>(
+        self,
+        first: Duration,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Timeout<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timeout::new(self, first, Box::new(move |_| duration), scheduler, None)
+    }
+
+    fn timeout_or<Scheduler, Fallback, FallbackSubscription>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+        fallback: Fallback,
+    ) -> Timeout<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+        Fallback: core::Flow<FallbackSubscription, Item, Error> + Send + 'static,
+        FallbackSubscription: core::Subscription + Send + Sync + 'static,
+    {
+        Timeout::new(
+            self,
+            duration,
+            Box::new(move |_| duration),
+            scheduler,
+            Some(TimeoutFallback::new(fallback)),
+        )
+    }
+
+    fn timeout_with<TimeoutFn, Scheduler>(
+        self,
+        first: Duration,
+        timeout_fn: TimeoutFn,
+        scheduler: Scheduler,
+    ) -> Timeout<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        TimeoutFn: FnMut(&Item) -> Duration + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timeout::new(self, first, Box::new(timeout_fn), scheduler, None)
+    }
+
+    fn timestamp<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> Timestamp<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timestamp::new(self, scheduler)
+    }
+
+    fn window(self, count: usize) -> Window<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+        Error: Clone,
+    {
+        assert!(count > 0, "Flow::window: count must be greater than zero");
+        Window::new(self, count)
+    }
+
+    fn window_boundary<Boundary, BoundarySubscription, BoundaryItem>(
+        self,
+        boundary: Boundary,
+    ) -> WindowBoundary<Self, Subscription, Item, Error, Boundary, BoundarySubscription, BoundaryItem>
+    where
+        Self: Sized,
+        Error: Clone,
+        Boundary: core::Flow<BoundarySubscription, BoundaryItem, Error>,
+        BoundarySubscription: core::Subscription + Send + Sync + 'static,
+        BoundaryItem: Send + 'static,
+    {
+        WindowBoundary::new(self, boundary)
+    }
+
+    fn window_time<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> WindowTime<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Error: Clone,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        WindowTime::new(self, duration, scheduler)
+    }
+
+    fn with_latest_from<Other, OtherSubscription, OtherItem, ItemOut, CombineFn>(
+        self,
+        other: Other,
+        combiner: CombineFn,
+    ) -> WithLatestFrom<
+        Self,
+        Subscription,
+        Item,
+        Error,
+        Other,
+        OtherSubscription,
+        OtherItem,
+        ItemOut,
+        CombineFn,
+    >
+    where
+        Self: Sized,
+        Other: core::Flow<OtherSubscription, OtherItem, Error> + Send + 'static,
+        OtherSubscription: core::Subscription + Send + Sync + 'static,
+        OtherItem: Send + 'static,
+        CombineFn: FnMut(Item, &OtherItem) -> ItemOut,
+    {
+        WithLatestFrom::new(self, other, combiner)
+    }
+
+    fn zip<Other, OtherSubscription, OtherItem>(
+        self,
+        other: Other,
+    ) -> ZipTuple<(Item, OtherItem), Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Flow<OtherSubscription, OtherItem, Error> + Send + 'static,
+        OtherSubscription: core::Subscription + Send + Sync + 'static,
+        OtherItem: Send + 'static,
+    {
+        Zip::zip2(self, other)
+    }
+
+    fn zip3<FlowB, SubscriptionB, B, FlowC, SubscriptionC, C>(
+        self,
+        b: FlowB,
+        c: FlowC,
+    ) -> ZipTuple<(Item, B, C), Error>
+    where
+        Self: Sized + Send + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        B: Send + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+        C: Send + 'static,
+    {
+        Zip::zip3(self, b, c)
+    }
+
+    fn zip4<FlowB, SubscriptionB, B, FlowC, SubscriptionC, C, FlowD, SubscriptionD, D>(
+        self,
+        b: FlowB,
+        c: FlowC,
+        d: FlowD,
+    ) -> ZipTuple<(Item, B, C, D), Error>
+    where
+        Self: Sized + Send + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        B: Send + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+        C: Send + 'static,
+        FlowD: core::Flow<SubscriptionD, D, Error> + Send + 'static,
+        SubscriptionD: core::Subscription + Send + Sync + 'static,
+        D: Send + 'static,
+    {
+        Zip::zip4(self, b, c, d)
+    }
 }
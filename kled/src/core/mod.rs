@@ -1,9 +1,11 @@
 reexport_all! {
+    mod aggregator;
     mod cancellable;
     mod flow;
     mod into;
     mod observable;
     mod observer;
+    mod processor;
     mod scheduler;
     mod subject;
     mod subscriber;
@@ -1,7 +1,15 @@
-use crate::{core, observer};
+use crate::{aggregator, core, observable, observer, Never};
 use crate::cancellable::LazyCancellable;
 use crate::observable::operators::*;
+use crate::subject::UnicastSubject;
 use async_trait::async_trait;
+use futures::channel::oneshot;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::iter;
+use std::ops::Add;
+use std::time::Duration;
 
 /// A non-backpressured source of `Item`s to which an [`Observer`] may subscribe.
 ///
@@ -58,54 +66,1079 @@ where
         cancellable
     }
 
+    /// Returns the result of collecting all items of the current [`Observable`] into a `C` or
+    /// the error the `Observable` terminated with.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    async fn collect<C>(self) -> Result<C, observable::Error<Error>>
+    where
+        Self: Sized,
+        C: Default + Extend<Item> + Send + 'static,
+    {
+        self.fold(C::default(), |mut collection, item| {
+            collection.extend(iter::once(item));
+            collection
+        })
+        .await
+    }
+
+    /// Returns the result of successively applying `binary_op` to an accumulator, starting
+    /// with `initial_value`, and each item of the current [`Observable`] or the error the
+    /// `Observable` terminated with. Resolves to [`Error::Disconnected`] if the `Observable`
+    /// drops its observer without terminating.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::Disconnected`]: ../observable/enum.Error.html#variant.Disconnected
+    async fn fold<Acc, BinaryOp>(
+        self,
+        initial_value: Acc,
+        binary_op: BinaryOp,
+    ) -> Result<Acc, observable::Error<Error>>
+    where
+        Self: Sized,
+        Acc: Send + 'static,
+        BinaryOp: FnMut(Acc, Item) -> Acc + Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        self.subscribe(observer::FoldObserver::new(initial_value, binary_op, sender))
+            .await;
+        receiver
+            .await
+            .unwrap_or(Err(observable::Error::Disconnected))
+    }
+
+    /// Like [`fold`], but uses the first item as the initial value. Resolves to `None` if the
+    /// current [`Observable`] completes without emitting any items.
+    ///
+    /// [`fold`]: trait.Observable.html#method.fold
+    /// [`Observable`]: trait.Observable.html
+    async fn reduce<BinaryOp>(
+        self,
+        mut binary_op: BinaryOp,
+    ) -> Result<Option<Item>, observable::Error<Error>>
+    where
+        Self: Sized,
+        BinaryOp: FnMut(Item, Item) -> Item + Send + 'static,
+    {
+        self.fold(None, move |value, item| match value {
+            Some(value) => Some(binary_op(value, item)),
+            None => Some(item),
+        })
+        .await
+    }
+
+    /// Returns a map of all items of the current [`Observable`] keyed by the result of `key_fn`
+    /// or the error the `Observable` terminated with. Later items replace earlier items with
+    /// the same key.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    async fn to_map<Key, KeyFn>(
+        self,
+        mut key_fn: KeyFn,
+    ) -> Result<HashMap<Key, Item>, observable::Error<Error>>
+    where
+        Self: Sized,
+        Key: Eq + Hash + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        self.fold(HashMap::new(), move |mut map, item| {
+            map.insert(key_fn(&item), item);
+            map
+        })
+        .await
+    }
+
+    /// Returns a `Vec` of all items of the current [`Observable`] or the error the
+    /// `Observable` terminated with.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    async fn to_vec(self) -> Result<Vec<Item>, observable::Error<Error>>
+    where
+        Self: Sized,
+    {
+        self.collect().await
+    }
+
+    /// Returns an [`Observable`] that emits whether `predicate_fn` returns true for all items of the
+    /// current `Observable`. The current `Observable` is cancelled as soon as an item does not
+    /// satisfy `predicate_fn`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn all<PredicateFn>(self, predicate_fn: PredicateFn) -> Any<Self, Cancellable, Item, Error, PredicateFn>
+    where
+        Self: Sized,
+        PredicateFn: FnMut(&Item) -> bool + Send + 'static,
+    {
+        Any::new(self, predicate_fn, false)
+    }
+
+    /// Returns an [`Observable`] that mirrors whichever of the current `Observable` and
+    /// `other` emits any signal first and cancels the other one.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn amb_with<Other, OtherCancellable>(self, other: Other) -> Amb<Item, Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Observable<OtherCancellable, Item, Error> + Send + 'static,
+        OtherCancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Amb::amb2(self, other)
+    }
+
+    /// Returns an [`Observable`] that emits whether `predicate_fn` returns true for any item of the
+    /// current `Observable`. The current `Observable` is cancelled as soon as an item satisfies
+    /// `predicate_fn`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn any<PredicateFn>(self, predicate_fn: PredicateFn) -> Any<Self, Cancellable, Item, Error, PredicateFn>
+    where
+        Self: Sized,
+        PredicateFn: FnMut(&Item) -> bool + Send + 'static,
+    {
+        Any::new(self, predicate_fn, true)
+    }
+
+    /// Returns an [`Observable`] that emits the arithmetic mean of all items of the current
+    /// `Observable` once it completes. Signals [`Error::NoSuchElement`] if the current `Observable`
+    /// is empty.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn average(self) -> Aggregate<Self, Cancellable, Item, Error, aggregator::Average, f64>
+    where
+        Self: Sized,
+        Item: Into<f64>,
+    {
+        Aggregate::new(self, aggregator::Average::default())
+    }
+
+    /// Like [`average`], but averages the values which `value_fn` maps the items to. This
+    /// allows averaging items which don't convert into `f64` losslessly, e.g. with
+    /// `average_by(|item: i64| item as f64)`.
+    ///
+    /// [`average`]: trait.Observable.html#method.average
+    fn average_by<ValueFn>(
+        self,
+        value_fn: ValueFn,
+    ) -> Aggregate<Self, Cancellable, Item, Error, aggregator::AverageBy<ValueFn>, f64>
+    where
+        Self: Sized,
+        ValueFn: FnMut(Item) -> f64 + Send + 'static,
+    {
+        Aggregate::new(self, aggregator::AverageBy::new(value_fn))
+    }
+
+    /// Returns an [`Observable`] that emits a pair of the latest items of the current
+    /// `Observable` and `other` whenever either of them emits an item, once both have emitted
+    /// at least one item. It completes once both sources completed, see
+    /// [`CombineLatest::complete_on_first`] to complete with the first source instead.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`CombineLatest::complete_on_first`]: ../observable/operators/struct.CombineLatest.html#method.complete_on_first
+    fn combine_latest<Other, OtherCancellable, OtherItem>(
+        self,
+        other: Other,
+    ) -> CombineLatestTuple<(Item, OtherItem), Error>
+    where
+        Self: Sized + Send + 'static,
+        Item: Clone,
+        Other: core::Observable<OtherCancellable, OtherItem, Error> + Send + 'static,
+        OtherCancellable: core::Cancellable + Send + Sync + 'static,
+        OtherItem: Clone + Send + 'static,
+    {
+        CombineLatest::combine_latest2(self, other)
+    }
+
+    /// Like [`combine_latest`], but combines the latest items of three sources into triples.
+    ///
+    /// [`combine_latest`]: trait.Observable.html#method.combine_latest
+    fn combine_latest3<ObservableB, CancellableB, B, ObservableC, CancellableC, C>(
+        self,
+        b: ObservableB,
+        c: ObservableC,
+    ) -> CombineLatestTuple<(Item, B, C), Error>
+    where
+        Self: Sized + Send + 'static,
+        Item: Clone,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        B: Clone + Send + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+        C: Clone + Send + 'static,
+    {
+        CombineLatest::combine_latest3(self, b, c)
+    }
+
+    /// Like [`combine_latest`], but combines the latest items of four sources into quadruples.
+    ///
+    /// [`combine_latest`]: trait.Observable.html#method.combine_latest
+    fn combine_latest4<ObservableB, CancellableB, B, ObservableC, CancellableC, C, ObservableD, CancellableD, D>(
+        self,
+        b: ObservableB,
+        c: ObservableC,
+        d: ObservableD,
+    ) -> CombineLatestTuple<(Item, B, C, D), Error>
+    where
+        Self: Sized + Send + 'static,
+        Item: Clone,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        B: Clone + Send + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+        C: Clone + Send + 'static,
+        ObservableD: core::Observable<CancellableD, D, Error> + Send + 'static,
+        CancellableD: core::Cancellable + Send + Sync + 'static,
+        D: Clone + Send + 'static,
+    {
+        CombineLatest::combine_latest4(self, b, c, d)
+    }
+
+    /// Returns an [`Observable`] that emits the items of the current `Observable` followed by
+    /// the items of `other`, which is subscribed to once the current `Observable` completed.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn concat_with<Other, OtherCancellable>(self, other: Other) -> Concat<Item, Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Observable<OtherCancellable, Item, Error> + Send + 'static,
+        OtherCancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Concat::concat2(self, other)
+    }
+
+    /// Returns an [`Observable`] that emits whether the current `Observable` emits an item equal to
+    /// `item`. The current `Observable` is cancelled as soon as such an item was received.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn contains(self, item: Item) -> Any<Self, Cancellable, Item, Error, BoxedPredicate<Item>>
+    where
+        Self: Sized,
+        Item: PartialEq + Send + 'static,
+    {
+        Any::new(self, Box::new(move |other| *other == item), true)
+    }
+
+    /// Returns an [`Observable`] that emits the number of items of the current `Observable` once
+    /// it completes.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn count(self) -> Aggregate<Self, Cancellable, Item, Error, aggregator::Count, usize>
+    where
+        Self: Sized,
+    {
+        Aggregate::new(self, aggregator::Count::default())
+    }
+
+    /// Returns an [`Observable`] that emits an item of the current `Observable` only after no
+    /// other item was emitted for `duration`. An item still pending on completion is emitted
+    /// before completing.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn debounce<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Debounce<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Debounce::new(self, Box::new(move |_| duration), scheduler)
+    }
+
+    /// Like [`debounce`], but the quiet period following each item is determined by applying
+    /// `duration_fn` to it.
+    ///
+    /// [`debounce`]: trait.Observable.html#method.debounce
+    fn debounce_with<DurationFn, Scheduler>(
+        self,
+        duration_fn: DurationFn,
+        scheduler: Scheduler,
+    ) -> Debounce<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        DurationFn: FnMut(&Item) -> Duration + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Debounce::new(self, Box::new(duration_fn), scheduler)
+    }
+
+    /// Returns an [`Observable`] that emits `default` if the current `Observable` completes
+    /// without emitting any items.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn default_if_empty(self, default: Item) -> WithItems<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        WithItems::new(self, vec![], vec![], Some(default))
+    }
+
+    /// Returns an [`Observable`] that emits the items and the completion of the current
+    /// `Observable` shifted by `duration` on the given `scheduler`. An error is shifted as well
+    /// if `delay_error` is set and is emitted immediately otherwise, dropping all items which
+    /// are still delayed.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn delay<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+        delay_error: bool,
+    ) -> Delay<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Dematerialize::new(DelayRaw::new(
+            Materialize::new(self),
+            duration,
+            scheduler,
+            delay_error,
+        ))
+    }
+
+    /// Returns an [`Observable`] that subscribes to the current `Observable` only after
+    /// `duration` has passed on the specified [`Scheduler`]. The observer receives its
+    /// cancellable right away, and the current `Observable` is never subscribed to if it is
+    /// cancelled during the delay.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn delay_subscription<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> DelaySubscription<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        DelaySubscription::new(self, duration, scheduler)
+    }
+
     fn dematerialize(
         self,
-    ) -> Dematerialize<Self, Cancellable, Item, Error>
+    ) -> Dematerialize<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        Dematerialize::new(self)
+    }
+
+    /// Returns an [`Observable`] that calls `cancel_fn` whenever the returned `Observable` is
+    /// cancelled, before the cancellation is passed on.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn do_on_cancel<CancelFn>(
+        self,
+        cancel_fn: CancelFn,
+    ) -> DoOnCancel<Self, Cancellable, Item, Error, CancelFn>
+    where
+        Self: Sized,
+        CancelFn: Fn() + Send + Sync + 'static,
+    {
+        DoOnCancel::new(self, cancel_fn)
+    }
+
+    /// Returns an [`Observable`] that calls `complete_fn` when the current `Observable`
+    /// completes, before the completion is passed on.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn do_on_complete<CompleteFn>(
+        self,
+        complete_fn: CompleteFn,
+    ) -> DoOnComplete<Self, Cancellable, Item, Error, CompleteFn>
+    where
+        Self: Sized,
+        CompleteFn: FnMut() + Send,
+    {
+        DoOnComplete::new(self, complete_fn)
+    }
+
+    /// Returns an [`Observable`] that calls `error_fn` with each error of the current
+    /// `Observable`, before the error is passed on.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn do_on_error<ErrorFn>(
+        self,
+        error_fn: ErrorFn,
+    ) -> DoOnError<Self, Cancellable, Item, Error, ErrorFn>
+    where
+        Self: Sized,
+        ErrorFn: FnMut(&Error) + Send,
+    {
+        DoOnError::new(self, error_fn)
+    }
+
+    /// Returns an [`Observable`] that calls `next_fn` with each item of the current
+    /// `Observable`, before the item is passed on.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn do_on_next<NextFn>(
+        self,
+        next_fn: NextFn,
+    ) -> DoOnNext<Self, Cancellable, Item, Error, NextFn>
+    where
+        Self: Sized,
+        NextFn: FnMut(&Item) + Send,
+    {
+        DoOnNext::new(self, next_fn)
+    }
+
+    /// Returns an [`Observable`] that calls `subscribe_fn` when the current `Observable` is
+    /// subscribed to, before the subscription is passed on.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn do_on_subscribe<SubscribeFn>(
+        self,
+        subscribe_fn: SubscribeFn,
+    ) -> DoOnSubscribe<Self, Cancellable, Item, Error, SubscribeFn>
+    where
+        Self: Sized,
+        SubscribeFn: FnMut() + Send,
+    {
+        DoOnSubscribe::new(self, subscribe_fn)
+    }
+
+    /// Returns an [`Observable`] that emits only the item at `index` of the current
+    /// `Observable` and cancels it as soon as that item was received. Signals
+    /// [`Error::NoSuchElement`] if the current `Observable` completes early.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn element_at(self, index: usize) -> ElementAt<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        ElementAt::new(self, index, None)
+    }
+
+    /// Returns an [`Observable`] that emits `items` after the current `Observable` completed.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn end_with<IntoIter>(self, items: IntoIter) -> WithItems<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+        IntoIter: IntoIterator<Item = Item>,
+    {
+        WithItems::new(self, vec![], items.into_iter().collect(), None)
+    }
+
+    /// Returns an [`Observable`] that emits only those items of the current `Observable` for
+    /// which the future returned by `predicate` resolves to `true`. Up to `concurrency`
+    /// futures run at once on the specified [`Scheduler`] and the items are emitted in the
+    /// order of the current `Observable`. Panics if `concurrency` is zero.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn filter_async<Predicate, Fut, Scheduler>(
+        self,
+        predicate: Predicate,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Cancellable, Item, Error, Item, Scheduler>
+    where
+        Self: Sized,
+        Predicate: FnMut(&Item) -> Fut + Send + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(concurrency > 0, "Observable::filter_async: concurrency must be positive");
+        MapAsync::new(self, filter_async_fn(predicate), concurrency, true, scheduler)
+    }
+
+    /// Returns an [`Observable`] like [`filter_async`] which emits the items as soon as their
+    /// futures resolve, regardless of the order of the current `Observable`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`filter_async`]: trait.Observable.html#method.filter_async
+    fn filter_async_unordered<Predicate, Fut, Scheduler>(
+        self,
+        predicate: Predicate,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Cancellable, Item, Error, Item, Scheduler>
+    where
+        Self: Sized,
+        Predicate: FnMut(&Item) -> Fut + Send + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(
+            concurrency > 0,
+            "Observable::filter_async_unordered: concurrency must be positive"
+        );
+        MapAsync::new(self, filter_async_fn(predicate), concurrency, false, scheduler)
+    }
+
+    /// Returns an [`Observable`] that calls `finally_fn` exactly once after the current
+    /// `Observable` completed or failed, or after the returned `Observable` was cancelled,
+    /// whichever happens first. This also holds if these events race on different threads.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn finally<FinallyFn>(
+        self,
+        finally_fn: FinallyFn,
+    ) -> Finally<Self, Cancellable, Item, Error, FinallyFn>
+    where
+        Self: Sized,
+        FinallyFn: FnOnce() + Send + 'static,
+    {
+        Finally::new(self, finally_fn)
+    }
+
+    /// Returns an [`Observable`] that emits only the first item of the current `Observable`.
+    /// Signals [`Error::NoSuchElement`] if the current `Observable` is empty.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn first(self) -> ElementAt<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        ElementAt::new(self, 0, None)
+    }
+
+    /// Like [`first`], but emits `default` if the current [`Observable`] is empty.
+    ///
+    /// [`first`]: trait.Observable.html#method.first
+    /// [`Observable`]: trait.Observable.html
+    fn first_or(self, default: Item) -> ElementAt<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        ElementAt::new(self, 0, Some(default))
+    }
+
+    /// Returns an [`Observable`] that groups the items of the current `Observable` by the key
+    /// returned by `key_fn` and emits a [`GroupedObservable`] for each distinct key. Groups are
+    /// created lazily and a group whose observer cancelled is replaced by a new group once
+    /// another item with its key arrives. Once the returned `Observable` is cancelled no further
+    /// groups are created, but the current `Observable` is only cancelled after every live group
+    /// was cancelled as well.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`GroupedObservable`]: ../observable/operators/struct.GroupedObservable.html
+    fn group_by<Key, KeyFn>(self, key_fn: KeyFn) -> GroupBy<Self, Cancellable, Item, Error, Key, KeyFn>
+    where
+        Self: Sized,
+        Error: Clone,
+        Key: Clone + Eq + Hash + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        GroupBy::new(self, key_fn, None)
+    }
+
+    /// Like [`group_by`], but additionally completes every group that did not receive an item
+    /// for at least `eviction`, as measured by the clock of the specified [`Scheduler`] whose
+    /// timers drive the eviction.
+    ///
+    /// [`group_by`]: trait.Observable.html#method.group_by
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn group_by_with_eviction<Key, KeyFn, Scheduler>(
+        self,
+        key_fn: KeyFn,
+        eviction: Duration,
+        scheduler: Scheduler,
+    ) -> GroupBy<Self, Cancellable, Item, Error, Key, KeyFn>
+    where
+        Self: Sized,
+        Error: Clone,
+        Key: Clone + Eq + Hash + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        GroupBy::new(self, key_fn, Some(Eviction::new(eviction, scheduler)))
+    }
+
+    /// Returns an [`Observable`] that emits whether the current `Observable` completes without
+    /// emitting any item. The current `Observable` is cancelled as soon as an item was received.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    #[allow(clippy::wrong_self_convention)]
+    fn is_empty(self) -> Any<Self, Cancellable, Item, Error, BoxedPredicate<Item>>
+    where
+        Self: Sized,
+    {
+        Any::new(self, Box::new(|_| false), false)
+    }
+
+    /// Returns an [`Observable`] that emits only the last item of the current `Observable`.
+    /// Signals [`Error::NoSuchElement`] if the current `Observable` is empty.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn last(self) -> Last<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        Last::new(self)
+    }
+
+    /// Returns an [`Observable`] that applies the function `unary_op` to each element of the
+    /// current `Observable` and emits the results of those function calls.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn map<ItemOut, UnaryOp>(
+        self,
+        unary_op: UnaryOp,
+    ) -> Map<Self, Cancellable, Item, Error, ItemOut, UnaryOp>
+    where
+        Self: Sized,
+        UnaryOp: FnMut(Item) -> ItemOut + Send + 'static,
+    {
+        Map::new(self, unary_op)
+    }
+
+    /// Returns an [`Observable`] that applies the asynchronous function `unary_op` to each
+    /// element of the current `Observable` and emits the outputs of the returned futures. Up
+    /// to `concurrency` futures run at once on the specified [`Scheduler`] and the outputs
+    /// are emitted in the order of the current `Observable`. Items are not accepted from the
+    /// current `Observable` while `concurrency` outputs are pending or waiting to be emitted.
+    /// Panics if `concurrency` is zero.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn map_async<ItemOut, UnaryOp, Fut, Scheduler>(
+        self,
+        unary_op: UnaryOp,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Cancellable, Item, Error, ItemOut, Scheduler>
+    where
+        Self: Sized,
+        ItemOut: Send + 'static,
+        UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(concurrency > 0, "Observable::map_async: concurrency must be positive");
+        MapAsync::new(self, map_async_fn(unary_op), concurrency, true, scheduler)
+    }
+
+    /// Returns an [`Observable`] like [`map_async`] which emits the outputs as soon as their
+    /// futures resolve, regardless of the order of the current `Observable`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`map_async`]: trait.Observable.html#method.map_async
+    fn map_async_unordered<ItemOut, UnaryOp, Fut, Scheduler>(
+        self,
+        unary_op: UnaryOp,
+        concurrency: usize,
+        scheduler: Scheduler,
+    ) -> MapAsync<Self, Cancellable, Item, Error, ItemOut, Scheduler>
+    where
+        Self: Sized,
+        ItemOut: Send + 'static,
+        UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(
+            concurrency > 0,
+            "Observable::map_async_unordered: concurrency must be positive"
+        );
+        MapAsync::new(self, map_async_fn(unary_op), concurrency, false, scheduler)
+    }
+
+    fn materialize(
+        self,
+    ) -> Materialize<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        Materialize::new(self)
+    }
+
+    /// Returns an [`Observable`] that emits the greatest item of the current `Observable` once
+    /// it completes. Signals [`Error::NoSuchElement`] if the current `Observable` is empty.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn max(self) -> Aggregate<Self, Cancellable, Item, Error, aggregator::Max<Item>, Item>
+    where
+        Self: Sized,
+        Item: PartialOrd + Clone,
+    {
+        Aggregate::new(self, aggregator::Max::default())
+    }
+
+    /// Returns an [`Observable`] that emits the item of the current `Observable` for which
+    /// `key_fn` returns the greatest key once it completes. Signals [`Error::NoSuchElement`] if
+    /// the current `Observable` is empty.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn max_by_key<Key, KeyFn>(
+        self,
+        key_fn: KeyFn,
+    ) -> Aggregate<Self, Cancellable, Item, Error, aggregator::MaxByKey<Item, Key, KeyFn>, Item>
+    where
+        Self: Sized,
+        Item: Clone,
+        Key: PartialOrd + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        Aggregate::new(self, aggregator::MaxByKey::new(key_fn))
+    }
+
+    /// Returns an [`Observable`] that emits the items of the current `Observable` and `other`
+    /// as they arrive. It completes once both sources completed and emits the first error of
+    /// either source immediately.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn merge_with<Other, OtherCancellable>(self, other: Other) -> Merge<Item, Error, Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Observable<OtherCancellable, Item, Error> + Send + 'static,
+        OtherCancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Merge::merge2(self, other)
+    }
+
+    /// Returns an [`Observable`] that emits the least item of the current `Observable` once it
+    /// completes. Signals [`Error::NoSuchElement`] if the current `Observable` is empty.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn min(self) -> Aggregate<Self, Cancellable, Item, Error, aggregator::Min<Item>, Item>
+    where
+        Self: Sized,
+        Item: PartialOrd + Clone,
+    {
+        Aggregate::new(self, aggregator::Min::default())
+    }
+
+    /// Returns an [`Observable`] that emits the item of the current `Observable` for which
+    /// `key_fn` returns the least key once it completes. Signals [`Error::NoSuchElement`] if the
+    /// current `Observable` is empty.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn min_by_key<Key, KeyFn>(
+        self,
+        key_fn: KeyFn,
+    ) -> Aggregate<Self, Cancellable, Item, Error, aggregator::MinByKey<Item, Key, KeyFn>, Item>
+    where
+        Self: Sized,
+        Item: Clone,
+        Key: PartialOrd + Send + 'static,
+        KeyFn: FnMut(&Item) -> Key + Send + 'static,
+    {
+        Aggregate::new(self, aggregator::MinByKey::new(key_fn))
+    }
+
+    /// Returns an [`Observable`] that performs the current `Observable`'s emissions on the
+    /// specified [`Scheduler`]. Note that `onError` notifications will not be sent in order
+    /// i.e. not all items sent before the error may be re-emitted on the scheduler.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn observe_on<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> ObserveOn<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Dematerialize::new(ObserveOnRaw::new(Materialize::new(self), scheduler))
+    }
+
+    /// Returns an [`Observable`] that completes instead of failing if the current `Observable`
+    /// fails.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn on_error_complete(self) -> OnErrorComplete<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        OnErrorComplete::new(self)
+    }
+
+    /// Returns an [`Observable`] that switches to the `Observable` returned by `resume_fn` if the
+    /// current `Observable` fails.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn on_error_resume_next<Fallback, FallbackCancellable, ResumeFn>(
+        self,
+        resume_fn: ResumeFn,
+    ) -> OnErrorResumeNext<Self, Cancellable, Item, Error, Fallback, FallbackCancellable, ResumeFn>
+    where
+        Self: Sized,
+        Fallback: core::Observable<FallbackCancellable, Item, Error>,
+        FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+        ResumeFn: FnMut(Error) -> Fallback + Send,
+    {
+        OnErrorResumeNext::new(self, resume_fn)
+    }
+
+    /// Returns an [`Observable`] that emits the item returned by `item_fn` and then completes if
+    /// the current `Observable` fails.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn on_error_return<ItemFn>(
+        self,
+        item_fn: ItemFn,
+    ) -> OnErrorReturn<Self, Cancellable, Item, Error, ItemFn>
+    where
+        Self: Sized,
+        ItemFn: FnMut(Error) -> Item + Send,
+    {
+        OnErrorReturn::new(self, item_fn)
+    }
+
+    /// Returns an [`Observable`] that emits each item of the current `Observable` together with
+    /// its predecessor as a `(previous, current)` pair. The first item is only emitted as the
+    /// predecessor of the second one.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn pairwise(self) -> Pairwise<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+        Item: Clone,
+    {
+        Pairwise::new(self)
+    }
+
+    /// Returns an [`Observable`] that subscribes to the current `Observable` `count` times in
+    /// total, each time after the previous subscription completed. The returned `Observable`
+    /// completes after the last repetition, or right away if `count` is zero.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn repeat(self, count: usize) -> Repeat<Self, Cancellable, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+    {
+        Repeat::new(self, count)
+    }
+
+    /// Returns an [`Observable`] that resubscribes to the current `Observable` whenever it
+    /// completes.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn repeat_forever(self) -> Repeat<Self, Cancellable, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+    {
+        Repeat::forever(self)
+    }
+
+    /// Returns an [`Observable`] that passes the [`Observable`] of all completions of the
+    /// current `Observable` to `repeat_when` and subscribes to the returned notifier. The
+    /// current `Observable` is resubscribed to for each item of the notifier after it completed,
+    /// while the returned `Observable` terminates once the notifier completes or fails.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn repeat_when<RepeatWhen, Notifier, NotifierCancellable, NotifierItem>(
+        self,
+        repeat_when: RepeatWhen,
+    ) -> Repeat<Self, Cancellable, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        RepeatWhen: FnOnce(UnicastSubject<Never, (), Error>) -> Notifier + Send + 'static,
+        Notifier: core::Observable<NotifierCancellable, NotifierItem, Error> + Send + 'static,
+        NotifierCancellable: core::Cancellable + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Repeat::when(self, repeat_when)
+    }
+
+    /// Returns an [`Observable`] that resubscribes to the current `Observable` if it fails, at
+    /// most `count` times in total. Once all retries are used up, the error is passed on.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn retry(self, count: usize) -> Retry<Self, Cancellable, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+    {
+        Retry::new(self, count)
+    }
+
+    /// Returns an [`Observable`] that resubscribes to the current `Observable` if it fails with an
+    /// error for which `predicate` returns true. Any other error is passed on.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn retry_if<Predicate>(self, predicate: Predicate) -> Retry<Self, Cancellable, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        Predicate: FnMut(&Error) -> bool + Send + 'static,
+    {
+        Retry::with_predicate(self, predicate)
+    }
+
+    /// Returns an [`Observable`] that passes the [`Observable`] of all errors of the current
+    /// `Observable` to `retry_when` and subscribes to the returned notifier. The current
+    /// `Observable` is resubscribed to for each item of the notifier after it failed, while the
+    /// returned `Observable` terminates once the notifier completes or fails.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn retry_when<RetryWhen, Notifier, NotifierCancellable, NotifierItem>(
+        self,
+        retry_when: RetryWhen,
+    ) -> Retry<Self, Cancellable, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        RetryWhen: FnOnce(UnicastSubject<Never, Error, Error>) -> Notifier + Send + 'static,
+        Notifier: core::Observable<NotifierCancellable, NotifierItem, Error> + Send + 'static,
+        NotifierCancellable: core::Cancellable + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Retry::when(self, retry_when)
+    }
+
+    /// Returns an [`Observable`] that resubscribes to the current `Observable` whenever it fails,
+    /// after a delay timed on the given [`Scheduler`]. The delay starts at `initial`, is
+    /// multiplied by `factor` on each retry and is capped at `max`. Each delay is randomly varied
+    /// by up to the fraction `jitter` of itself. Once the current `Observable` emits an item, the
+    /// delay starts over.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn retry_with_backoff<Scheduler>(
+        self,
+        initial: Duration,
+        max: Duration,
+        factor: f64,
+        jitter: f64,
+        scheduler: Scheduler,
+    ) -> Retry<Self, Cancellable, Item, Error>
+    where
+        Self: Sized + Clone + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        assert!(
+            factor >= 1.0,
+            "Observable::retry_with_backoff: factor must be at least one"
+        );
+        assert!(
+            (0.0..=1.0).contains(&jitter),
+            "Observable::retry_with_backoff: jitter must be between zero and one"
+        );
+        Retry::with_backoff(self, initial, max, factor, jitter, scheduler)
+    }
+
+    /// Returns an [`Observable`] that emits the arithmetic mean of all items of the current
+    /// `Observable` so far for each of its items.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn running_average(
+        self,
+    ) -> RunningAggregate<Self, Cancellable, Item, Error, aggregator::Average, f64>
     where
         Self: Sized,
+        Item: Into<f64>,
     {
-        Dematerialize::new(self)
+        RunningAggregate::new(self, aggregator::Average::default())
     }
 
-    /// Returns an [`Observable`] that applies the function `unary_op` to each element of the
-    /// current `Observable` and emits the results of those function calls.
+    /// Like [`running_average`], but averages the values which `value_fn` maps the items to.
+    ///
+    /// [`running_average`]: trait.Observable.html#method.running_average
+    fn running_average_by<ValueFn>(
+        self,
+        value_fn: ValueFn,
+    ) -> RunningAggregate<Self, Cancellable, Item, Error, aggregator::AverageBy<ValueFn>, f64>
+    where
+        Self: Sized,
+        ValueFn: FnMut(Item) -> f64 + Send + 'static,
+    {
+        RunningAggregate::new(self, aggregator::AverageBy::new(value_fn))
+    }
+
+    /// Returns an [`Observable`] that emits the number of items of the current `Observable` so
+    /// far for each of its items.
     ///
     /// [`Observable`]: trait.Observable.html
-    fn map<ItemOut, UnaryOp>(
+    fn running_count(
         self,
-        unary_op: UnaryOp,
-    ) -> Map<Self, Cancellable, Item, Error, ItemOut, UnaryOp>
+    ) -> RunningAggregate<Self, Cancellable, Item, Error, aggregator::Count, usize>
     where
         Self: Sized,
-        UnaryOp: FnMut(Item) -> ItemOut + Send + 'static,
     {
-        Map::new(self, unary_op)
+        RunningAggregate::new(self, aggregator::Count::default())
     }
 
-    fn materialize(
+    /// Returns an [`Observable`] that emits the greatest item of the current `Observable` so far
+    /// for each of its items.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn running_max(
         self,
-    ) -> Materialize<Self, Cancellable, Item, Error>
+    ) -> RunningAggregate<Self, Cancellable, Item, Error, aggregator::Max<Item>, Item>
     where
         Self: Sized,
+        Item: PartialOrd + Clone,
     {
-        Materialize::new(self)
+        RunningAggregate::new(self, aggregator::Max::default())
     }
 
-    /// Returns an [`Observable`] that performs the current `Observable`'s emissions on the
-    /// specified [`Scheduler`]. Note that `onError` notifications will not be sent in order
-    /// i.e. not all items sent before the error may be re-emitted on the scheduler.
+    /// Returns an [`Observable`] that emits the least item of the current `Observable` so far
+    /// for each of its items.
     ///
     /// [`Observable`]: trait.Observable.html
-    /// [`Scheduler`]: trait.Scheduler.html
-    fn observe_on<Scheduler>(
+    fn running_min(
+        self,
+    ) -> RunningAggregate<Self, Cancellable, Item, Error, aggregator::Min<Item>, Item>
+    where
+        Self: Sized,
+        Item: PartialOrd + Clone,
+    {
+        RunningAggregate::new(self, aggregator::Min::default())
+    }
+
+    /// Returns an [`Observable`] that emits the sum of all items of the current `Observable` so
+    /// far for each of its items.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn running_sum(
+        self,
+    ) -> RunningAggregate<Self, Cancellable, Item, Error, aggregator::Sum<Item>, Item>
+    where
+        Self: Sized,
+        Item: Add<Output = Item> + Clone + Default,
+    {
+        RunningAggregate::new(self, aggregator::Sum::default())
+    }
+
+    /// Returns an [`Observable`] that emits the most recent item emitted by the current
+    /// `Observable` at the end of every `period`, starting at subscription. Periods in which no
+    /// item was emitted are skipped and an item still pending on completion is dropped.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn sample<Scheduler>(
         self,
+        period: Duration,
         scheduler: Scheduler,
-    ) -> ObserveOn<Self, Cancellable, Item, Error, Scheduler>
+    ) -> Sample<Self, Cancellable, Item, Error, Scheduler>
     where
         Self: Sized,
         Scheduler: core::Scheduler + Send + 'static,
     {
-        Dematerialize::new(ObserveOnRaw::new(Materialize::new(self), scheduler))
+        Sample::new(self, period, scheduler)
+    }
+
+    /// Returns an [`Observable`] that emits the most recent item emitted by the current
+    /// `Observable` whenever `sampler` emits an item. The returned `Observable` completes as soon
+    /// as either the current `Observable` or `sampler` completes.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn sample_with<Sampler, SamplerCancellable, SamplerItem>(
+        self,
+        sampler: Sampler,
+    ) -> SampleWith<Self, Cancellable, Item, Error, Sampler, SamplerCancellable, SamplerItem>
+    where
+        Self: Sized,
+        Item: Send + 'static,
+        Error: Send + 'static,
+        Sampler: core::Observable<SamplerCancellable, SamplerItem, Error>,
+        SamplerCancellable: core::Cancellable + Send + Sync + 'static,
+        SamplerItem: Send + 'static,
+    {
+        SampleWith::new(self, sampler)
     }
 
     /// Returns an [`Observable`] that first emits the provided `initial_value` as an item and the
@@ -127,6 +1160,47 @@ where
         Scan::new(self, initial_value, binary_op)
     }
 
+    /// Returns an [`Observable`] that emits the only item of the current `Observable`. Signals
+    /// [`Error::NoSuchElement`] if the current `Observable` is empty and
+    /// [`Error::TooManyElements`] if it emits more than one item.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    /// [`Error::TooManyElements`]: ../observable/enum.Error.html#variant.TooManyElements
+    fn single(self) -> Single<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        Single::new(self)
+    }
+
+    /// Returns an [`Observable`] that emits overlapping windows of the last `count` items of the
+    /// current `Observable`, one for each item once `count` items were received. The windows
+    /// share the storage of their items, so neither creating nor keeping them around copies
+    /// the items.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn sliding(self, count: usize) -> Sliding<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+        Item: Sync,
+    {
+        assert!(count > 0, "Observable::sliding: count must be greater than zero");
+        Sliding::new(self, count)
+    }
+
+    /// Returns an [`Observable`] that emits `items` before the items of the current
+    /// `Observable`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn start_with<IntoIter>(self, items: IntoIter) -> WithItems<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+        IntoIter: IntoIterator<Item = Item>,
+    {
+        WithItems::new(self, items.into_iter().collect(), vec![], None)
+    }
+
     /// Asynchronously subscribes [`Observer`]s to the current [`Observable`] on the given
     /// [`Scheduler`].
     ///
@@ -143,4 +1217,339 @@ where
     {
         SubscribeOn::new(self, scheduler)
     }
+
+    /// Returns an [`Observable`] that emits the sum of all items of the current `Observable` once
+    /// it completes. The sum of an empty `Observable` is `Item::default()`, whereas [`min`] and
+    /// [`max`] signal [`Error::NoSuchElement`] in that case.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`min`]: trait.Observable.html#method.min
+    /// [`max`]: trait.Observable.html#method.max
+    /// [`Error::NoSuchElement`]: ../observable/enum.Error.html#variant.NoSuchElement
+    fn sum(self) -> Aggregate<Self, Cancellable, Item, Error, aggregator::Sum<Item>, Item>
+    where
+        Self: Sized,
+        Item: Add<Output = Item> + Clone + Default,
+    {
+        Aggregate::new(self, aggregator::Sum::default())
+    }
+
+    /// Returns an [`Observable`] that subscribes to `fallback` if the current `Observable`
+    /// completes without emitting any items.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn switch_if_empty<Fallback, FallbackCancellable>(
+        self,
+        fallback: Fallback,
+    ) -> SwitchIfEmpty<Self, Cancellable, Item, Error, Fallback, FallbackCancellable>
+    where
+        Self: Sized,
+        Fallback: core::Observable<FallbackCancellable, Item, Error> + Send + 'static,
+        FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        SwitchIfEmpty::new(self, fallback)
+    }
+
+    /// Returns an [`Observable`] that emits an item of the current `Observable` and then drops
+    /// all items emitted within the following `duration`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn throttle_first<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> ThrottleFirst<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        ThrottleFirst::new(self, duration, scheduler)
+    }
+
+    /// Alias for [`sample`].
+    ///
+    /// [`sample`]: trait.Observable.html#method.sample
+    fn throttle_last<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Sample<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Sample::new(self, duration, scheduler)
+    }
+
+    /// Returns an [`Observable`] that emits an item of the current `Observable` and then waits for
+    /// `duration`. If further items were emitted in the meantime, the latest of those is emitted
+    /// at the end of the `duration` and the wait starts again. If `emit_last` is set, an item
+    /// still pending on completion is emitted before completing.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn throttle_latest<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+        emit_last: bool,
+    ) -> ThrottleLatest<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        ThrottleLatest::new(self, duration, scheduler, emit_last)
+    }
+
+    /// Returns an [`Observable`] that emits each item together with the time elapsed since the
+    /// previous item, or since the subscription for the first item, as read from the clock of
+    /// the given [`Scheduler`].
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn time_interval<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> TimeInterval<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        TimeInterval::new(self, scheduler)
+    }
+
+    /// Returns an [`Observable`] that fails with [`observable::Error::Timeout`] and cancels the
+    /// current `Observable` if it does not emit its first or next item within `duration`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`observable::Error::Timeout`]: ../observable/enum.Error.html#variant.Timeout
+    fn timeout<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Timeout<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timeout::new(self, duration, Box::new(move |_| duration), scheduler, None)
+    }
+
+    /// Like [`timeout`], but allows the first item to take up to `first` instead.
+    ///
+    /// [`timeout`]: trait.Observable.html#method.timeout
+    fn timeout_first<Scheduler>(
+        self,
+        first: Duration,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> Timeout<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timeout::new(self, first, Box::new(move |_| duration), scheduler, None)
+    }
+
+    /// Like [`timeout`], but switches to `fallback` instead of failing once the current
+    /// [`Observable`] timed out.
+    ///
+    /// [`timeout`]: trait.Observable.html#method.timeout
+    /// [`Observable`]: trait.Observable.html
+    fn timeout_or<Scheduler, Fallback, FallbackCancellable>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+        fallback: Fallback,
+    ) -> Timeout<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+        Fallback: core::Observable<FallbackCancellable, Item, Error> + Send + 'static,
+        FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Timeout::new(
+            self,
+            duration,
+            Box::new(move |_| duration),
+            scheduler,
+            Some(TimeoutFallback::new(fallback)),
+        )
+    }
+
+    /// Like [`timeout_first`], but the time allowed for the item following each item is determined
+    /// by applying `timeout_fn` to it.
+    ///
+    /// [`timeout_first`]: trait.Observable.html#method.timeout_first
+    fn timeout_with<TimeoutFn, Scheduler>(
+        self,
+        first: Duration,
+        timeout_fn: TimeoutFn,
+        scheduler: Scheduler,
+    ) -> Timeout<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        TimeoutFn: FnMut(&Item) -> Duration + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timeout::new(self, first, Box::new(timeout_fn), scheduler, None)
+    }
+
+    /// Returns an [`Observable`] that emits each item together with the instant it was received
+    /// at, as read from the clock of the given [`Scheduler`].
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn timestamp<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> Timestamp<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Timestamp::new(self, scheduler)
+    }
+
+    /// Returns an [`Observable`] that emits non-overlapping windows of at most `count` items
+    /// each. Each window is itself an [`Observable`] which buffers its items until it is
+    /// subscribed to and may be cancelled independently of the returned `Observable`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn window(self, count: usize) -> Window<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+        Error: Clone,
+    {
+        assert!(count > 0, "Observable::window: count must be greater than zero");
+        Window::new(self, count)
+    }
+
+    /// Returns an [`Observable`] that emits a new window whenever `boundary` emits an item.
+    /// The returned `Observable` terminates as soon as either the current `Observable` or
+    /// `boundary` terminates.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn window_boundary<Boundary, BoundaryCancellable, BoundaryItem>(
+        self,
+        boundary: Boundary,
+    ) -> WindowBoundary<Self, Cancellable, Item, Error, Boundary, BoundaryCancellable, BoundaryItem>
+    where
+        Self: Sized,
+        Error: Clone,
+        Boundary: core::Observable<BoundaryCancellable, BoundaryItem, Error>,
+        BoundaryCancellable: core::Cancellable + Send + Sync + 'static,
+        BoundaryItem: Send + 'static,
+    {
+        WindowBoundary::new(self, boundary)
+    }
+
+    /// Returns an [`Observable`] that emits a new window every `duration`, timed on the given
+    /// [`Scheduler`].
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    fn window_time<Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: Scheduler,
+    ) -> WindowTime<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Error: Clone,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        WindowTime::new(self, duration, scheduler)
+    }
+
+    /// Returns an [`Observable`] that emits an item for every item of the current `Observable`,
+    /// combined with the latest item of `other` using `combiner`. Items arriving before `other`
+    /// emitted its first item are dropped.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn with_latest_from<Other, OtherCancellable, OtherItem, ItemOut, CombineFn>(
+        self,
+        other: Other,
+        combiner: CombineFn,
+    ) -> WithLatestFrom<
+        Self,
+        Cancellable,
+        Item,
+        Error,
+        Other,
+        OtherCancellable,
+        OtherItem,
+        ItemOut,
+        CombineFn,
+    >
+    where
+        Self: Sized,
+        Other: core::Observable<OtherCancellable, OtherItem, Error> + Send + 'static,
+        OtherCancellable: core::Cancellable + Send + Sync + 'static,
+        OtherItem: Send + 'static,
+        CombineFn: FnMut(Item, &OtherItem) -> ItemOut,
+    {
+        WithLatestFrom::new(self, other, combiner)
+    }
+
+    /// Returns an [`Observable`] that combines the items of the current `Observable` and
+    /// `other` positionally into pairs. It completes as soon as either source completed and
+    /// all of its items were emitted, cancelling the other source.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    fn zip<Other, OtherCancellable, OtherItem>(
+        self,
+        other: Other,
+    ) -> ZipTuple<(Item, OtherItem), Error>
+    where
+        Self: Sized + Send + 'static,
+        Other: core::Observable<OtherCancellable, OtherItem, Error> + Send + 'static,
+        OtherCancellable: core::Cancellable + Send + Sync + 'static,
+        OtherItem: Send + 'static,
+    {
+        Zip::zip2(self, other)
+    }
+
+    /// Like [`zip`], but combines the items of three sources into triples.
+    ///
+    /// [`zip`]: trait.Observable.html#method.zip
+    fn zip3<ObservableB, CancellableB, B, ObservableC, CancellableC, C>(
+        self,
+        b: ObservableB,
+        c: ObservableC,
+    ) -> ZipTuple<(Item, B, C), Error>
+    where
+        Self: Sized + Send + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        B: Send + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+        C: Send + 'static,
+    {
+        Zip::zip3(self, b, c)
+    }
+
+    /// Like [`zip`], but combines the items of four sources into quadruples.
+    ///
+    /// [`zip`]: trait.Observable.html#method.zip
+    fn zip4<ObservableB, CancellableB, B, ObservableC, CancellableC, C, ObservableD, CancellableD, D>(
+        self,
+        b: ObservableB,
+        c: ObservableC,
+        d: ObservableD,
+    ) -> ZipTuple<(Item, B, C, D), Error>
+    where
+        Self: Sized + Send + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        B: Send + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+        C: Send + 'static,
+        ObservableD: core::Observable<CancellableD, D, Error> + Send + 'static,
+        CancellableD: core::Cancellable + Send + Sync + 'static,
+        D: Send + 'static,
+    {
+        Zip::zip4(self, b, c, d)
+    }
 }
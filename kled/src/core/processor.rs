@@ -1,6 +1,10 @@
 use crate::core;
 
-pub trait Processor<SubscriptionIn, Item, Error>:
-    core::Flow + core::Subscriber<SubscriptionIn, Item, Error>
+pub trait Processor<SubscriptionIn, SubscriptionOut, Item, Error>:
+    core::Flow<SubscriptionOut, Item, Error> + core::Subscriber<SubscriptionIn, Item, Error>
+where
+    SubscriptionOut: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
 {
 }
@@ -1,4 +1,6 @@
 use std::future::Future;
+#[chronobreak]
+use std::time::Instant;
 
 pub trait Scheduler: Clone + Send + Sync + 'static {
     fn schedule<Fut>(&self, future: Fut)
@@ -6,4 +8,8 @@ pub trait Scheduler: Clone + Send + Sync + 'static {
         Fut: Future<Output = ()> + Send + 'static;
 
     fn join(&self);
+
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
 }
@@ -35,6 +35,10 @@ impl<Item, Error> BoxEmitter<Item, Error> {
         self.subscriber.on_completed().await;
     }
 
+    pub fn requested(&self) -> usize {
+        self.stub.requested()
+    }
+
     pub fn is_cancelled(&self) -> bool {
         self.stub.is_cancelled()
     }
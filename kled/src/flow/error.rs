@@ -2,6 +2,11 @@
 pub enum Error<UpstreamError> {
     Upstream(UpstreamError),
     MissingBackpressure,
+    NoSuchElement,
+    TooManyElements,
+    Timeout,
+    AlreadySubscribed,
+    Disconnected,
 }
 
 impl<UpstreamError> Error<UpstreamError> {
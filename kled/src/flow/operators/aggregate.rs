@@ -0,0 +1,157 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::ElementSubscription;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    item = "Output",
+    subscription = "ElementSubscription<Subscription>"
+)]
+pub struct Aggregate<Aggregator, Output>
+where
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+{
+    aggregator: Aggregator,
+}
+
+#[derive(new)]
+struct AggregateSubscriber<Subscriber, Aggregator> {
+    subscriber: Subscriber,
+    aggregator: Aggregator,
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Aggregator, Output>
+    core::Subscriber<Subscription, Item, Error> for AggregateSubscriber<Subscriber, Aggregator>
+where
+    Subscriber: core::Subscriber<ElementSubscription<Subscription>, Output, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+    Output: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(ElementSubscription::new(Arc::new(subscription), usize::MAX))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.aggregator.update(item);
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        match self.aggregator.output() {
+            Some(output) => {
+                self.subscriber.on_next(output).await;
+                self.subscriber.on_completed().await;
+            }
+            None => self.subscriber.on_error(flow::Error::NoSuchElement).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn sum() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .sum()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, usize::MAX);
+        test_flow.emit_all(vec![1, 2, 3]).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![6]);
+    }
+
+    #[async_std::test]
+    async fn count_and_average() {
+        let count_subscriber = TestSubscriber::new(1);
+        vec![1, 2, 3, 6]
+            .into_flow()
+            .count()
+            .subscribe(count_subscriber.clone())
+            .await;
+        let average_subscriber = TestSubscriber::new(1);
+        vec![1, 2, 3, 6]
+            .into_flow()
+            .average()
+            .subscribe(average_subscriber.clone())
+            .await;
+
+        assert_eq!(count_subscriber.items().await, vec![4]);
+        assert_eq!(average_subscriber.items().await, vec![3.0]);
+    }
+
+    #[async_std::test]
+    async fn average_by() {
+        let test_subscriber = TestSubscriber::new(1);
+        vec![1i64, 2, 6]
+            .into_flow()
+            .average_by(|item| item as f64)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![3.0]);
+    }
+
+    #[async_std::test]
+    async fn min_max() {
+        let min_subscriber = TestSubscriber::new(1);
+        vec![3, 1, 2]
+            .into_flow()
+            .min()
+            .subscribe(min_subscriber.clone())
+            .await;
+        let max_by_key_subscriber = TestSubscriber::new(1);
+        vec!["a", "ccc", "bb"]
+            .into_flow()
+            .max_by_key(|item| item.len())
+            .subscribe(max_by_key_subscriber.clone())
+            .await;
+
+        assert_eq!(min_subscriber.items().await, vec![1]);
+        assert_eq!(max_by_key_subscriber.items().await, vec!["ccc"]);
+    }
+
+    #[async_std::test]
+    async fn empty() {
+        let sum_subscriber = TestSubscriber::new(1);
+        Vec::<i32>::new()
+            .into_flow()
+            .sum()
+            .subscribe(sum_subscriber.clone())
+            .await;
+        let min_subscriber = TestSubscriber::new(1);
+        Vec::<i32>::new()
+            .into_flow()
+            .min_by_key(|item| -item)
+            .subscribe(min_subscriber.clone())
+            .await;
+
+        assert_eq!(sum_subscriber.items().await, vec![0]);
+        assert_eq!(
+            min_subscriber.error().await,
+            Some(flow::Error::NoSuchElement)
+        );
+    }
+}
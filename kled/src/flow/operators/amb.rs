@@ -0,0 +1,318 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedSource<Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedSubscriber<Item, Error> =
+    Box<dyn core::Subscriber<AmbSubscription<Item, Error>, Item, Error> + Send + 'static>;
+
+type BoxedSubscription = Arc<dyn core::Subscription + Send + Sync>;
+
+/// Mirrors the first of multiple sources to emit any signal. All sources are
+/// subscribed to and receive the demand requested before the winner is
+/// known, while all other sources are cancelled as soon as it is.
+pub struct Amb<Item, Error> {
+    sources: Vec<BoxedSource<Item, Error>>,
+}
+
+impl<Item, Error> Amb<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn with_source<Flow, Subscription>(mut self, flow: Flow) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move { flow.subscribe(AmbSubscriber { shared, index }).await })
+        }));
+        self
+    }
+
+    pub fn amb_all<Flow, Subscription>(sources: Vec<Flow>) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        sources
+            .into_iter()
+            .fold(Self { sources: vec![] }, |amb, source| {
+                amb.with_source(source)
+            })
+    }
+
+    pub fn amb2<FlowA, SubscriptionA, FlowB, SubscriptionB>(a: FlowA, b: FlowB) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, Item, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, Item, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+    {
+        Self::amb_all::<FlowA, SubscriptionA>(vec![])
+            .with_source(a)
+            .with_source(b)
+    }
+}
+
+/// Returns a [`Flow`] that mirrors the first of all `sources` to emit an
+/// item, an error or its completion and cancels all other sources.
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+pub fn amb<Flow, Subscription, Item, Error>(sources: Vec<Flow>) -> Amb<Item, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Amb::amb_all(sources)
+}
+
+#[async_trait]
+impl<Item, Error> core::Flow<AmbSubscription<Item, Error>, Item, Error> for Amb<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<AmbSubscription<Item, Error>, Item, Error> + Send + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            subscriber: Mutex::new(None),
+            state: Mutex::new(State {
+                subscriptions: (0..count).map(|_| None).collect(),
+                winner: None,
+                requested: 0,
+                is_cancelled: false,
+            }),
+        });
+        subscriber
+            .on_subscribe(AmbSubscription {
+                shared: shared.clone(),
+                cancelled: AtomicBool::new(false),
+            })
+            .await;
+        if count == 0 {
+            subscriber.on_completed().await;
+            return;
+        }
+        *shared.subscriber.lock().await = Some(Box::new(subscriber));
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Item, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<Item, Error>>>,
+    state: Mutex<State>,
+}
+
+struct State {
+    subscriptions: Vec<Option<BoxedSubscription>>,
+    winner: Option<usize>,
+    requested: usize,
+    is_cancelled: bool,
+}
+
+impl<Item, Error> Shared<Item, Error> {
+    /// Returns whether the source at `index` is the winner, deciding it to be
+    /// if there is none yet and cancelling all other sources in that case.
+    async fn win(&self, index: usize) -> bool {
+        let losers: Vec<BoxedSubscription> = {
+            let mut state = self.state.lock().await;
+            match state.winner {
+                Some(winner) => return winner == index,
+                None => state.winner = Some(index),
+            }
+            state
+                .subscriptions
+                .iter_mut()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .filter_map(|(_, subscription)| subscription.take())
+                .collect()
+        };
+        for subscription in losers {
+            subscription.cancel().await;
+        }
+        true
+    }
+}
+
+struct AmbSubscriber<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+    index: usize,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for AmbSubscriber<Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription: BoxedSubscription = Arc::new(subscription);
+        let requested = {
+            let mut state = self.shared.state.lock().await;
+            if state.is_cancelled || matches!(state.winner, Some(winner) if winner != self.index) {
+                None
+            } else {
+                state.subscriptions[self.index] = Some(subscription.clone());
+                Some(state.requested)
+            }
+        };
+        match requested {
+            None => subscription.cancel().await,
+            Some(0) => {}
+            Some(requested) => subscription.request(requested).await,
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.shared.win(self.index).await {
+            if let Some(subscriber) = self.shared.subscriber.lock().await.as_mut() {
+                subscriber.on_next(item).await;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if self.shared.win(self.index).await {
+            let subscriber = self.shared.subscriber.lock().await.take();
+            if let Some(mut subscriber) = subscriber {
+                subscriber.on_error(error).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if self.shared.win(self.index).await {
+            let subscriber = self.shared.subscriber.lock().await.take();
+            if let Some(mut subscriber) = subscriber {
+                subscriber.on_completed().await;
+            }
+        }
+    }
+}
+
+pub struct AmbSubscription<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+    cancelled: AtomicBool,
+}
+
+#[async_trait]
+impl<Item, Error> core::Subscription for AmbSubscription<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        let subscriptions: Vec<BoxedSubscription> = {
+            let mut state = self.shared.state.lock().await;
+            state.is_cancelled = true;
+            state
+                .subscriptions
+                .iter_mut()
+                .filter_map(Option::take)
+                .collect()
+        };
+        for subscription in subscriptions {
+            subscription.cancel().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        let subscriptions: Vec<BoxedSubscription> = {
+            let mut state = self.shared.state.lock().await;
+            if state.winner.is_none() {
+                state.requested = state.requested.saturating_add(count);
+            }
+            state.subscriptions.iter().flatten().cloned().collect()
+        };
+        for subscription in subscriptions {
+            subscription.request(count).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn amb() {
+        let test_subscriber = TestSubscriber::new(2);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        operators::amb(vec![a.clone(), b.clone()])
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(a.requested().await, 2);
+        assert_eq!(b.requested().await, 2);
+        b.emit(0).await;
+        assert!(a.is_cancelled().await);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(a.requested().await, 2);
+        assert_eq!(b.requested().await, 3);
+        b.emit(1).await;
+        b.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+    }
+
+    #[async_std::test]
+    async fn amb_with() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        vec![0, 1]
+            .into_flow()
+            .amb_with(vec![2, 3].into_flow())
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_subscriber = TestSubscriber::new(1);
+        let a = TestFlow::default().annotate_item_type(0);
+        let b = TestFlow::default().annotate_item_type(0);
+        a.clone()
+            .amb_with(b.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_error(()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+        assert!(b.is_cancelled().await);
+    }
+}
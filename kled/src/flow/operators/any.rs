@@ -0,0 +1,148 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::ElementSubscription;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+/// The predicate of operators such as `contains` and `is_empty`.
+pub type BoxedPredicate<Item> = Box<dyn FnMut(&Item) -> bool + Send>;
+
+#[operator(
+    type = "flow",
+    item = "bool",
+    subscription = "ElementSubscription<Subscription>"
+)]
+pub struct Any<PredicateFn>
+where
+    PredicateFn: FnMut(&Item) -> bool + Send,
+{
+    predicate_fn: PredicateFn,
+    until: bool,
+}
+
+/// Emits `until` as soon as `predicate_fn` returns `until` for an item and
+/// `!until` if the upstream completes before.
+struct AnySubscriber<Subscriber, Subscription, PredicateFn> {
+    subscriber: Option<Subscriber>,
+    upstream: Option<Arc<Subscription>>,
+    predicate_fn: PredicateFn,
+    until: bool,
+}
+
+impl<Subscriber, Subscription, PredicateFn> AnySubscriber<Subscriber, Subscription, PredicateFn> {
+    fn new(subscriber: Subscriber, predicate_fn: PredicateFn, until: bool) -> Self {
+        Self {
+            subscriber: Some(subscriber),
+            upstream: None,
+            predicate_fn,
+            until,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, PredicateFn> core::Subscriber<Subscription, Item, Error>
+    for AnySubscriber<Subscriber, Subscription, PredicateFn>
+where
+    Subscriber: core::Subscriber<ElementSubscription<Subscription>, bool, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    PredicateFn: FnMut(&Item) -> bool + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = Arc::new(subscription);
+        self.upstream = Some(subscription.clone());
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber
+                .on_subscribe(ElementSubscription::new(subscription, usize::MAX))
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.subscriber.is_none() || (self.predicate_fn)(&item) != self.until {
+            return;
+        }
+        if let Some(upstream) = self.upstream.take() {
+            upstream.cancel().await;
+        }
+        if let Some(mut subscriber) = self.subscriber.take() {
+            subscriber.on_next(self.until).await;
+            subscriber.on_completed().await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if let Some(mut subscriber) = self.subscriber.take() {
+            subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(mut subscriber) = self.subscriber.take() {
+            subscriber.on_next(!self.until).await;
+            subscriber.on_completed().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn any() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .any(|item| *item > 1)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        test_flow.emit(2).await;
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![true]);
+    }
+
+    #[async_std::test]
+    async fn all() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .all(|item| *item < 2)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1, 2]).await;
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.items().await, vec![false]);
+    }
+
+    #[async_std::test]
+    async fn contains_and_is_empty() {
+        let contains_subscriber = TestSubscriber::new(1);
+        vec![0, 1, 2]
+            .into_flow()
+            .contains(3)
+            .subscribe(contains_subscriber.clone())
+            .await;
+        let is_empty_subscriber = TestSubscriber::new(1);
+        Vec::<i32>::new()
+            .into_flow()
+            .is_empty()
+            .subscribe(is_empty_subscriber.clone())
+            .await;
+
+        assert_eq!(contains_subscriber.items().await, vec![false]);
+        assert_eq!(is_empty_subscriber.items().await, vec![true]);
+    }
+}
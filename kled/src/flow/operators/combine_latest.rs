@@ -0,0 +1,749 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The default number of items requested from each source ahead of their
+/// consumption.
+const PREFETCH: usize = 128;
+
+/// The latest items of the sources of an operator such as
+/// [`Flow::combine_latest`], which are combined into a single item. Each source
+/// pushes into its own typed queue of [`Queues`](CombineLatestItems::Queues)
+/// and its latest item is kept in [`Partial`](CombineLatestItems::Partial)
+/// until every source has emitted an item.
+///
+/// [`Flow::combine_latest`]: ../../core/trait.Flow.html#method.combine_latest
+pub trait CombineLatestItems: Sized + Send + 'static {
+    type Queues: Send + 'static;
+    type Partial: Send + 'static;
+
+    /// Returns whether the source at `index` has not emitted an item yet.
+    fn is_missing(partial: &Self::Partial, index: usize) -> bool;
+
+    /// Moves the next queued item of the source at `index` into `partial` or,
+    /// once every source has emitted an item, into `latest`.
+    fn update(
+        queues: &mut Self::Queues,
+        partial: &mut Self::Partial,
+        latest: &mut Option<Self>,
+        index: usize,
+    );
+}
+
+impl<Item> CombineLatestItems for Vec<Item>
+where
+    Item: Send + 'static,
+{
+    type Queues = Vec<VecDeque<Item>>;
+    type Partial = Vec<Option<Item>>;
+
+    fn is_missing(partial: &Self::Partial, index: usize) -> bool {
+        partial[index].is_none()
+    }
+
+    fn update(
+        queues: &mut Self::Queues,
+        partial: &mut Self::Partial,
+        latest: &mut Option<Self>,
+        index: usize,
+    ) {
+        let item = match queues[index].pop_front() {
+            Some(item) => item,
+            None => return,
+        };
+        match latest.as_mut() {
+            Some(latest) => latest[index] = item,
+            None => {
+                partial[index] = Some(item);
+                if partial.iter().all(Option::is_some) {
+                    *latest = Some(partial.iter_mut().filter_map(Option::take).collect());
+                }
+            }
+        }
+    }
+}
+
+macro_rules! combine_latest_items {
+    ($($item:ident $name:ident $index:tt),+) => {
+        impl<$($item),+> CombineLatestItems for ($($item,)+)
+        where
+            $($item: Send + 'static,)+
+        {
+            type Queues = ($(VecDeque<$item>,)+);
+            type Partial = ($(Option<$item>,)+);
+
+            fn is_missing(partial: &Self::Partial, index: usize) -> bool {
+                match index {
+                    $($index => partial.$index.is_none(),)+
+                    _ => false,
+                }
+            }
+
+            fn update(
+                queues: &mut Self::Queues,
+                partial: &mut Self::Partial,
+                latest: &mut Option<Self>,
+                index: usize,
+            ) {
+                match index {
+                    $($index => match (queues.$index.pop_front(), latest.as_mut()) {
+                        (Some(item), Some(latest)) => latest.$index = item,
+                        (Some(item), None) => partial.$index = Some(item),
+                        (None, _) => return,
+                    },)+
+                    _ => return,
+                }
+                if latest.is_none() {
+                    if $(partial.$index.is_some())&&+ {
+                        if let ($(Some($name),)+) = std::mem::take(partial) {
+                            *latest = Some(($($name,)+));
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+combine_latest_items!(A a 0, B b 1);
+combine_latest_items!(A a 0, B b 1, C c 2);
+combine_latest_items!(A a 0, B b 1, C c 2, D d 3);
+
+type BoxedSource<Items, Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Items, Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedSubscriber<Items, Item, Error> = Box<
+    dyn core::Subscriber<CombineLatestSubscription<Items, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+type BoxedSubscription = Arc<dyn core::Subscription + Send + Sync>;
+
+type Combiner<Items, Item> = Box<dyn FnMut(&Items) -> Item + Send>;
+
+/// Combines the latest items of multiple sources. An item is emitted for each
+/// item of any source once every source has emitted at least one item. Each
+/// source is requested independently with a prefetch window.
+pub struct CombineLatest<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    sources: Vec<BoxedSource<Items, Item, Error>>,
+    queues: Items::Queues,
+    partial: Items::Partial,
+    combiner: Combiner<Items, Item>,
+    complete_on_first: bool,
+    prefetch: usize,
+}
+
+/// A [`CombineLatest`] of a fixed number of sources, such as
+/// [`Flow::combine_latest`], which emits the latest items of its sources as
+/// tuples.
+///
+/// [`Flow::combine_latest`]: ../../core/trait.Flow.html#method.combine_latest
+pub type CombineLatestTuple<Items, Error> = CombineLatest<Items, Items, Error>;
+
+impl<Items, Item, Error> CombineLatest<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn new<CombineFn>(queues: Items::Queues, partial: Items::Partial, combiner: CombineFn) -> Self
+    where
+        CombineFn: FnMut(&Items) -> Item + Send + 'static,
+    {
+        Self {
+            sources: vec![],
+            queues,
+            partial,
+            combiner: Box::new(combiner),
+            complete_on_first: false,
+            prefetch: PREFETCH,
+        }
+    }
+
+    fn with_source<Flow, Subscription, SourceItem, Push>(mut self, flow: Flow, push: Push) -> Self
+    where
+        Flow: core::Flow<Subscription, SourceItem, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        SourceItem: Send + 'static,
+        Push: FnMut(&mut Items::Queues, SourceItem) + Send + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move {
+                flow.subscribe(CombineLatestSubscriber {
+                    shared,
+                    index,
+                    push,
+                    phantom: PhantomData,
+                })
+                .await
+            })
+        }));
+        self
+    }
+
+    /// Completes as soon as the first source completed and all of its items
+    /// were emitted instead of waiting for the completion of all sources.
+    pub fn complete_on_first(mut self) -> Self {
+        self.complete_on_first = true;
+        self
+    }
+
+    /// Requests `prefetch` items from each source ahead of their consumption
+    /// instead of 128. A source is requested again once three quarters of its
+    /// prefetch window were consumed.
+    pub fn with_prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+}
+
+impl<SourceItem, Item, Error> CombineLatest<Vec<SourceItem>, Item, Error>
+where
+    SourceItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest_all<Flow, Subscription, CombineFn>(
+        sources: Vec<Flow>,
+        mut combiner: CombineFn,
+    ) -> Self
+    where
+        Flow: core::Flow<Subscription, SourceItem, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        CombineFn: FnMut(&[SourceItem]) -> Item + Send + 'static,
+    {
+        let queues = sources.iter().map(|_| VecDeque::new()).collect();
+        let partial = sources.iter().map(|_| None).collect();
+        let combine_latest = Self::new(queues, partial, move |items: &Vec<SourceItem>| {
+            combiner(items)
+        });
+        sources
+            .into_iter()
+            .enumerate()
+            .fold(combine_latest, |combine_latest, (index, source)| {
+                combine_latest
+                    .with_source(source, move |queues, item| queues[index].push_back(item))
+            })
+    }
+}
+
+impl<A, B, Error> CombineLatest<(A, B), (A, B), Error>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest2<FlowA, SubscriptionA, FlowB, SubscriptionB>(a: FlowA, b: FlowB) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), Default::default(), Clone::clone)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+    }
+}
+
+impl<A, B, C, Error> CombineLatest<(A, B, C), (A, B, C), Error>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+    C: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest3<FlowA, SubscriptionA, FlowB, SubscriptionB, FlowC, SubscriptionC>(
+        a: FlowA,
+        b: FlowB,
+        c: FlowC,
+    ) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), Default::default(), Clone::clone)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+    }
+}
+
+impl<A, B, C, D, Error> CombineLatest<(A, B, C, D), (A, B, C, D), Error>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+    C: Clone + Send + 'static,
+    D: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest4<
+        FlowA,
+        SubscriptionA,
+        FlowB,
+        SubscriptionB,
+        FlowC,
+        SubscriptionC,
+        FlowD,
+        SubscriptionD,
+    >(
+        a: FlowA,
+        b: FlowB,
+        c: FlowC,
+        d: FlowD,
+    ) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+        FlowD: core::Flow<SubscriptionD, D, Error> + Send + 'static,
+        SubscriptionD: core::Subscription + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), Default::default(), Clone::clone)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+            .with_source(d, |queues, item| queues.3.push_back(item))
+    }
+}
+
+/// Returns a [`Flow`] that applies `combiner` to the latest items of all
+/// `sources` whenever any of them emits an item, once every source has
+/// emitted at least one item.
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+pub fn combine_latest_all<Flow, Subscription, Item, Error, ItemOut, CombineFn>(
+    sources: Vec<Flow>,
+    combiner: CombineFn,
+) -> CombineLatest<Vec<Item>, ItemOut, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ItemOut: Send + 'static,
+    CombineFn: FnMut(&[Item]) -> ItemOut + Send + 'static,
+{
+    CombineLatest::combine_latest_all(sources, combiner)
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Flow<CombineLatestSubscription<Items, Item, Error>, Item, Error>
+    for CombineLatest<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<CombineLatestSubscription<Items, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            subscriber: Mutex::new(None),
+            subscriptions: Mutex::new((0..count).map(|_| None).collect()),
+            state: Mutex::new(State {
+                combiner: self.combiner,
+                order: VecDeque::new(),
+                queues: self.queues,
+                partial: self.partial,
+                latest: None,
+                consumed: vec![0; count],
+                done: vec![false; count],
+                complete_on_first: self.complete_on_first,
+                prefetch: self.prefetch,
+                requested: 0,
+                error: None,
+                is_cancelled: false,
+                is_terminated: false,
+            }),
+            wip: AtomicUsize::new(0),
+        });
+        subscriber
+            .on_subscribe(CombineLatestSubscription {
+                shared: shared.clone(),
+                cancelled: AtomicBool::new(false),
+            })
+            .await;
+        *shared.subscriber.lock().await = Some(Box::new(subscriber));
+        drain(&shared).await;
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    subscriber: Mutex<Option<BoxedSubscriber<Items, Item, Error>>>,
+    subscriptions: Mutex<Vec<Option<BoxedSubscription>>>,
+    state: Mutex<State<Items, Item, Error>>,
+    wip: AtomicUsize,
+}
+
+struct State<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    combiner: Combiner<Items, Item>,
+    /// The indices of the sources of all queued items in emission order.
+    order: VecDeque<usize>,
+    queues: Items::Queues,
+    partial: Items::Partial,
+    latest: Option<Items>,
+    consumed: Vec<usize>,
+    done: Vec<bool>,
+    complete_on_first: bool,
+    prefetch: usize,
+    requested: usize,
+    error: Option<flow::Error<Error>>,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Items, Item, Error> State<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    fn is_completed(&self) -> bool {
+        if !self.order.is_empty() {
+            return false;
+        }
+        let is_any_done = self.done.iter().any(|done| *done);
+        let is_starved = self.latest.is_none()
+            && self
+                .done
+                .iter()
+                .enumerate()
+                .any(|(index, done)| *done && Items::is_missing(&self.partial, index));
+        self.done.iter().all(|done| *done) || (self.complete_on_first && is_any_done) || is_starved
+    }
+
+    fn will_emit(&self, index: usize) -> bool {
+        self.latest.is_some()
+            || (0..self.done.len()).all(|i| i == index || !Items::is_missing(&self.partial, i))
+    }
+
+    fn update(&mut self, index: usize) -> Option<Item> {
+        Items::update(&mut self.queues, &mut self.partial, &mut self.latest, index);
+        let combiner = &mut self.combiner;
+        self.latest.as_ref().map(combiner)
+    }
+
+    /// The number of consumed items after which a source is requested again.
+    fn limit(&self) -> usize {
+        self.prefetch - self.prefetch / 4
+    }
+}
+
+impl<Items, Item, Error> Shared<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    async fn subscription(&self, index: usize) -> Option<BoxedSubscription> {
+        self.subscriptions.lock().await[index].clone()
+    }
+
+    async fn cancel_sources(&self) {
+        let subscriptions: Vec<_> = self
+            .subscriptions
+            .lock()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect();
+        for subscription in subscriptions {
+            subscription.cancel().await;
+        }
+    }
+}
+
+async fn drain<Items, Item, Error>(shared: &Shared<Items, Item, Error>)
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = shared.subscriber.lock().await;
+        let mut is_terminated = false;
+        if let Some(downstream) = subscriber.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    state.is_terminated = true;
+                    is_terminated = true;
+                    break;
+                }
+                if let Some(error) = state.error.take() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_error(error).await;
+                    is_terminated = true;
+                    break;
+                }
+                if state.is_completed() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_completed().await;
+                    is_terminated = true;
+                    break;
+                }
+                let index = match state.order.front() {
+                    Some(index) => *index,
+                    None => break,
+                };
+                // Items that do not lead to an emission are consumed
+                // regardless of the downstream demand.
+                if state.will_emit(index) {
+                    if state.requested == 0 {
+                        break;
+                    }
+                    if state.requested != usize::MAX {
+                        state.requested -= 1;
+                    }
+                }
+                state.order.pop_front();
+                let item = state.update(index);
+                let limit = state.limit();
+                state.consumed[index] += 1;
+                let replenish = state.consumed[index] == limit;
+                if replenish {
+                    state.consumed[index] = 0;
+                }
+                drop(state);
+                if replenish {
+                    if let Some(subscription) = shared.subscription(index).await {
+                        subscription.request(limit).await;
+                    }
+                }
+                if let Some(item) = item {
+                    downstream.on_next(item).await;
+                }
+            }
+        }
+        if is_terminated {
+            *subscriber = None;
+        }
+        drop(subscriber);
+        if is_terminated {
+            shared.cancel_sources().await;
+        }
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+struct CombineLatestSubscriber<Items, Item, Error, SourceItem, Push>
+where
+    Items: CombineLatestItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+    index: usize,
+    push: Push,
+    phantom: PhantomData<SourceItem>,
+}
+
+#[async_trait]
+impl<Subscription, Items, Item, Error, SourceItem, Push>
+    core::Subscriber<Subscription, SourceItem, Error>
+    for CombineLatestSubscriber<Items, Item, Error, SourceItem, Push>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SourceItem: Send + 'static,
+    Push: FnMut(&mut Items::Queues, SourceItem) + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription: BoxedSubscription = Arc::new(subscription);
+        self.shared.subscriptions.lock().await[self.index] = Some(subscription.clone());
+        let state = self.shared.state.lock().await;
+        let (is_terminated, prefetch) = (state.is_terminated, state.prefetch);
+        drop(state);
+        if is_terminated {
+            subscription.cancel().await;
+        } else {
+            subscription.request(prefetch).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: SourceItem) {
+        let mut state = self.shared.state.lock().await;
+        (self.push)(&mut state.queues, item);
+        state.order.push_back(self.index);
+        drop(state);
+        drain(&self.shared).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.error.is_none() {
+                state.error = Some(error);
+            }
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.done[self.index] = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct CombineLatestSubscription<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+    cancelled: AtomicBool,
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Subscription for CombineLatestSubscription<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.shared.state.lock().await.is_cancelled = true;
+        drain(&self.shared).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+        }
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn combine_latest() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .combine_latest(b.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_all(vec![0, 1]).await;
+        b.emit("a").await;
+        a.emit(2).await;
+        b.emit("b").await;
+        a.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        b.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(
+            test_subscriber.items().await,
+            vec![(1, "a"), (2, "a"), (2, "b")]
+        );
+    }
+
+    #[async_std::test]
+    async fn complete_on_first() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .combine_latest(b.clone())
+            .complete_on_first()
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit(0).await;
+        b.emit(1).await;
+        a.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![(0, 1)]);
+        assert!(b.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn with_prefetch() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .combine_latest(b.clone())
+            .with_prefetch(4)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(a.requested().await, 4);
+        a.emit_all(vec![0, 1, 2]).await;
+        assert_eq!(a.requested().await, 7);
+        assert_eq!(b.requested().await, 4);
+        b.emit('a').await;
+
+        assert_eq!(test_subscriber.items().await, vec![(2, 'a')]);
+    }
+
+    #[async_std::test]
+    async fn combine_latest_all_backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        operators::combine_latest_all(vec![a.clone(), b.clone()], |items| {
+            items.iter().sum::<i32>()
+        })
+        .subscribe(test_subscriber.clone())
+        .await;
+        assert_eq!(a.requested().await, 128);
+        a.emit_all(vec![1, 2]).await;
+        b.emit(10).await;
+        b.emit(20).await;
+        assert_eq!(test_subscriber.items().await, vec![12]);
+        test_subscriber.request_direct(1).await;
+
+        assert_eq!(test_subscriber.items().await, vec![12, 22]);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedSource<Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedSubscriber<Item, Error> =
+    Box<dyn core::Subscriber<ConcatSubscription<Item, Error>, Item, Error> + Send + 'static>;
+
+type BoxedSubscription = Arc<dyn core::Subscription + Send + Sync>;
+
+/// Emits the items of multiple sources one after another. Each source is
+/// only subscribed to after the previous one completed, and demand which was
+/// not fulfilled by a source is carried over to the next one.
+pub struct Concat<Item, Error> {
+    sources: VecDeque<BoxedSource<Item, Error>>,
+}
+
+impl<Item, Error> Concat<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn with_source<Flow, Subscription>(mut self, flow: Flow) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        self.sources.push_back(Box::new(move |shared| {
+            Box::pin(async move { flow.subscribe(ConcatSubscriber { shared }).await })
+        }));
+        self
+    }
+
+    pub fn concat_all<Flow, Subscription>(sources: Vec<Flow>) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        sources.into_iter().fold(
+            Self {
+                sources: VecDeque::new(),
+            },
+            |concat, source| concat.with_source(source),
+        )
+    }
+
+    pub fn concat2<FlowA, SubscriptionA, FlowB, SubscriptionB>(a: FlowA, b: FlowB) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, Item, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, Item, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+    {
+        Self::concat_all::<FlowA, SubscriptionA>(vec![])
+            .with_source(a)
+            .with_source(b)
+    }
+}
+
+/// Returns a [`Flow`] that emits the items of all `sources` one after
+/// another, subscribing to each source after the previous one completed.
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+pub fn concat<Flow, Subscription, Item, Error>(sources: Vec<Flow>) -> Concat<Item, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Concat::concat_all(sources)
+}
+
+#[async_trait]
+impl<Item, Error> core::Flow<ConcatSubscription<Item, Error>, Item, Error> for Concat<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<ConcatSubscription<Item, Error>, Item, Error> + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            subscriber: Mutex::new(None),
+            sources: Mutex::new(self.sources),
+            state: Mutex::new(State {
+                current: None,
+                requested: 0,
+                is_cancelled: false,
+            }),
+            wip: AtomicUsize::new(0),
+        });
+        subscriber
+            .on_subscribe(ConcatSubscription {
+                shared: shared.clone(),
+                cancelled: AtomicBool::new(false),
+            })
+            .await;
+        *shared.subscriber.lock().await = Some(Box::new(subscriber));
+        subscribe_next(shared).await;
+    }
+}
+
+struct Shared<Item, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<Item, Error>>>,
+    sources: Mutex<VecDeque<BoxedSource<Item, Error>>>,
+    state: Mutex<State>,
+    wip: AtomicUsize,
+}
+
+struct State {
+    current: Option<BoxedSubscription>,
+    requested: usize,
+    is_cancelled: bool,
+}
+
+/// Subscribes to the next source or completes the downstream subscriber if
+/// there is none left. Sources which complete during their subscription are
+/// followed up iteratively instead of recursively, so that the stack stays
+/// shallow regardless of the number of sources.
+async fn subscribe_next<Item, Error>(shared: Arc<Shared<Item, Error>>)
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    loop {
+        if shared.state.lock().await.is_cancelled {
+            shared.sources.lock().await.clear();
+            *shared.subscriber.lock().await = None;
+            return;
+        }
+        let source = shared.sources.lock().await.pop_front();
+        match source {
+            Some(source) => source(shared.clone()).await,
+            None => {
+                let subscriber = shared.subscriber.lock().await.take();
+                if let Some(mut subscriber) = subscriber {
+                    subscriber.on_completed().await;
+                }
+                return;
+            }
+        }
+        if shared.wip.fetch_sub(1, Ordering::SeqCst) == 1 {
+            return;
+        }
+    }
+}
+
+struct ConcatSubscriber<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for ConcatSubscriber<Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription: BoxedSubscription = Arc::new(subscription);
+        let (requested, is_cancelled) = {
+            let mut state = self.shared.state.lock().await;
+            state.current = Some(subscription.clone());
+            (state.requested, state.is_cancelled)
+        };
+        if is_cancelled {
+            subscription.cancel().await;
+        } else if requested > 0 {
+            subscription.request(requested).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.requested != usize::MAX {
+                state.requested = state.requested.saturating_sub(1);
+            }
+        }
+        if let Some(subscriber) = self.shared.subscriber.lock().await.as_mut() {
+            subscriber.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.shared.sources.lock().await.clear();
+        let subscriber = self.shared.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.current = None;
+        subscribe_next(self.shared.clone()).await;
+    }
+}
+
+pub struct ConcatSubscription<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+    cancelled: AtomicBool,
+}
+
+#[async_trait]
+impl<Item, Error> core::Subscription for ConcatSubscription<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        let current = {
+            let mut state = self.shared.state.lock().await;
+            state.is_cancelled = true;
+            state.current.take()
+        };
+        if let Some(current) = current {
+            current.cancel().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        let current = {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+            state.current.clone()
+        };
+        if let Some(current) = current {
+            current.request(count).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn concat_with() {
+        let test_subscriber = TestSubscriber::new(3);
+        let live = TestFlow::default();
+        vec![0, 1]
+            .into_flow()
+            .concat_with(live.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(live.requested().await, 1);
+        live.emit(2).await;
+        live.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn many_sources() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        operators::concat((0..10000).map(|item| vec![item].into_flow()).collect())
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(
+            test_subscriber.items().await,
+            (0..10000).collect::<Vec<_>>()
+        );
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_item_type(0);
+        let b = TestFlow::default();
+        operators::concat(vec![a.clone(), b.clone()])
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_error(()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+        assert!(!b.has_observer().await);
+    }
+}
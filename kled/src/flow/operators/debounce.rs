@@ -0,0 +1,331 @@
+use crate::core;
+use crate::flow;
+use crate::scheduler::{unbounded, DelaySender};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::prelude::*;
+
+#[chronobreak]
+mod mock {
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+pub type DebounceFn<Item> = Box<dyn FnMut(&Item) -> Duration + Send>;
+
+#[operator(type = "flow", subscription = "DebounceSubscription<Subscription>")]
+pub struct Debounce<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration_fn: DebounceFn<Item>,
+    scheduler: Scheduler,
+}
+
+pub struct DebounceSubscriber<Subscriber, Subscription, Item, Scheduler> {
+    data: Arc<Mutex<Data<Subscriber, Subscription, Item>>>,
+    duration_fn: DebounceFn<Item>,
+    scheduler: Scheduler,
+}
+
+struct Data<Subscriber, Subscription, Item> {
+    subscriber: Subscriber,
+    shared: Option<Arc<Shared<Subscription>>>,
+    sender: Option<DelaySender<usize>>,
+    pending: Option<Item>,
+    index: usize,
+    is_done: bool,
+}
+
+struct Shared<Upstream> {
+    upstream: Upstream,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Subscriber, Subscription, Item, Scheduler>
+    DebounceSubscriber<Subscriber, Subscription, Item, Scheduler>
+{
+    pub fn new(
+        subscriber: Subscriber,
+        duration_fn: DebounceFn<Item>,
+        scheduler: Scheduler,
+    ) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                subscriber,
+                shared: None,
+                sender: None,
+                pending: None,
+                index: 0,
+                is_done: false,
+            })),
+            duration_fn,
+            scheduler,
+        }
+    }
+}
+
+impl<Subscriber, Subscription, Item> Data<Subscriber, Subscription, Item> {
+    async fn emit_pending<Error>(&mut self)
+    where
+        Subscriber: core::Subscriber<DebounceSubscription<Subscription>, Item, Error> + Send,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        Item: Send + 'static,
+        Error: Send + 'static,
+    {
+        let (item, shared) = match (self.pending.take(), self.shared.as_ref()) {
+            (Some(item), Some(shared)) => (item, shared),
+            _ => return,
+        };
+        let requested = shared.requested.load(Ordering::Relaxed);
+        if requested == 0 {
+            self.is_done = true;
+            self.subscriber
+                .on_error(flow::Error::MissingBackpressure)
+                .await;
+            shared.upstream.cancel().await;
+            return;
+        }
+        if requested != usize::MAX {
+            shared.requested.fetch_sub(1, Ordering::Relaxed);
+        }
+        self.subscriber.on_next(item).await;
+    }
+
+    fn is_cancelled(&self) -> bool {
+        match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::Relaxed),
+            None => true,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for DebounceSubscriber<Subscriber, Subscription, Item, Scheduler>
+where
+    Subscriber: core::Subscriber<DebounceSubscription<Subscription>, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            requested: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+        });
+        let mut data = self.data.lock().await;
+        data.shared = Some(shared.clone());
+        data.subscriber
+            .on_subscribe(DebounceSubscription { shared })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        let deadline = self.scheduler.now() + (self.duration_fn)(&item);
+        data.pending = Some(item);
+        data.index += 1;
+        // The timer task runs until the latest item was emitted and ignores
+        // the timers of all items that were superseded in the meantime.
+        if data.sender.is_none() {
+            let (sender, mut receiver) = unbounded();
+            data.sender = Some(sender);
+            let task_data = self.data.clone();
+            self.scheduler.schedule(async move {
+                while let Some(index) = receiver.next().await {
+                    let mut data = task_data.lock().await;
+                    if data.is_done || data.is_cancelled() {
+                        data.sender = None;
+                        break;
+                    }
+                    if index != data.index {
+                        continue;
+                    }
+                    data.sender = None;
+                    data.emit_pending::<Error>().await;
+                    break;
+                }
+            });
+        }
+        let index = data.index;
+        if let Some(sender) = data.sender.as_mut() {
+            if sender.send_at(deadline, index).await.is_err() {
+                data.sender = None;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        data.sender = None;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        data.sender = None;
+        data.emit_pending::<Error>().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_completed().await;
+        }
+    }
+}
+
+pub struct DebounceSubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for DebounceSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+        self.shared.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+
+    #[chronobreak::test]
+    async fn debounce() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .debounce(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, usize::MAX);
+        test_flow.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await, vec![1]);
+        test_flow.emit(2).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn debounce_with() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .debounce_with(
+                |item| Duration::from_millis(if *item == 0 { 100 } else { 10 }),
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await, vec![1]);
+        test_flow.emit(2).await;
+        scheduler.join();
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn debounce_with_shorter_duration() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .debounce_with(
+                |item| Duration::from_millis(if *item == 0 { 100 } else { 5 }),
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        let start = Instant::now();
+        test_flow.emit(0).await;
+        clock::advance(Duration::from_millis(10));
+        test_flow.emit(1).await;
+        scheduler.join();
+
+        // The later item is emitted after its own 5ms, the timer of the
+        // superseded one is not waited for.
+        assert_eq!(test_subscriber.items().await, vec![1]);
+        assert!(Instant::now() - start < Duration::from_millis(100));
+    }
+
+    #[chronobreak::test]
+    async fn burst() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .debounce(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(0..1000).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await, vec![999]);
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![999]);
+    }
+
+    #[chronobreak::test]
+    async fn missing_backpressure() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .debounce(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::MissingBackpressure)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::flow::operators::{Dematerialize, Materialize};
+use crate::flow::Signal;
+use crate::subscriber::ScheduledSubscriber;
+use crate::{core, flow, Never};
+use async_trait::async_trait;
+#[chronobreak]
+use std::time::*;
+
+#[operator(
+    type = "flow",
+    subscriber = "DelaySubscriber",
+    upstream_subscription = "Never",
+    upstream_item = "Signal<Subscription, Item, Error>",
+    upstream_error = "Never",
+    subscription = "Never",
+    item = "Signal<Subscription, Item, Error>",
+    error = "Never"
+)]
+pub struct DelayRaw<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+    delay_error: bool,
+}
+
+pub type Delay<Upstream, Subscription, Item, Error, Scheduler> = Dematerialize<
+    DelayRaw<
+        Materialize<Upstream, Subscription, Item, Error>,
+        Subscription,
+        Item,
+        Error,
+        Scheduler,
+    >,
+    Subscription,
+    Item,
+    Error,
+>;
+
+pub struct DelaySubscriber<Subscription, Item, Error> {
+    scheduled: ScheduledSubscriber<Subscription, Item, Error>,
+    duration: Duration,
+    delay_error: bool,
+}
+
+impl<Subscription, Item, Error> DelaySubscriber<Subscription, Item, Error> {
+    pub fn new<Subscriber, Scheduler>(
+        subscriber: Subscriber,
+        duration: Duration,
+        scheduler: Scheduler,
+        delay_error: bool,
+    ) -> Self
+    where
+        Subscription: Send + 'static,
+        Item: Send + 'static,
+        Error: Send + 'static,
+        Subscriber:
+            core::Subscriber<Never, Signal<Subscription, Item, Error>, Never> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Self {
+            scheduled: ScheduledSubscriber::new(subscriber, scheduler),
+            duration,
+            delay_error,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Never, Signal<Subscription, Item, Error>, Never>
+    for DelaySubscriber<Subscription, Item, Error>
+where
+    Subscription: Send,
+    Item: Send,
+    Error: Send,
+{
+    async fn on_subscribe(&mut self, _: Never) {
+        unreachable! {};
+    }
+
+    async fn on_next(&mut self, signal: Signal<Subscription, Item, Error>) {
+        if signal.is_subscribe() || (signal.is_error() && !self.delay_error) {
+            self.scheduled.on_next(signal).await;
+        } else {
+            self.scheduled.on_next_delayed(self.duration, signal).await;
+        }
+    }
+
+    async fn on_error(&mut self, _: flow::Error<Never>) {
+        unreachable! {};
+    }
+
+    async fn on_completed(&mut self) {
+        self.scheduled.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn delay() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let start = Instant::now();
+        vec![0, 1, 2]
+            .into_flow()
+            .delay(Duration::from_millis(10), scheduler.clone(), true)
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() >= start + Duration::from_millis(10));
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn delay_error() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .delay(Duration::from_millis(10), scheduler.clone(), true)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_flow.emit_error(()).await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+    }
+
+    #[chronobreak::test]
+    async fn immediate_error() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .delay(Duration::from_millis(10), scheduler.clone(), false)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_flow.emit_error(()).await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.items().await, Vec::<i32>::new());
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[derive(new)]
+pub struct DelaySubscription<Flow, Subscription, Item, Error, Scheduler> {
+    flow: Flow,
+    duration: Duration,
+    scheduler: Scheduler,
+    phantom: PhantomData<(Subscription, Item, Error)>,
+}
+
+#[async_trait]
+impl<Flow, Subscription, Item, Error, Scheduler>
+    core::Flow<LazySubscription<Subscription>, Item, Error>
+    for DelaySubscription<Flow, Subscription, Item, Error, Scheduler>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<LazySubscription<Subscription>, Item, Error> + Send + 'static,
+    {
+        let stub = LazySubscriptionStub::default();
+        subscriber.on_subscribe(stub.subscription()).await;
+        let flow = self.flow;
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            Delay::new(duration).await;
+            // The upstream is not subscribed to at all once the downstream
+            // cancelled during the delay.
+            if stub.is_cancelled().await {
+                return;
+            }
+            flow.subscribe(DelaySubscriptionSubscriber { subscriber, stub })
+                .await;
+        });
+    }
+}
+
+struct DelaySubscriptionSubscriber<Subscriber, Subscription> {
+    subscriber: Subscriber,
+    stub: LazySubscriptionStub<Subscription>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for DelaySubscriptionSubscriber<Subscriber, Subscription>
+where
+    Subscriber: core::Subscriber<LazySubscription<Subscription>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.stub.set_subscription(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn delay_subscription() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let start = Instant::now();
+        vec![0, 1, 2]
+            .into_flow()
+            .delay_subscription(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() >= start + Duration::from_millis(10));
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn cancel_during_delay() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default()
+            .annotate_item_type(0)
+            .annotate_error_type(());
+        test_flow
+            .clone()
+            .delay_subscription(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        test_subscriber.cancel().await;
+        scheduler.join();
+
+        assert!(!test_flow.has_observer().await);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Cancelled);
+    }
+}
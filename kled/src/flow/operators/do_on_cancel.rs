@@ -0,0 +1,120 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "DoOnCancelSubscription<Subscription, CancelFn>"
+)]
+pub struct DoOnCancel<CancelFn>
+where
+    CancelFn: Fn() + Send + Sync + 'static,
+{
+    cancel_fn: CancelFn,
+}
+
+struct DoOnCancelSubscriber<Subscriber, CancelFn> {
+    subscriber: Subscriber,
+    cancel_fn: Arc<CancelFn>,
+}
+
+impl<Subscriber, CancelFn> DoOnCancelSubscriber<Subscriber, CancelFn> {
+    fn new(subscriber: Subscriber, cancel_fn: CancelFn) -> Self {
+        Self {
+            subscriber,
+            cancel_fn: Arc::new(cancel_fn),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, CancelFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnCancelSubscriber<Subscriber, CancelFn>
+where
+    Subscriber:
+        core::Subscriber<DoOnCancelSubscription<Subscription, CancelFn>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CancelFn: Fn() + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(DoOnCancelSubscription::new(
+                subscription,
+                self.cancel_fn.clone(),
+            ))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct DoOnCancelSubscription<Upstream, CancelFn> {
+    upstream: Upstream,
+    cancel_fn: Arc<CancelFn>,
+}
+
+#[async_trait]
+impl<Upstream, CancelFn> core::Subscription for DoOnCancelSubscription<Upstream, CancelFn>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    CancelFn: Fn() + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        (self.cancel_fn)();
+        self.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_cancel() {
+        let mut test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let cancellations = Arc::new(AtomicUsize::new(0));
+        let cancel_cancellations = cancellations.clone();
+        test_flow
+            .clone()
+            .do_on_cancel(move || {
+                cancel_cancellations.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(cancellations.load(Ordering::SeqCst), 0);
+        test_subscriber.cancel().await;
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(cancellations.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnComplete<CompleteFn>
+where
+    CompleteFn: FnMut() + Send,
+{
+    complete_fn: CompleteFn,
+}
+
+#[derive(new)]
+struct DoOnCompleteSubscriber<Subscriber, CompleteFn> {
+    subscriber: Subscriber,
+    complete_fn: CompleteFn,
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, CompleteFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnCompleteSubscriber<Subscriber, CompleteFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CompleteFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        (self.complete_fn)();
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_complete() {
+        let test_subscriber = TestSubscriber::default();
+        let completions = Arc::new(AtomicUsize::new(0));
+        let complete_completions = completions.clone();
+        vec![0, 1]
+            .into_flow()
+            .do_on_complete(move || {
+                complete_completions.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(completions.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnError<ErrorFn>
+where
+    ErrorFn: FnMut(&flow::Error<Error>) + Send,
+{
+    error_fn: ErrorFn,
+}
+
+#[derive(new)]
+struct DoOnErrorSubscriber<Subscriber, ErrorFn> {
+    subscriber: Subscriber,
+    error_fn: ErrorFn,
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, ErrorFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnErrorSubscriber<Subscriber, ErrorFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorFn: FnMut(&flow::Error<Error>) + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        (self.error_fn)(&error);
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn do_on_error() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_item_type(());
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let error_errors = errors.clone();
+        test_flow
+            .clone()
+            .do_on_error(move |error| error_errors.lock().unwrap().push(*error))
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_error(0).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(*errors.lock().unwrap(), vec![flow::Error::Upstream(0)]);
+    }
+}
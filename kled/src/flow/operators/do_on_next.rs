@@ -0,0 +1,68 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnNext<NextFn>
+where
+    NextFn: FnMut(&Item) + Send,
+{
+    next_fn: NextFn,
+}
+
+#[derive(new)]
+struct DoOnNextSubscriber<Subscriber, NextFn> {
+    subscriber: Subscriber,
+    next_fn: NextFn,
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, NextFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnNextSubscriber<Subscriber, NextFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NextFn: FnMut(&Item) + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        (self.next_fn)(&item);
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn do_on_next() {
+        let test_subscriber = TestSubscriber::default();
+        let items = Arc::new(Mutex::new(Vec::new()));
+        let next_items = items.clone();
+        vec![0, 1, 2]
+            .into_flow()
+            .do_on_next(move |item| next_items.lock().unwrap().push(*item))
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+        assert_eq!(*items.lock().unwrap(), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "DoOnRequestSubscription<Subscription, RequestFn>"
+)]
+pub struct DoOnRequest<RequestFn>
+where
+    RequestFn: Fn(usize) + Send + Sync + 'static,
+{
+    request_fn: RequestFn,
+}
+
+struct DoOnRequestSubscriber<Subscriber, RequestFn> {
+    subscriber: Subscriber,
+    request_fn: Arc<RequestFn>,
+}
+
+impl<Subscriber, RequestFn> DoOnRequestSubscriber<Subscriber, RequestFn> {
+    fn new(subscriber: Subscriber, request_fn: RequestFn) -> Self {
+        Self {
+            subscriber,
+            request_fn: Arc::new(request_fn),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, RequestFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnRequestSubscriber<Subscriber, RequestFn>
+where
+    Subscriber:
+        core::Subscriber<DoOnRequestSubscription<Subscription, RequestFn>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    RequestFn: Fn(usize) + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(DoOnRequestSubscription::new(
+                subscription,
+                self.request_fn.clone(),
+            ))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct DoOnRequestSubscription<Upstream, RequestFn> {
+    upstream: Upstream,
+    request_fn: Arc<RequestFn>,
+}
+
+#[async_trait]
+impl<Upstream, RequestFn> core::Subscription for DoOnRequestSubscription<Upstream, RequestFn>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    RequestFn: Fn(usize) + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        (self.request_fn)(count);
+        self.upstream.request(count).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn do_on_request() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let request_requests = requests.clone();
+        test_flow
+            .clone()
+            .do_on_request(move |count| request_requests.lock().unwrap().push(count))
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_subscriber.request_direct(3).await;
+
+        assert_eq!(test_flow.requested().await, 5);
+        assert_eq!(*requests.lock().unwrap(), vec![2, 3]);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnSubscribe<SubscribeFn>
+where
+    SubscribeFn: FnMut() + Send,
+{
+    subscribe_fn: SubscribeFn,
+}
+
+#[derive(new)]
+struct DoOnSubscribeSubscriber<Subscriber, SubscribeFn> {
+    subscriber: Subscriber,
+    subscribe_fn: SubscribeFn,
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, SubscribeFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnSubscribeSubscriber<Subscriber, SubscribeFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SubscribeFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        (self.subscribe_fn)();
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_subscribe() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let subscribed = Arc::new(AtomicBool::new(false));
+        let subscribe_subscribed = subscribed.clone();
+        test_flow
+            .clone()
+            .do_on_subscribe(move || subscribe_subscribed.store(true, Ordering::SeqCst))
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert!(subscribed.load(Ordering::SeqCst));
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+    }
+}
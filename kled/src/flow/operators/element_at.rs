@@ -0,0 +1,206 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "flow", subscription = "ElementSubscription<Subscription>")]
+pub struct ElementAt {
+    index: usize,
+    default: Option<Item>,
+}
+
+struct ElementAtSubscriber<Subscriber, Subscription, Item> {
+    subscriber: Subscriber,
+    upstream: Option<Arc<Subscription>>,
+    index: usize,
+    default: Option<Item>,
+    is_done: bool,
+}
+
+impl<Subscriber, Subscription, Item> ElementAtSubscriber<Subscriber, Subscription, Item> {
+    fn new(subscriber: Subscriber, index: usize, default: Option<Item>) -> Self {
+        Self {
+            subscriber,
+            upstream: None,
+            index,
+            default,
+            is_done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for ElementAtSubscriber<Subscriber, Subscription, Item>
+where
+    Subscriber: core::Subscriber<ElementSubscription<Subscription>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let upstream = Arc::new(subscription);
+        self.upstream = Some(upstream.clone());
+        self.subscriber
+            .on_subscribe(ElementSubscription::new(
+                upstream,
+                self.index.saturating_add(1),
+            ))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.is_done {
+            return;
+        }
+        if self.index > 0 {
+            self.index -= 1;
+            return;
+        }
+        self.is_done = true;
+        if let Some(upstream) = self.upstream.take() {
+            upstream.cancel().await;
+        }
+        self.subscriber.on_next(item).await;
+        self.subscriber.on_completed().await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if !self.is_done {
+            self.is_done = true;
+            self.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if !self.is_done {
+            self.is_done = true;
+            match self.default.take() {
+                Some(item) => {
+                    self.subscriber.on_next(item).await;
+                    self.subscriber.on_completed().await;
+                }
+                None => self.subscriber.on_error(flow::Error::NoSuchElement).await,
+            }
+        }
+    }
+}
+
+/// The subscription of an operator that emits at most a single item. The
+/// first request of the downstream subscriber is translated into a request of
+/// `count` items from upstream, all further requests are ignored.
+pub struct ElementSubscription<Upstream> {
+    upstream: Arc<Upstream>,
+    count: usize,
+    requested: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream> ElementSubscription<Upstream> {
+    pub fn new(upstream: Arc<Upstream>, count: usize) -> Self {
+        Self {
+            upstream,
+            count,
+            requested: AtomicBool::new(false),
+            cancelled: AtomicBool::new(false),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for ElementSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        if count > 0 && !self.requested.swap(true, Ordering::Relaxed) {
+            self.upstream.request(self.count).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn first() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .first()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 1);
+        test_flow.emit(0).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert!(test_flow.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn first_or() {
+        let test_subscriber = TestSubscriber::new(1);
+        Vec::new()
+            .into_flow()
+            .first_or(1)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1]);
+    }
+
+    #[async_std::test]
+    async fn element_at() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let cancel_cancelled = cancelled.clone();
+        test_flow
+            .clone()
+            .do_on_cancel(move || cancel_cancelled.store(true, Ordering::SeqCst))
+            .element_at(2)
+            .do_on_complete(move || assert!(cancelled.load(Ordering::SeqCst)))
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 3);
+        test_flow.emit_all(vec![0, 1, 2]).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![2]);
+        assert!(test_flow.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn no_such_element() {
+        let test_subscriber = TestSubscriber::new(1);
+        vec![0, 1]
+            .into_flow()
+            .element_at(2)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::NoSuchElement)
+        );
+    }
+}
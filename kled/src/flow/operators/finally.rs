@@ -0,0 +1,178 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "FinallySubscription<Subscription, FinallyFn>"
+)]
+pub struct Finally<FinallyFn>
+where
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    finally_fn: FinallyFn,
+}
+
+/// Holds the callback until it is taken by whichever of completion, error or
+/// cancellation happens first.
+struct Shared<FinallyFn> {
+    finally_fn: Mutex<Option<FinallyFn>>,
+}
+
+impl<FinallyFn> Shared<FinallyFn>
+where
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    async fn run(&self) {
+        let finally_fn = self.finally_fn.lock().await.take();
+        if let Some(finally_fn) = finally_fn {
+            finally_fn();
+        }
+    }
+}
+
+struct FinallySubscriber<Subscriber, FinallyFn> {
+    subscriber: Subscriber,
+    shared: Arc<Shared<FinallyFn>>,
+}
+
+impl<Subscriber, FinallyFn> FinallySubscriber<Subscriber, FinallyFn> {
+    fn new(subscriber: Subscriber, finally_fn: FinallyFn) -> Self {
+        Self {
+            subscriber,
+            shared: Arc::new(Shared {
+                finally_fn: Mutex::new(Some(finally_fn)),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, FinallyFn> core::Subscriber<Subscription, Item, Error>
+    for FinallySubscriber<Subscriber, FinallyFn>
+where
+    Subscriber: core::Subscriber<FinallySubscription<Subscription, FinallyFn>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(FinallySubscription {
+                upstream: subscription,
+                shared: self.shared.clone(),
+            })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+        self.shared.run().await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+        self.shared.run().await;
+    }
+}
+
+pub struct FinallySubscription<Upstream, FinallyFn> {
+    upstream: Upstream,
+    shared: Arc<Shared<FinallyFn>>,
+}
+
+#[async_trait]
+impl<Upstream, FinallyFn> core::Subscription for FinallySubscription<Upstream, FinallyFn>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.shared.run().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn finally_completed() {
+        let test_subscriber = TestSubscriber::default();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let finally_runs = runs.clone();
+        vec![0, 1]
+            .into_flow()
+            .finally(move || {
+                finally_runs.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn finally_error() {
+        let mut test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_item_type(());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let finally_runs = runs.clone();
+        test_flow
+            .clone()
+            .finally(move || {
+                finally_runs.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+        test_flow.emit_error(()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        test_subscriber.cancel().await;
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn finally_cancelled() {
+        let mut test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let finally_runs = runs.clone();
+        test_flow
+            .clone()
+            .finally(move || {
+                finally_runs.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_subscriber.cancel().await;
+        assert!(test_flow.is_cancelled().await);
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
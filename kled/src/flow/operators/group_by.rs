@@ -0,0 +1,716 @@
+use crate::core;
+use crate::flow;
+use crate::processor::*;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+/// The number of items requested from upstream ahead of their consumption by
+/// the groups.
+const PREFETCH: usize = 128;
+
+#[operator(
+    type = "flow",
+    subscription = "GroupBySubscription<Subscription>",
+    item = "GroupedFlow<Key, Subscription, Item, Error>"
+)]
+pub struct GroupBy<Key, KeyFn>
+where
+    Key: Clone + Eq + Hash,
+    KeyFn: FnMut(&Item) -> Key,
+    Error: Clone,
+{
+    key_fn: KeyFn,
+    eviction: Option<Eviction>,
+}
+
+/// The idle duration after which [`Flow::group_by_with_eviction`] completes a
+/// group, together with the clock and the timers of the scheduler it was
+/// given.
+///
+/// [`Flow::group_by_with_eviction`]: ../../core/trait.Flow.html#method.group_by_with_eviction
+pub struct Eviction {
+    duration: Duration,
+    now: Box<dyn Fn() -> Instant + Send + Sync>,
+    schedule: Box<dyn Fn(BoxFuture<'static, ()>) + Send + Sync>,
+}
+
+impl Eviction {
+    pub fn new<Scheduler>(duration: Duration, scheduler: Scheduler) -> Self
+    where
+        Scheduler: core::Scheduler,
+    {
+        let clock = scheduler.clone();
+        Self {
+            duration,
+            now: Box::new(move || clock.now()),
+            schedule: Box::new(move |future| scheduler.schedule(future)),
+        }
+    }
+}
+
+/// A [`Flow`] of all items that were mapped to the same key by
+/// [`Flow::group_by`]. Only a single subscriber may subscribe to a group, any
+/// further one is signalled [`flow::Error::AlreadySubscribed`].
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+/// [`Flow::group_by`]: ../../core/trait.Flow.html#method.group_by
+/// [`flow::Error::AlreadySubscribed`]: ../enum.Error.html#variant.AlreadySubscribed
+pub struct GroupedFlow<Key, Subscription, Item, Error> {
+    key: Key,
+    processor: UnicastProcessor<Subscription, Item, Error>,
+    shared: Arc<Shared<Subscription>>,
+    pending: Arc<AtomicUsize>,
+}
+
+impl<Key, Subscription, Item, Error> GroupedFlow<Key, Subscription, Item, Error> {
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+}
+
+impl<Key, Subscription, Item, Error> Clone for GroupedFlow<Key, Subscription, Item, Error>
+where
+    Key: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            processor: self.processor.clone(),
+            shared: self.shared.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Key, Subscription, Item, Error>
+    core::Flow<
+        GroupedFlowSubscription<
+            UnicastProcessorSubscription<Subscription, Item, Error>,
+            Subscription,
+        >,
+        Item,
+        Error,
+    > for GroupedFlow<Key, Subscription, Item, Error>
+where
+    Key: Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<
+                GroupedFlowSubscription<
+                    UnicastProcessorSubscription<Subscription, Item, Error>,
+                    Subscription,
+                >,
+                Item,
+                Error,
+            > + Send
+            + 'static,
+    {
+        self.processor
+            .subscribe(GroupSubscriber {
+                subscriber,
+                shared: self.shared,
+                pending: self.pending,
+            })
+            .await;
+    }
+}
+
+/// The upstream shared by the downstream and the groups, which is only
+/// cancelled once the downstream and every group were cancelled.
+struct Shared<Upstream> {
+    upstream: Upstream,
+    requested: AtomicUsize,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream> Shared<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    fn group<Item, Error>(self: &Arc<Self>) -> UnicastProcessor<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastProcessor::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.upstream.cancel().await;
+        }
+    }
+
+    /// Requests the items buffered by a cancelled group again, as they are
+    /// dropped and will never be consumed.
+    async fn reclaim(&self, pending: &AtomicUsize) {
+        let pending = pending.swap(0, Ordering::SeqCst);
+        if pending > 0 {
+            self.upstream.request(pending).await;
+        }
+    }
+}
+
+struct Group<Subscription, Item, Error> {
+    processor: UnicastProcessor<Subscription, Item, Error>,
+    pending: Arc<AtomicUsize>,
+    tick: usize,
+}
+
+struct Groups<Key, Subscription, Item, Error> {
+    groups: HashMap<Key, Group<Subscription, Item, Error>>,
+    /// The keys of all groups together with the instant of their last item,
+    /// ordered by that instant. Only maintained if groups are evicted.
+    by_activity: BTreeMap<usize, (Key, Instant)>,
+    tick: usize,
+    is_timer_armed: bool,
+    is_done: bool,
+}
+
+impl<Key, Subscription, Item, Error> Groups<Key, Subscription, Item, Error>
+where
+    Key: Clone + Eq + Hash,
+{
+    fn touch(&mut self, key: &Key, now: Instant) {
+        if let Some(group) = self.groups.get_mut(key) {
+            self.by_activity.remove(&group.tick);
+            self.tick += 1;
+            group.tick = self.tick;
+            self.by_activity.insert(self.tick, (key.clone(), now));
+        }
+    }
+
+    fn remove(&mut self, key: &Key) -> Option<Group<Subscription, Item, Error>> {
+        let group = self.groups.remove(key)?;
+        self.by_activity.remove(&group.tick);
+        Some(group)
+    }
+
+    fn remove_idle(
+        &mut self,
+        now: Instant,
+        eviction: Duration,
+    ) -> Vec<Group<Subscription, Item, Error>> {
+        let mut idle = vec![];
+        while let Some((key, last_active)) = self.by_activity.values().next().cloned() {
+            if now.saturating_duration_since(last_active) < eviction {
+                break;
+            }
+            idle.extend(self.remove(&key));
+        }
+        idle
+    }
+
+    fn drain(&mut self) -> Vec<Group<Subscription, Item, Error>> {
+        self.is_done = true;
+        self.by_activity.clear();
+        self.groups.drain().map(|(_, group)| group).collect()
+    }
+}
+
+type SharedGroups<Key, Subscription, Item, Error> =
+    Arc<Mutex<Groups<Key, Subscription, Item, Error>>>;
+
+fn schedule_eviction<Key, Subscription, Item, Error>(
+    groups: SharedGroups<Key, Subscription, Item, Error>,
+    eviction: Arc<Eviction>,
+    delay: Duration,
+) where
+    Key: Clone + Eq + Hash + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    let timer = eviction.clone();
+    (timer.schedule)(Box::pin(async move {
+        Delay::new(delay).await;
+        evict_idle_groups(groups, eviction).await;
+    }));
+}
+
+async fn evict_idle_groups<Key, Subscription, Item, Error>(
+    groups: SharedGroups<Key, Subscription, Item, Error>,
+    eviction: Arc<Eviction>,
+) where
+    Key: Clone + Eq + Hash + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    let (idle, next) = {
+        let mut locked = groups.lock().await;
+        let now = (eviction.now)();
+        let idle = locked.remove_idle(now, eviction.duration);
+        // The timer is rearmed for the group that becomes idle next.
+        let next = match locked.by_activity.values().next() {
+            Some((_, last_active)) if !locked.is_done => {
+                Some((*last_active + eviction.duration).saturating_duration_since(now))
+            }
+            _ => None,
+        };
+        locked.is_timer_armed = next.is_some();
+        (idle, next)
+    };
+    if let Some(delay) = next {
+        schedule_eviction(groups, eviction, delay);
+    }
+    for mut group in idle {
+        core::Subscriber::on_completed(&mut group.processor).await;
+    }
+}
+
+pub struct GroupBySubscriber<Subscriber, Subscription, Item, Error, Key, KeyFn> {
+    subscriber: Subscriber,
+    key_fn: KeyFn,
+    eviction: Option<Arc<Eviction>>,
+    shared: Option<Arc<Shared<Subscription>>>,
+    groups: SharedGroups<Key, Subscription, Item, Error>,
+}
+
+impl<Subscriber, Subscription, Item, Error, Key, KeyFn>
+    GroupBySubscriber<Subscriber, Subscription, Item, Error, Key, KeyFn>
+where
+    Key: Eq + Hash,
+{
+    pub fn new(subscriber: Subscriber, key_fn: KeyFn, eviction: Option<Eviction>) -> Self {
+        Self {
+            subscriber,
+            key_fn,
+            eviction: eviction.map(Arc::new),
+            shared: None,
+            groups: Arc::new(Mutex::new(Groups {
+                groups: HashMap::new(),
+                by_activity: BTreeMap::new(),
+                tick: 0,
+                is_timer_armed: false,
+                is_done: false,
+            })),
+        }
+    }
+}
+
+impl<Subscriber, Subscription, Item, Error, Key, KeyFn>
+    GroupBySubscriber<Subscriber, Subscription, Item, Error, Key, KeyFn>
+where
+    Subscriber: core::Subscriber<
+            GroupBySubscription<Subscription>,
+            GroupedFlow<Key, Subscription, Item, Error>,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    Key: Clone + Eq + Hash + Send + 'static,
+{
+    async fn terminate(&mut self, error: Option<flow::Error<Error>>) {
+        let groups = {
+            let mut groups = self.groups.lock().await;
+            if groups.is_done {
+                return;
+            }
+            groups.drain()
+        };
+        for mut group in groups {
+            match error.clone() {
+                Some(error) => core::Subscriber::on_error(&mut group.processor, error).await,
+                None => core::Subscriber::on_completed(&mut group.processor).await,
+            }
+        }
+        let is_cancelled = match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::SeqCst),
+            None => false,
+        };
+        if is_cancelled {
+            return;
+        }
+        match error {
+            Some(error) => self.subscriber.on_error(error).await,
+            None => self.subscriber.on_completed().await,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Key, KeyFn> core::Subscriber<Subscription, Item, Error>
+    for GroupBySubscriber<Subscriber, Subscription, Item, Error, Key, KeyFn>
+where
+    Subscriber: core::Subscriber<
+            GroupBySubscription<Subscription>,
+            GroupedFlow<Key, Subscription, Item, Error>,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    Key: Clone + Eq + Hash + Send + 'static,
+    KeyFn: FnMut(&Item) -> Key + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(PREFETCH).await;
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            requested: AtomicUsize::new(0),
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        });
+        self.shared = Some(shared.clone());
+        self.subscriber
+            .on_subscribe(GroupBySubscription { shared })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let shared = match self.shared.as_ref() {
+            Some(shared) => shared.clone(),
+            None => return,
+        };
+        let key = (self.key_fn)(&item);
+        let mut groups = self.groups.lock().await;
+        if groups.is_done {
+            return;
+        }
+        let is_cancelled = match groups.groups.get(&key) {
+            Some(group) => group.processor.is_cancelled().await,
+            None => false,
+        };
+        if is_cancelled {
+            // A cancelled group is replaced by a new one with the same key.
+            groups.remove(&key);
+        }
+        let mut new_group = None;
+        if !groups.groups.contains_key(&key) {
+            if shared.cancelled.load(Ordering::SeqCst) {
+                // Once the downstream cancelled, no further groups are opened
+                // and the dropped item is requested again for the live ones.
+                drop(groups);
+                shared.upstream.request(1).await;
+                return;
+            }
+            if shared.requested.load(Ordering::Relaxed) == 0 {
+                drop(groups);
+                self.terminate(Some(flow::Error::MissingBackpressure)).await;
+                shared.upstream.cancel().await;
+                return;
+            }
+            shared.requested.fetch_sub(1, Ordering::Relaxed);
+            let group = GroupedFlow {
+                key: key.clone(),
+                processor: shared.group(),
+                shared: shared.clone(),
+                pending: Arc::new(AtomicUsize::new(0)),
+            };
+            groups.groups.insert(
+                key.clone(),
+                Group {
+                    processor: group.processor.clone(),
+                    pending: group.pending.clone(),
+                    tick: 0,
+                },
+            );
+            new_group = Some(group);
+        }
+        let mut schedule = false;
+        if let Some(eviction) = self.eviction.as_ref() {
+            groups.touch(&key, (eviction.now)());
+            schedule = !groups.is_timer_armed;
+            groups.is_timer_armed = true;
+        }
+        let (mut processor, pending) = match groups.groups.get(&key) {
+            Some(group) => (group.processor.clone(), group.pending.clone()),
+            None => return,
+        };
+        drop(groups);
+
+        if let (true, Some(eviction)) = (schedule, self.eviction.as_ref()) {
+            schedule_eviction(self.groups.clone(), eviction.clone(), eviction.duration);
+        }
+        if let Some(group) = new_group {
+            self.subscriber.on_next(group).await;
+        }
+        pending.fetch_add(1, Ordering::SeqCst);
+        core::Subscriber::on_next(&mut processor, item).await;
+        if processor.is_cancelled().await {
+            // The group may have been cancelled while the item was added.
+            shared.reclaim(&pending).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.terminate(Some(error)).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.terminate(None).await;
+    }
+}
+
+struct GroupSubscriber<Subscriber, Subscription> {
+    subscriber: Subscriber,
+    shared: Arc<Shared<Subscription>>,
+    pending: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, GroupSubscription, Item, Error>
+    core::Subscriber<GroupSubscription, Item, Error> for GroupSubscriber<Subscriber, Subscription>
+where
+    Subscriber: core::Subscriber<GroupedFlowSubscription<GroupSubscription, Subscription>, Item, Error>
+        + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    GroupSubscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: GroupSubscription) {
+        self.subscriber
+            .on_subscribe(GroupedFlowSubscription {
+                upstream: subscription,
+                shared: self.shared.clone(),
+                pending: self.pending.clone(),
+            })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.pending.fetch_sub(1, Ordering::SeqCst);
+        self.subscriber.on_next(item).await;
+        // Every item consumed by a group frees a slot for a new one.
+        self.shared.upstream.request(1).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+pub struct GroupedFlowSubscription<Upstream, Subscription> {
+    upstream: Upstream,
+    shared: Arc<Shared<Subscription>>,
+    pending: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl<Upstream, Subscription> core::Subscription for GroupedFlowSubscription<Upstream, Subscription>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.shared.reclaim(&self.pending).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await;
+    }
+}
+
+pub struct GroupBySubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for GroupBySubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::SeqCst) || self.shared.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::flow;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn group_by() {
+        let test_subscriber = TestSubscriber::new(2);
+        vec![0, 1, 2, 3, 4]
+            .into_flow()
+            .group_by(|item| item % 2)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+
+        let mut items = vec![];
+        for group in test_subscriber.items().await {
+            let group_subscriber = TestSubscriber::new(3);
+            let key = *group.key();
+            group.subscribe(group_subscriber.clone()).await;
+            assert_eq!(group_subscriber.status().await, SubscriberStatus::Completed);
+            items.push((key, group_subscriber.items().await));
+        }
+        assert_eq!(items, vec![(0, vec![0, 2, 4]), (1, vec![1, 3])]);
+    }
+
+    #[async_std::test]
+    async fn backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .group_by(|_: &i32| ())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 128);
+        test_flow.emit_all(vec![0, 1, 2]).await;
+
+        let group_subscriber = TestSubscriber::new(2);
+        let group = test_subscriber.items().await.remove(0);
+        group.subscribe(group_subscriber.clone()).await;
+        assert_eq!(group_subscriber.items().await, vec![0, 1]);
+        assert_eq!(test_flow.requested().await, 130);
+    }
+
+    #[async_std::test]
+    async fn cancelled_group() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .group_by(|_: &i32| ())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        let mut group_subscriber = TestSubscriber::default();
+        let group = test_subscriber.items().await.remove(0);
+        group.subscribe(group_subscriber.clone()).await;
+        group_subscriber.cancel().await;
+        test_flow.emit(2).await;
+
+        assert_eq!(test_subscriber.items().await.len(), 2);
+        assert_eq!(test_flow.requested().await, 130);
+    }
+
+    #[async_std::test]
+    async fn cancel_with_live_group() {
+        let mut test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .group_by(|item: &i32| item % 2)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        let mut group_subscriber = TestSubscriber::new(2);
+        let group = test_subscriber.items().await.remove(0);
+        group.subscribe(group_subscriber.clone()).await;
+        test_subscriber.cancel().await;
+        assert!(!test_flow.is_cancelled().await);
+
+        // Items of new keys are dropped, the live group keeps receiving.
+        test_flow.emit_all(vec![1, 2]).await;
+        assert_eq!(test_subscriber.items().await.len(), 1);
+        assert_eq!(group_subscriber.items().await, vec![0, 2]);
+        group_subscriber.cancel().await;
+        assert!(test_flow.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn cancel_completes_live_group() {
+        let mut test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .group_by(|_: &i32| ())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        let group_subscriber = TestSubscriber::new(2);
+        let group = test_subscriber.items().await.remove(0);
+        group.subscribe(group_subscriber.clone()).await;
+        test_subscriber.cancel().await;
+        test_flow.emit(1).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(group_subscriber.items().await, vec![0, 1]);
+        assert_eq!(group_subscriber.status().await, SubscriberStatus::Completed);
+    }
+
+    #[chronobreak::test]
+    async fn eviction() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .group_by_with_eviction(|_: &i32| (), Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        scheduler.join();
+
+        // The idle group is evicted without another item arriving.
+        let group_subscriber = TestSubscriber::new(1);
+        let group = test_subscriber.items().await.remove(0);
+        group.subscribe(group_subscriber.clone()).await;
+        assert_eq!(group_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(group_subscriber.items().await, vec![0]);
+
+        test_flow.emit(1).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await.len(), 2);
+    }
+
+    #[async_std::test]
+    async fn second_subscriber() {
+        let test_subscriber = TestSubscriber::new(1);
+        vec![0]
+            .into_flow()
+            .group_by(|_| ())
+            .subscribe(test_subscriber.clone())
+            .await;
+        let group = test_subscriber.items().await.remove(0);
+        let first_subscriber = TestSubscriber::new(1);
+        let second_subscriber = TestSubscriber::new(1);
+        group.clone().subscribe(first_subscriber.clone()).await;
+        group.subscribe(second_subscriber.clone()).await;
+
+        assert_eq!(first_subscriber.items().await, vec![0]);
+        assert_eq!(
+            second_subscriber.error().await,
+            Some(flow::Error::AlreadySubscribed)
+        );
+    }
+}
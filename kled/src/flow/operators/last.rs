@@ -0,0 +1,88 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::ElementSubscription;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "flow", subscription = "ElementSubscription<Subscription>")]
+pub struct Last {}
+
+#[derive(new)]
+struct LastSubscriber<Subscriber, Item> {
+    subscriber: Subscriber,
+    #[new(default)]
+    last: Option<Item>,
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for LastSubscriber<Subscriber, Item>
+where
+    Subscriber: core::Subscriber<ElementSubscription<Subscription>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(ElementSubscription::new(Arc::new(subscription), usize::MAX))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.last = Some(item);
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        match self.last.take() {
+            Some(item) => {
+                self.subscriber.on_next(item).await;
+                self.subscriber.on_completed().await;
+            }
+            None => self.subscriber.on_error(flow::Error::NoSuchElement).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn last() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .last()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, usize::MAX);
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![2]);
+    }
+
+    #[async_std::test]
+    async fn empty() {
+        let test_subscriber = TestSubscriber::new(1);
+        Vec::<i32>::new()
+            .into_flow()
+            .last()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::NoSuchElement)
+        );
+    }
+}
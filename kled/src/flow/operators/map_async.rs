@@ -0,0 +1,423 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::future::Future;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The type-erased asynchronous function of operators such as
+/// [`Flow::map_async`] and [`Flow::filter_async`]. An output of `None` drops
+/// the item.
+///
+/// [`Flow::map_async`]: ../../core/trait.Flow.html#method.map_async
+/// [`Flow::filter_async`]: ../../core/trait.Flow.html#method.filter_async
+pub type AsyncFn<Item, ItemOut> =
+    Box<dyn FnMut(Item) -> BoxFuture<'static, Option<ItemOut>> + Send>;
+
+pub(crate) fn map_async_fn<Item, ItemOut, UnaryOp, Fut>(
+    mut unary_op: UnaryOp,
+) -> AsyncFn<Item, ItemOut>
+where
+    UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+    Fut: Future<Output = ItemOut> + Send + 'static,
+{
+    Box::new(move |item| {
+        let future = unary_op(item);
+        Box::pin(async move { Some(future.await) })
+    })
+}
+
+pub(crate) fn filter_async_fn<Item, Predicate, Fut>(mut predicate: Predicate) -> AsyncFn<Item, Item>
+where
+    Item: Send + 'static,
+    Predicate: FnMut(&Item) -> Fut + Send + 'static,
+    Fut: Future<Output = bool> + Send + 'static,
+{
+    Box::new(move |item| {
+        let future = predicate(&item);
+        Box::pin(async move {
+            if future.await {
+                Some(item)
+            } else {
+                None
+            }
+        })
+    })
+}
+
+type BoxedSubscriber<Subscription, ItemOut, Error> = Box<
+    dyn core::Subscriber<MapAsyncSubscription<Subscription, ItemOut, Error>, ItemOut, Error>
+        + Send
+        + 'static,
+>;
+
+#[operator(
+    type = "flow",
+    item = "ItemOut",
+    subscription = "MapAsyncSubscription<Subscription, ItemOut, Error>"
+)]
+pub struct MapAsync<ItemOut, Scheduler>
+where
+    Item: Send + 'static,
+    ItemOut: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async_fn: AsyncFn<Item, ItemOut>,
+    concurrency: usize,
+    ordered: bool,
+    scheduler: Scheduler,
+}
+
+struct MapAsyncSubscriber<Subscription, Item, ItemOut, Error, Scheduler> {
+    subscriber: Option<BoxedSubscriber<Subscription, ItemOut, Error>>,
+    shared: Arc<Shared<Subscription, ItemOut, Error>>,
+    async_fn: AsyncFn<Item, ItemOut>,
+    concurrency: usize,
+    ordered: bool,
+    scheduler: Scheduler,
+}
+
+impl<Subscription, Item, ItemOut, Error, Scheduler>
+    MapAsyncSubscriber<Subscription, Item, ItemOut, Error, Scheduler>
+{
+    fn new<Subscriber>(
+        subscriber: Subscriber,
+        async_fn: AsyncFn<Item, ItemOut>,
+        concurrency: usize,
+        ordered: bool,
+        scheduler: Scheduler,
+    ) -> Self
+    where
+        Subscriber: core::Subscriber<MapAsyncSubscription<Subscription, ItemOut, Error>, ItemOut, Error>
+            + Send
+            + 'static,
+    {
+        Self {
+            subscriber: Some(Box::new(subscriber)),
+            shared: Arc::new(Shared {
+                subscriber: Mutex::new(None),
+                upstream: Mutex::new(None),
+                state: Mutex::new(State {
+                    results: VecDeque::new(),
+                    popped: 0,
+                    in_flight: 0,
+                    requested: 0,
+                    error: None,
+                    is_done: false,
+                    is_cancelled: false,
+                    is_terminated: false,
+                }),
+                wip: AtomicUsize::new(0),
+            }),
+            async_fn,
+            concurrency,
+            ordered,
+            scheduler,
+        }
+    }
+}
+
+struct Shared<Subscription, ItemOut, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<Subscription, ItemOut, Error>>>,
+    upstream: Mutex<Option<Arc<Subscription>>>,
+    state: Mutex<State<ItemOut, Error>>,
+    wip: AtomicUsize,
+}
+
+struct State<ItemOut, Error> {
+    /// The results in emission order. A slot is `None` while the result of an
+    /// ordered item is still pending.
+    results: VecDeque<Option<Option<ItemOut>>>,
+    /// The number of slots removed from the front of `results` so far.
+    popped: usize,
+    in_flight: usize,
+    requested: usize,
+    error: Option<flow::Error<Error>>,
+    is_done: bool,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Subscription, ItemOut, Error> Shared<Subscription, ItemOut, Error> {
+    async fn complete(&self, slot: Option<usize>, result: Option<ItemOut>) {
+        let mut state = self.state.lock().await;
+        state.in_flight -= 1;
+        match slot {
+            Some(slot) => {
+                let index = slot - state.popped;
+                state.results[index] = Some(result);
+            }
+            None => state.results.push_back(Some(result)),
+        }
+    }
+}
+
+async fn drain<Subscription, ItemOut, Error>(shared: &Shared<Subscription, ItemOut, Error>)
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = shared.subscriber.lock().await;
+        let mut is_terminated = false;
+        let mut consumed = 0;
+        if let Some(downstream) = subscriber.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    is_terminated = true;
+                    break;
+                }
+                if let Some(error) = state.error.take() {
+                    drop(state);
+                    downstream.on_error(error).await;
+                    is_terminated = true;
+                    break;
+                }
+                match state.results.front() {
+                    Some(Some(None)) => {
+                        state.results.pop_front();
+                        state.popped += 1;
+                        consumed += 1;
+                    }
+                    Some(Some(Some(_))) if state.requested > 0 => {
+                        if state.requested != usize::MAX {
+                            state.requested -= 1;
+                        }
+                        let item = state.results.pop_front().flatten().flatten();
+                        state.popped += 1;
+                        consumed += 1;
+                        drop(state);
+                        if let Some(item) = item {
+                            downstream.on_next(item).await;
+                        }
+                    }
+                    None if state.is_done && state.in_flight == 0 => {
+                        drop(state);
+                        downstream.on_completed().await;
+                        is_terminated = true;
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        if is_terminated {
+            shared.state.lock().await.is_terminated = true;
+            *subscriber = None;
+        }
+        drop(subscriber);
+        if consumed > 0 && !is_terminated {
+            // Each consumed result frees up room for another upstream item.
+            let upstream = shared.upstream.lock().await.clone();
+            if let Some(upstream) = upstream {
+                upstream.request(consumed).await;
+            }
+        }
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, ItemOut, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for MapAsyncSubscriber<Subscription, Item, ItemOut, Error, Scheduler>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = Arc::new(subscription);
+        *self.shared.upstream.lock().await = Some(subscription.clone());
+        if let Some(mut subscriber) = self.subscriber.take() {
+            subscriber
+                .on_subscribe(MapAsyncSubscription {
+                    upstream: subscription.clone(),
+                    shared: self.shared.clone(),
+                })
+                .await;
+            *self.shared.subscriber.lock().await = Some(subscriber);
+        }
+        // At most `concurrency` items are running or buffered at any time.
+        subscription.request(self.concurrency).await;
+        drain(&self.shared).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let slot = {
+            let mut state = self.shared.state.lock().await;
+            if state.is_terminated {
+                return;
+            }
+            state.in_flight += 1;
+            if self.ordered {
+                state.results.push_back(None);
+                Some(state.popped + state.results.len() - 1)
+            } else {
+                None
+            }
+        };
+        let future = (self.async_fn)(item);
+        let shared = self.shared.clone();
+        self.scheduler.schedule(async move {
+            let result = future.await;
+            shared.complete(slot, result).await;
+            drain(&shared).await;
+        });
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.error.is_none() {
+                state.error = Some(error);
+            }
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.is_done = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct MapAsyncSubscription<Upstream, ItemOut, Error> {
+    upstream: Arc<Upstream>,
+    shared: Arc<Shared<Upstream, ItemOut, Error>>,
+}
+
+#[async_trait]
+impl<Upstream, ItemOut, Error> core::Subscription for MapAsyncSubscription<Upstream, ItemOut, Error>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.state.lock().await.is_cancelled = true;
+        self.upstream.cancel().await;
+        drain(&self.shared).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.state.lock().await.is_cancelled
+    }
+
+    async fn request(&self, count: usize) {
+        {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+        }
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    use futures_timer::Delay;
+    use std::time::Duration;
+
+    #[async_std::test]
+    async fn map_async() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::new(3);
+        vec![30, 10, 20]
+            .into_flow()
+            .map_async(
+                |millis| async move {
+                    Delay::new(Duration::from_millis(millis)).await;
+                    millis / 10
+                },
+                3,
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![3, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn map_async_unordered() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::new(3);
+        vec![30, 10, 20]
+            .into_flow()
+            .map_async_unordered(
+                |millis| async move {
+                    Delay::new(Duration::from_millis(millis)).await;
+                    millis / 10
+                },
+                3,
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn filter_async() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::new(5);
+        vec![0, 1, 2, 3, 4]
+            .into_flow()
+            .filter_async(
+                |item| futures::future::ready(item % 2 == 0),
+                2,
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 2, 4]);
+    }
+
+    #[async_std::test]
+    async fn backpressure() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .map_async(|item| async move { item + 1 }, 2, scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 2);
+        test_flow.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await, vec![1]);
+        assert_eq!(test_flow.requested().await, 3);
+        test_subscriber.request_direct(1).await;
+
+        assert_eq!(test_subscriber.items().await, vec![1, 2]);
+        assert_eq!(test_flow.requested().await, 4);
+    }
+}
@@ -0,0 +1,528 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The number of items requested from each source ahead of their emission.
+const PREFETCH: usize = 128;
+
+type BoxedSource<Item, Error, ErrorOut> =
+    Box<dyn FnOnce(Arc<Shared<Item, Error, ErrorOut>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedSubscriber<Item, Error, ErrorOut> = Box<
+    dyn core::Subscriber<MergeSubscription<Item, Error, ErrorOut>, Item, ErrorOut> + Send + 'static,
+>;
+
+type BoxedSubscription = Arc<dyn core::Subscription + Send + Sync>;
+
+type Composite<Error, ErrorOut> = fn(Vec<flow::Error<Error>>) -> flow::Error<ErrorOut>;
+
+/// Merges the items of multiple sources into a single flow. Each source is
+/// requested independently with a prefetch window, while downstream demand
+/// is distributed over the sources round-robin.
+///
+/// If errors are delayed, the errors of all sources are collected and
+/// emitted as a single composite error once all sources terminated.
+pub struct Merge<Item, Error, ErrorOut> {
+    sources: Vec<BoxedSource<Item, Error, ErrorOut>>,
+    delay_error: bool,
+    composite: Composite<Error, ErrorOut>,
+    prefetch: usize,
+}
+
+impl<Item, Error, ErrorOut> Merge<Item, Error, ErrorOut>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    fn with_source<Flow, Subscription>(mut self, flow: Flow) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move { flow.subscribe(MergeSubscriber { shared, index }).await })
+        }));
+        self
+    }
+
+    fn with_sources<Flow, Subscription>(self, sources: Vec<Flow>) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        sources
+            .into_iter()
+            .fold(self, |merge, source| merge.with_source(source))
+    }
+
+    /// Requests `prefetch` items from each source ahead of their emission
+    /// instead of 128. Each source is requested again once three quarters of
+    /// the prefetch window were emitted.
+    pub fn with_prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+}
+
+impl<Item, Error> Merge<Item, Error, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn merge_all<Flow, Subscription>(sources: Vec<Flow>) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        Self {
+            sources: vec![],
+            delay_error: false,
+            composite: |errors| errors.into_iter().next().unwrap(),
+            prefetch: PREFETCH,
+        }
+        .with_sources(sources)
+    }
+
+    pub fn merge2<FlowA, SubscriptionA, FlowB, SubscriptionB>(a: FlowA, b: FlowB) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, Item, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, Item, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+    {
+        Self::merge_all::<FlowA, SubscriptionA>(vec![])
+            .with_source(a)
+            .with_source(b)
+    }
+}
+
+impl<Item, Error> Merge<Item, Error, Vec<flow::Error<Error>>>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn merge_delay_error<Flow, Subscription>(sources: Vec<Flow>) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        Self {
+            sources: vec![],
+            delay_error: true,
+            composite: flow::Error::Upstream,
+            prefetch: PREFETCH,
+        }
+        .with_sources(sources)
+    }
+}
+
+/// Returns a [`Flow`] that emits the items of all `sources` as they arrive.
+/// The first error of any source is emitted immediately.
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+pub fn merge<Flow, Subscription, Item, Error>(sources: Vec<Flow>) -> Merge<Item, Error, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Merge::merge_all(sources)
+}
+
+/// Returns a [`Flow`] that emits the items of all `sources` as they arrive.
+/// Errors are collected and emitted as a single composite error once all
+/// sources terminated.
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+pub fn merge_delay_error<Flow, Subscription, Item, Error>(
+    sources: Vec<Flow>,
+) -> Merge<Item, Error, Vec<flow::Error<Error>>>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Merge::merge_delay_error(sources)
+}
+
+#[async_trait]
+impl<Item, Error, ErrorOut> core::Flow<MergeSubscription<Item, Error, ErrorOut>, Item, ErrorOut>
+    for Merge<Item, Error, ErrorOut>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<MergeSubscription<Item, Error, ErrorOut>, Item, ErrorOut>
+            + Send
+            + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            subscriber: Mutex::new(None),
+            subscriptions: Mutex::new((0..count).map(|_| None).collect()),
+            state: Mutex::new(State {
+                queues: (0..count).map(|_| VecDeque::new()).collect(),
+                consumed: vec![0; count],
+                done: vec![false; count],
+                prefetch: self.prefetch,
+                next: 0,
+                requested: 0,
+                errors: vec![],
+                is_cancelled: false,
+                is_terminated: false,
+            }),
+            delay_error: self.delay_error,
+            composite: self.composite,
+            wip: AtomicUsize::new(0),
+        });
+        subscriber
+            .on_subscribe(MergeSubscription {
+                shared: shared.clone(),
+                cancelled: AtomicBool::new(false),
+            })
+            .await;
+        *shared.subscriber.lock().await = Some(Box::new(subscriber));
+        drain(&shared).await;
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Item, Error, ErrorOut> {
+    subscriber: Mutex<Option<BoxedSubscriber<Item, Error, ErrorOut>>>,
+    subscriptions: Mutex<Vec<Option<BoxedSubscription>>>,
+    state: Mutex<State<Item, Error>>,
+    delay_error: bool,
+    composite: Composite<Error, ErrorOut>,
+    wip: AtomicUsize,
+}
+
+struct State<Item, Error> {
+    queues: Vec<VecDeque<Item>>,
+    consumed: Vec<usize>,
+    done: Vec<bool>,
+    prefetch: usize,
+    next: usize,
+    requested: usize,
+    errors: Vec<flow::Error<Error>>,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Item, Error> State<Item, Error> {
+    /// The number of emitted items of a source after which it is requested
+    /// again.
+    fn limit(&self) -> usize {
+        self.prefetch - self.prefetch / 4
+    }
+
+    fn is_completed(&self) -> bool {
+        self.done.iter().all(|done| *done) && self.queues.iter().all(VecDeque::is_empty)
+    }
+
+    /// Returns the index of the next source with a queued item, starting
+    /// after the source that emitted last.
+    fn next_ready(&self) -> Option<usize> {
+        let count = self.queues.len();
+        (0..count)
+            .map(|offset| (self.next + offset) % count)
+            .find(|index| !self.queues[*index].is_empty())
+    }
+}
+
+impl<Item, Error, ErrorOut> Shared<Item, Error, ErrorOut> {
+    async fn subscriptions(&self) -> Vec<BoxedSubscription> {
+        self.subscriptions
+            .lock()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect()
+    }
+}
+
+async fn drain<Item, Error, ErrorOut>(shared: &Shared<Item, Error, ErrorOut>)
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = shared.subscriber.lock().await;
+        let mut is_terminated = false;
+        if let Some(downstream) = subscriber.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    state.is_terminated = true;
+                    is_terminated = true;
+                    break;
+                }
+                if !state.errors.is_empty() && (!shared.delay_error || state.is_completed()) {
+                    state.is_terminated = true;
+                    let errors = std::mem::take(&mut state.errors);
+                    drop(state);
+                    downstream.on_error((shared.composite)(errors)).await;
+                    is_terminated = true;
+                    break;
+                }
+                if state.is_completed() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_completed().await;
+                    is_terminated = true;
+                    break;
+                }
+                if state.requested == 0 {
+                    break;
+                }
+                let index = match state.next_ready() {
+                    Some(index) => index,
+                    None => break,
+                };
+                let item = state.queues[index].pop_front().unwrap();
+                state.next = (index + 1) % state.queues.len();
+                if state.requested != usize::MAX {
+                    state.requested -= 1;
+                }
+                state.consumed[index] += 1;
+                let limit = state.limit();
+                let replenish = state.consumed[index] == limit;
+                if replenish {
+                    state.consumed[index] = 0;
+                }
+                drop(state);
+                if replenish {
+                    let subscription = shared.subscriptions.lock().await[index].clone();
+                    if let Some(subscription) = subscription {
+                        subscription.request(limit).await;
+                    }
+                }
+                downstream.on_next(item).await;
+            }
+        }
+        if is_terminated {
+            *subscriber = None;
+        }
+        drop(subscriber);
+        if is_terminated {
+            for subscription in shared.subscriptions().await {
+                subscription.cancel().await;
+            }
+        }
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+struct MergeSubscriber<Item, Error, ErrorOut> {
+    shared: Arc<Shared<Item, Error, ErrorOut>>,
+    index: usize,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, ErrorOut> core::Subscriber<Subscription, Item, Error>
+    for MergeSubscriber<Item, Error, ErrorOut>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription: BoxedSubscription = Arc::new(subscription);
+        self.shared.subscriptions.lock().await[self.index] = Some(subscription.clone());
+        let state = self.shared.state.lock().await;
+        let (is_terminated, prefetch) = (state.is_terminated, state.prefetch);
+        drop(state);
+        if is_terminated {
+            subscription.cancel().await;
+        } else {
+            subscription.request(prefetch).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.shared.state.lock().await.queues[self.index].push_back(item);
+        drain(&self.shared).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        {
+            let mut state = self.shared.state.lock().await;
+            state.errors.push(error);
+            state.done[self.index] = true;
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.done[self.index] = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct MergeSubscription<Item, Error, ErrorOut> {
+    shared: Arc<Shared<Item, Error, ErrorOut>>,
+    cancelled: AtomicBool,
+}
+
+#[async_trait]
+impl<Item, Error, ErrorOut> core::Subscription for MergeSubscription<Item, Error, ErrorOut>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.shared.state.lock().await.is_cancelled = true;
+        drain(&self.shared).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+        }
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn merge_with() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .merge_with(b.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(a.requested().await, 128);
+        assert_eq!(b.requested().await, 128);
+        a.emit(0).await;
+        b.emit(1).await;
+        a.emit(2).await;
+        a.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        b.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn with_prefetch() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .merge_with(b.clone())
+            .with_prefetch(4)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(a.requested().await, 4);
+        assert_eq!(b.requested().await, 4);
+        a.emit_all(vec![0, 1]).await;
+        assert_eq!(a.requested().await, 4);
+        a.emit(2).await;
+
+        assert_eq!(a.requested().await, 7);
+        assert_eq!(b.requested().await, 4);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn fair_demand() {
+        let test_subscriber = TestSubscriber::new(0);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        operators::merge(vec![a.clone(), b.clone()])
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_all(vec![0, 1, 2]).await;
+        b.emit_all(vec![10, 11, 12]).await;
+        test_subscriber.request_direct(3).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 10, 1]);
+        a.emit_completed().await;
+        b.emit_completed().await;
+        test_subscriber.request_direct(3).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 10, 1, 11, 2, 12]);
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_item_type(0);
+        let b = TestFlow::default().annotate_item_type(0);
+        operators::merge(vec![a.clone(), b.clone()])
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_error(()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+        assert!(b.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn merge_delay_error() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default();
+        let b = TestFlow::default();
+        operators::merge_delay_error(vec![a.clone(), b.clone()])
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_error(0).await;
+        b.emit(1).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        b.emit_error(2).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, vec![1]);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(vec![
+                flow::Error::Upstream(0),
+                flow::Error::Upstream(2)
+            ]))
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct OnErrorComplete {}
+
+#[derive(new)]
+struct OnErrorCompleteSubscriber<Subscriber> {
+    subscriber: Subscriber,
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error> core::Subscriber<Subscription, Item, Error>
+    for OnErrorCompleteSubscriber<Subscriber>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, _: flow::Error<Error>) {
+        self.subscriber.on_completed().await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn on_error_complete() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .on_error_complete()
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_flow.emit_error(()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+}
@@ -0,0 +1,254 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "OnErrorResumeNextSubscription<Subscription, FallbackSubscription>"
+)]
+pub struct OnErrorResumeNext<Fallback, FallbackSubscription, ResumeFn>
+where
+    Fallback: core::Flow<FallbackSubscription, Item, Error>,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ResumeFn: FnMut(flow::Error<Error>) -> Fallback + Send,
+{
+    resume_fn: ResumeFn,
+}
+
+struct OnErrorResumeNextSubscriber<Subscriber, FallbackSubscription, ResumeFn> {
+    subscriber: Option<Subscriber>,
+    shared: Arc<Shared<FallbackSubscription>>,
+    resume_fn: ResumeFn,
+}
+
+struct Shared<FallbackSubscription> {
+    state: Mutex<State<FallbackSubscription>>,
+}
+
+struct State<FallbackSubscription> {
+    fallback: Option<Arc<FallbackSubscription>>,
+    requested: usize,
+    is_cancelled: bool,
+}
+
+impl<Subscriber, FallbackSubscription, ResumeFn>
+    OnErrorResumeNextSubscriber<Subscriber, FallbackSubscription, ResumeFn>
+{
+    fn new(subscriber: Subscriber, resume_fn: ResumeFn) -> Self {
+        Self {
+            subscriber: Some(subscriber),
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    fallback: None,
+                    requested: 0,
+                    is_cancelled: false,
+                }),
+            }),
+            resume_fn,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Fallback, FallbackSubscription, ResumeFn>
+    core::Subscriber<Subscription, Item, Error>
+    for OnErrorResumeNextSubscriber<Subscriber, FallbackSubscription, ResumeFn>
+where
+    Subscriber: core::Subscriber<
+            OnErrorResumeNextSubscription<Subscription, FallbackSubscription>,
+            Item,
+            Error,
+        > + Send
+        + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Fallback: core::Flow<FallbackSubscription, Item, Error> + Send,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+    ResumeFn: FnMut(flow::Error<Error>) -> Fallback + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber
+                .on_subscribe(OnErrorResumeNextSubscription {
+                    upstream: subscription,
+                    shared: self.shared.clone(),
+                })
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.requested != usize::MAX {
+                state.requested = state.requested.saturating_sub(1);
+            }
+        }
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if let Some(subscriber) = self.subscriber.take() {
+            (self.resume_fn)(error)
+                .subscribe(FallbackSubscriber {
+                    subscriber,
+                    shared: self.shared.clone(),
+                    phantom: PhantomData,
+                })
+                .await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber.on_completed().await;
+        }
+    }
+}
+
+struct FallbackSubscriber<Subscriber, Subscription, FallbackSubscription> {
+    subscriber: Subscriber,
+    shared: Arc<Shared<FallbackSubscription>>,
+    phantom: PhantomData<Subscription>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, Item, Error, FallbackSubscription>
+    core::Subscriber<FallbackSubscription, Item, Error>
+    for FallbackSubscriber<Subscriber, Subscription, FallbackSubscription>
+where
+    Subscriber: core::Subscriber<
+            OnErrorResumeNextSubscription<Subscription, FallbackSubscription>,
+            Item,
+            Error,
+        > + Send,
+    Subscription: Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: FallbackSubscription) {
+        let subscription = Arc::new(subscription);
+        let (is_cancelled, requested) = {
+            let mut state = self.shared.state.lock().await;
+            state.fallback = Some(subscription.clone());
+            (state.is_cancelled, state.requested)
+        };
+        if is_cancelled {
+            subscription.cancel().await;
+        } else if requested > 0 {
+            // The demand which was not fulfilled by the upstream carries over
+            // to the fallback.
+            subscription.request(requested).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+pub struct OnErrorResumeNextSubscription<Upstream, FallbackSubscription> {
+    upstream: Upstream,
+    shared: Arc<Shared<FallbackSubscription>>,
+}
+
+#[async_trait]
+impl<Upstream, FallbackSubscription> core::Subscription
+    for OnErrorResumeNextSubscription<Upstream, FallbackSubscription>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        let fallback = {
+            let mut state = self.shared.state.lock().await;
+            state.is_cancelled = true;
+            state.fallback.clone()
+        };
+        self.upstream.cancel().await;
+        if let Some(fallback) = fallback {
+            fallback.cancel().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.state.lock().await.is_cancelled
+    }
+
+    async fn request(&self, count: usize) {
+        let fallback = {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+            state.fallback.clone()
+        };
+        match fallback {
+            Some(fallback) => fallback.request(count).await,
+            None => self.upstream.request(count).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn on_error_resume_next() {
+        let test_subscriber = TestSubscriber::new(3);
+        let test_flow = TestFlow::default();
+        let fallback = TestFlow::default();
+        let resume_fallback = fallback.clone();
+        test_flow
+            .clone()
+            .on_error_resume_next(move |_| resume_fallback.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_flow.emit_error(()).await;
+        assert_eq!(fallback.requested().await, 2);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(fallback.requested().await, 3);
+        fallback.emit_all(vec![1, 2, 3]).await;
+        fallback.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_item_type(0);
+        let fallback = TestFlow::default();
+        let resume_fallback = fallback.clone();
+        test_flow
+            .clone()
+            .on_error_resume_next(move |_| resume_fallback.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_error(()).await;
+        test_subscriber.cancel().await;
+
+        assert!(fallback.is_cancelled().await);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedSubscriber<Subscription, Item, Error> = Box<
+    dyn core::Subscriber<OnErrorReturnSubscription<Subscription, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+#[operator(
+    type = "flow",
+    subscription = "OnErrorReturnSubscription<Subscription, Item, Error>"
+)]
+pub struct OnErrorReturn<ItemFn>
+where
+    ItemFn: FnMut(flow::Error<Error>) -> Item + Send,
+{
+    item_fn: ItemFn,
+}
+
+struct OnErrorReturnSubscriber<Subscription, Item, Error, ItemFn> {
+    shared: Arc<Shared<Subscription, Item, Error>>,
+    item_fn: ItemFn,
+}
+
+struct Shared<Subscription, Item, Error> {
+    subscriber: Mutex<BoxedSubscriber<Subscription, Item, Error>>,
+    state: Mutex<State<Item>>,
+}
+
+struct State<Item> {
+    requested: usize,
+    pending: Option<Item>,
+}
+
+impl<Subscription, Item, Error, ItemFn> OnErrorReturnSubscriber<Subscription, Item, Error, ItemFn> {
+    pub fn new<Subscriber>(subscriber: Subscriber, item_fn: ItemFn) -> Self
+    where
+        Subscriber: core::Subscriber<OnErrorReturnSubscription<Subscription, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        Self {
+            shared: Arc::new(Shared {
+                subscriber: Mutex::new(Box::new(subscriber)),
+                state: Mutex::new(State {
+                    requested: 0,
+                    pending: None,
+                }),
+            }),
+            item_fn,
+        }
+    }
+}
+
+impl<Subscription, Item, Error> Shared<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn emit_last(&self, item: Item) {
+        let mut subscriber = self.subscriber.lock().await;
+        subscriber.on_next(item).await;
+        subscriber.on_completed().await;
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, ItemFn> core::Subscriber<Subscription, Item, Error>
+    for OnErrorReturnSubscriber<Subscription, Item, Error, ItemFn>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ItemFn: FnMut(flow::Error<Error>) -> Item + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = OnErrorReturnSubscription {
+            upstream: subscription,
+            shared: self.shared.clone(),
+        };
+        self.shared
+            .subscriber
+            .lock()
+            .await
+            .on_subscribe(subscription)
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.requested != usize::MAX {
+                state.requested = state.requested.saturating_sub(1);
+            }
+        }
+        self.shared.subscriber.lock().await.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let item = (self.item_fn)(error);
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.requested == 0 {
+                // The fallback item is emitted as soon as it is requested.
+                state.pending = Some(item);
+                return;
+            }
+        }
+        self.shared.emit_last(item).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.subscriber.lock().await.on_completed().await;
+    }
+}
+
+pub struct OnErrorReturnSubscription<Upstream, Item, Error> {
+    upstream: Upstream,
+    shared: Arc<Shared<Upstream, Item, Error>>,
+}
+
+#[async_trait]
+impl<Upstream, Item, Error> core::Subscription for OnErrorReturnSubscription<Upstream, Item, Error>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        let pending = {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+            state.pending.take()
+        };
+        match pending {
+            Some(item) => self.shared.emit_last(item).await,
+            None => self.upstream.request(count).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn on_error_return() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .on_error_return(|_| 1)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_flow.emit_error(()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+    }
+
+    #[async_std::test]
+    async fn missing_backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .on_backpressure_error()
+            .on_error_return(|error| match error {
+                flow::Error::MissingBackpressure => -1,
+                _ => -2,
+            })
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        test_subscriber.request_direct(1).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, -1]);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::SlidingSubscription;
+use async_trait::async_trait;
+
+#[operator(
+    type = "flow",
+    subscription = "SlidingSubscription<Subscription>",
+    item = "(Item, Item)"
+)]
+pub struct Pairwise
+where
+    Item: Clone, {}
+
+#[derive(new)]
+struct PairwiseSubscriber<Subscriber, Item> {
+    subscriber: Subscriber,
+    #[new(default)]
+    previous: Option<Item>,
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for PairwiseSubscriber<Subscriber, Item>
+where
+    Subscriber: core::Subscriber<SlidingSubscription<Subscription>, (Item, Item), Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(SlidingSubscription::new(subscription, 1))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(previous) = self.previous.replace(item.clone()) {
+            self.subscriber.on_next((previous, item)).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn pairwise() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        vec![0, 1, 2]
+            .into_flow()
+            .pairwise()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![(0, 1), (1, 2)]);
+    }
+
+    #[async_std::test]
+    async fn pairwise_backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .pairwise()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 2);
+        test_flow.emit_all(vec![0, 1]).await;
+
+        assert_eq!(test_subscriber.items().await, vec![(0, 1)]);
+    }
+}
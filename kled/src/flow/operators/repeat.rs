@@ -0,0 +1,244 @@
+use super::resubscribe::*;
+use crate::core;
+use crate::flow;
+use crate::processor::UnicastProcessor;
+use crate::Never;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+
+pub type RepeatSubscription<Upstream> = ResubscribeSubscription<Upstream>;
+
+pub struct Repeat<Flow, Subscription, Item, Error>(Resubscribe<Flow, Subscription, Item, Error>);
+
+impl<Flow, Subscription, Item, Error> Repeat<Flow, Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    /// Subscribes to `flow` `count` times in total, one subscription after
+    /// the completion of the previous one.
+    pub fn new(flow: Flow, count: usize) -> Self {
+        let mut repeats = 1;
+        Self(Resubscribe::with_setup(flow, move |resubscriber| {
+            Box::pin(async move {
+                if count == 0 {
+                    resubscriber.on_completed().await;
+                }
+                let on_termination: OnTermination<Subscription, Item, Error> =
+                    Box::new(move |termination, resubscriber| match termination {
+                        Termination::Completed if repeats < count => {
+                            repeats += 1;
+                            Box::pin(resubscriber.resubscribe())
+                        }
+                        Termination::Completed => Box::pin(resubscriber.on_completed()),
+                        Termination::Failed(error) => {
+                            Box::pin(resubscriber.on_error(flow::Error::Upstream(error)))
+                        }
+                    });
+                on_termination
+            })
+        }))
+    }
+
+    /// Resubscribes to `flow` on every completion.
+    pub fn forever(flow: Flow) -> Self {
+        Self::with_repeat_fn(flow, |resubscriber| Box::pin(resubscriber.resubscribe()))
+    }
+
+    /// Passes the flow of all completions to `repeat_when` and subscribes to
+    /// the returned notifier before subscribing to `flow`. Each item of the
+    /// notifier resubscribes to `flow` if it completed, while the returned
+    /// flow terminates once the notifier terminates.
+    pub fn when<RepeatWhen, Notifier, NotifierSubscription, NotifierItem>(
+        flow: Flow,
+        repeat_when: RepeatWhen,
+    ) -> Self
+    where
+        RepeatWhen: FnOnce(UnicastProcessor<Never, (), Error>) -> Notifier + Send + 'static,
+        Notifier: core::Flow<NotifierSubscription, NotifierItem, Error> + Send + 'static,
+        NotifierSubscription: core::Subscription + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Self(Resubscribe::with_setup(flow, move |resubscriber| {
+            Box::pin(async move {
+                let completions = UnicastProcessor::default();
+                repeat_when(completions.clone())
+                    .subscribe(NotifierSubscriber::new(resubscriber))
+                    .await;
+                let on_termination: OnTermination<Subscription, Item, Error> =
+                    Box::new(move |termination, resubscriber| match termination {
+                        Termination::Completed => {
+                            let mut completions = completions.clone();
+                            Box::pin(async move {
+                                core::Subscriber::on_next(&mut completions, ()).await;
+                            })
+                        }
+                        Termination::Failed(error) => {
+                            Box::pin(resubscriber.on_error(flow::Error::Upstream(error)))
+                        }
+                    });
+                on_termination
+            })
+        }))
+    }
+
+    fn with_repeat_fn<F>(flow: Flow, mut repeat_fn: F) -> Self
+    where
+        F: FnMut(Resubscriber<Subscription, Item, Error>) -> BoxFuture<'static, ()>
+            + Send
+            + 'static,
+    {
+        Self(Resubscribe::new(
+            flow,
+            move |termination, resubscriber| match termination {
+                Termination::Completed => repeat_fn(resubscriber),
+                Termination::Failed(error) => {
+                    Box::pin(resubscriber.on_error(flow::Error::Upstream(error)))
+                }
+            },
+        ))
+    }
+}
+
+#[async_trait]
+impl<Flow, Subscription, Item, Error> core::Flow<RepeatSubscription<Subscription>, Item, Error>
+    for Repeat<Flow, Subscription, Item, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Clone + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber:
+            core::Subscriber<RepeatSubscription<Subscription>, Item, Error> + Send + 'static,
+    {
+        self.0.subscribe(subscriber).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use crate::subscription::*;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    /// Emits `0` and `1` and completes, recording the number of items
+    /// requested at subscription.
+    #[derive(Clone, Default)]
+    struct CountingFlow {
+        requested: Arc<Mutex<Vec<usize>>>,
+    }
+
+    #[async_trait]
+    impl core::Flow<ArcSubscription, i32, ()> for CountingFlow {
+        async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+        where
+            Subscriber: core::Subscriber<ArcSubscription, i32, ()> + Send + 'static,
+        {
+            let mut emitter = BoxEmitter::from(subscriber).await;
+            self.requested.lock().unwrap().push(emitter.requested());
+            emitter.on_next(0).await;
+            emitter.on_next(1).await;
+            emitter.on_completed().await;
+        }
+    }
+
+    #[async_std::test]
+    async fn repeat() {
+        let test_subscriber = TestSubscriber::new(8);
+        let counting_flow = CountingFlow::default();
+        counting_flow
+            .clone()
+            .repeat(2)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 0, 1]);
+        assert_eq!(*counting_flow.requested.lock().unwrap(), vec![8, 6]);
+    }
+
+    #[async_std::test]
+    async fn repeat_zero() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let counting_flow = CountingFlow::default();
+        counting_flow
+            .clone()
+            .repeat(0)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, Vec::<i32>::new());
+        assert!(counting_flow.requested.lock().unwrap().is_empty());
+    }
+
+    #[async_std::test]
+    async fn repeat_forever() {
+        let test_subscriber = TestSubscriber::new(1);
+        CountingFlow::default()
+            .repeat_forever()
+            .element_at(4)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn repeat_when() {
+        let test_subscriber = TestSubscriber::new(1);
+        let counting_flow = CountingFlow::default();
+        counting_flow
+            .clone()
+            .repeat_when(|completions| completions.map(|_| ()))
+            .element_at(4)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(counting_flow.requested.lock().unwrap().len(), 3);
+    }
+
+    #[async_std::test]
+    async fn repeat_when_notifier() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let notifier = TestFlow::default().annotate_item_type(());
+        let repeat_notifier = notifier.clone();
+        CountingFlow::default()
+            .repeat_when(move |_| repeat_notifier)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(notifier.requested().await, 1);
+        notifier.emit(()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        notifier.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 0, 1]);
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_subscriber = TestSubscriber::new(usize::MAX);
+        let notifier = TestFlow::default().annotate_item_type(());
+        let repeat_notifier = notifier.clone();
+        CountingFlow::default()
+            .repeat_when(move |_| repeat_notifier)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_subscriber.cancel().await;
+
+        assert!(notifier.is_cancelled().await);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Cancelled);
+    }
+}
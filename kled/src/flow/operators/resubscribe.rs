@@ -0,0 +1,435 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::{self, BoxFuture};
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedSubscriber<Subscription, Item, Error> =
+    Box<dyn core::Subscriber<ResubscribeSubscription<Subscription>, Item, Error> + Send + 'static>;
+
+type BoxedSource<Subscription, Item, Error> =
+    Box<dyn Fn(ResubscribeSubscriber<Subscription, Item, Error>) -> BoxFuture<'static, ()> + Send>;
+
+pub(crate) type BoxedCancel = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+pub(crate) type OnTermination<Subscription, Item, Error> = Box<
+    dyn FnMut(Termination<Error>, Resubscriber<Subscription, Item, Error>) -> BoxFuture<'static, ()>
+        + Send,
+>;
+
+type Setup<Subscription, Item, Error> = Box<
+    dyn FnOnce(
+            Resubscriber<Subscription, Item, Error>,
+        ) -> BoxFuture<'static, OnTermination<Subscription, Item, Error>>
+        + Send,
+>;
+
+/// How a subscription to the source of `retry` or `repeat` terminated.
+pub(crate) enum Termination<Error> {
+    Completed,
+    Failed(Error),
+}
+
+/// The resubscription loop shared by `retry` and `repeat`, which hands each
+/// termination of the source to the operator's `OnTermination`.
+pub(crate) struct Resubscribe<Flow, Subscription, Item, Error> {
+    flow: Flow,
+    setup: Setup<Subscription, Item, Error>,
+}
+
+impl<Flow, Subscription, Item, Error> Resubscribe<Flow, Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub(crate) fn new<F>(flow: Flow, on_termination: F) -> Self
+    where
+        F: FnMut(
+                Termination<Error>,
+                Resubscriber<Subscription, Item, Error>,
+            ) -> BoxFuture<'static, ()>
+            + Send
+            + 'static,
+    {
+        let on_termination: OnTermination<Subscription, Item, Error> = Box::new(on_termination);
+        Self::with_setup(flow, move |_| Box::pin(future::ready(on_termination)))
+    }
+
+    /// Like `new`, but `setup` is called once the downstream subscribed and
+    /// before the first subscription to `flow`, which is skipped if `setup`
+    /// already terminated the downstream.
+    pub(crate) fn with_setup<F>(flow: Flow, setup: F) -> Self
+    where
+        F: FnOnce(
+                Resubscriber<Subscription, Item, Error>,
+            ) -> BoxFuture<'static, OnTermination<Subscription, Item, Error>>
+            + Send
+            + 'static,
+    {
+        Self {
+            flow,
+            setup: Box::new(setup),
+        }
+    }
+}
+
+#[async_trait]
+impl<Flow, Subscription, Item, Error> core::Flow<ResubscribeSubscription<Subscription>, Item, Error>
+    for Resubscribe<Flow, Subscription, Item, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Clone + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber:
+            core::Subscriber<ResubscribeSubscription<Subscription>, Item, Error> + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(State {
+            upstream: None,
+            notifier: None,
+            requested: 0,
+            has_emitted: false,
+            is_active: false,
+            is_subscribing: false,
+            is_pending: false,
+            is_done: false,
+            is_cancelled: false,
+        }));
+        subscriber
+            .on_subscribe(ResubscribeSubscription {
+                state: state.clone(),
+            })
+            .await;
+        let flow = self.flow;
+        let resubscriber = Resubscriber {
+            data: Arc::new(Mutex::new(Data {
+                subscriber: Box::new(subscriber),
+                source: Box::new(move |subscriber| flow.clone().subscribe(subscriber)),
+                on_termination: None,
+            })),
+            state,
+            has_emitted: false,
+        };
+        let on_termination = (self.setup)(resubscriber.clone()).await;
+        resubscriber.data.lock().await.on_termination = Some(on_termination);
+        resubscriber.resubscribe().await;
+    }
+}
+
+struct Data<Subscription, Item, Error> {
+    subscriber: BoxedSubscriber<Subscription, Item, Error>,
+    source: BoxedSource<Subscription, Item, Error>,
+    on_termination: Option<OnTermination<Subscription, Item, Error>>,
+}
+
+struct State<Upstream> {
+    upstream: Option<Arc<Upstream>>,
+    /// Cancels the notifier or the delay which the next subscription waits
+    /// for.
+    notifier: Option<BoxedCancel>,
+    requested: usize,
+    has_emitted: bool,
+    /// Whether the source was subscribed to and did not terminate yet.
+    is_active: bool,
+    is_subscribing: bool,
+    is_pending: bool,
+    is_done: bool,
+    is_cancelled: bool,
+}
+
+/// Decides how the subscription continues after the source terminated.
+pub(crate) struct Resubscriber<Subscription, Item, Error> {
+    data: Arc<Mutex<Data<Subscription, Item, Error>>>,
+    state: Arc<Mutex<State<Subscription>>>,
+    /// Whether the terminated subscription emitted any items.
+    pub(crate) has_emitted: bool,
+}
+
+impl<Subscription, Item, Error> Clone for Resubscriber<Subscription, Item, Error> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            state: self.state.clone(),
+            has_emitted: self.has_emitted,
+        }
+    }
+}
+
+impl<Subscription, Item, Error> Resubscriber<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    /// Subscribes to the source again unless it is still active, or the
+    /// subscription was terminated or cancelled.
+    pub(crate) async fn resubscribe(self) {
+        {
+            let mut state = self.state.lock().await;
+            if state.is_active || state.is_done || state.is_cancelled {
+                return;
+            }
+            state.is_active = true;
+            // A source which terminates synchronously within subscribe is
+            // resubscribed to by the loop below instead of recursively.
+            if state.is_subscribing {
+                state.is_pending = true;
+                return;
+            }
+            state.is_subscribing = true;
+        }
+        loop {
+            let subscribe = (self.data.lock().await.source)(ResubscribeSubscriber {
+                data: self.data.clone(),
+                state: self.state.clone(),
+            });
+            subscribe.await;
+            let mut state = self.state.lock().await;
+            if !state.is_pending || state.is_done || state.is_cancelled {
+                state.is_subscribing = false;
+                break;
+            }
+            state.is_pending = false;
+        }
+    }
+
+    /// Registers `cancel` to be called if the subscription is cancelled or
+    /// terminated. Returns false if that happened already.
+    pub(crate) async fn wait_on(&self, cancel: BoxedCancel) -> bool {
+        let mut state = self.state.lock().await;
+        state.notifier = Some(cancel);
+        !state.is_done && !state.is_cancelled
+    }
+
+    pub(crate) async fn on_error(self, error: flow::Error<Error>) {
+        if self.terminate().await {
+            self.data.lock().await.subscriber.on_error(error).await;
+        }
+    }
+
+    pub(crate) async fn on_completed(self) {
+        if self.terminate().await {
+            self.data.lock().await.subscriber.on_completed().await;
+        }
+    }
+
+    /// Marks the subscription as terminated and cancels both the notifier and
+    /// an active source. Returns false if it was terminated already.
+    async fn terminate(&self) -> bool {
+        let (upstream, notifier) = {
+            let mut state = self.state.lock().await;
+            if std::mem::replace(&mut state.is_done, true) {
+                return false;
+            }
+            let upstream = if state.is_active {
+                state.upstream.clone()
+            } else {
+                None
+            };
+            (upstream, state.notifier.take())
+        };
+        if let Some(upstream) = upstream {
+            upstream.cancel().await;
+        }
+        if let Some(cancel) = notifier {
+            cancel().await;
+        }
+        true
+    }
+}
+
+struct ResubscribeSubscriber<Subscription, Item, Error> {
+    data: Arc<Mutex<Data<Subscription, Item, Error>>>,
+    state: Arc<Mutex<State<Subscription>>>,
+}
+
+impl<Subscription, Item, Error> ResubscribeSubscriber<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_termination(&self, termination: Termination<Error>) {
+        let has_emitted = {
+            let mut state = self.state.lock().await;
+            state.is_active = false;
+            std::mem::replace(&mut state.has_emitted, false)
+        };
+        let resubscriber = Resubscriber {
+            data: self.data.clone(),
+            state: self.state.clone(),
+            has_emitted,
+        };
+        let on_termination = self
+            .data
+            .lock()
+            .await
+            .on_termination
+            .as_mut()
+            .map(|on_termination| on_termination(termination, resubscriber));
+        if let Some(on_termination) = on_termination {
+            on_termination.await;
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for ResubscribeSubscriber<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = Arc::new(subscription);
+        let (is_terminated, requested) = {
+            let mut state = self.state.lock().await;
+            state.upstream = Some(subscription.clone());
+            (state.is_done || state.is_cancelled, state.requested)
+        };
+        if is_terminated {
+            subscription.cancel().await;
+        } else if requested > 0 {
+            // The demand which was not fulfilled by the previous
+            // subscriptions carries over to the new one.
+            subscription.request(requested).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        {
+            let mut state = self.state.lock().await;
+            state.has_emitted = true;
+            if state.requested != usize::MAX {
+                state.requested = state.requested.saturating_sub(1);
+            }
+        }
+        self.data.lock().await.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        match error {
+            flow::Error::Upstream(error) => self.on_termination(Termination::Failed(error)).await,
+            error => {
+                let resubscriber = Resubscriber {
+                    data: self.data.clone(),
+                    state: self.state.clone(),
+                    has_emitted: false,
+                };
+                resubscriber.on_error(error).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        self.on_termination(Termination::Completed).await;
+    }
+}
+
+/// Subscribes to the source again for each item of the notifier of
+/// `retry_when` or `repeat_when`, and terminates once the notifier does.
+pub(crate) struct NotifierSubscriber<Subscription, Item, Error, NotifierSubscription> {
+    resubscriber: Resubscriber<Subscription, Item, Error>,
+    subscription: Option<Arc<NotifierSubscription>>,
+}
+
+impl<Subscription, Item, Error, NotifierSubscription>
+    NotifierSubscriber<Subscription, Item, Error, NotifierSubscription>
+{
+    pub(crate) fn new(resubscriber: Resubscriber<Subscription, Item, Error>) -> Self {
+        Self {
+            resubscriber,
+            subscription: None,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, NotifierSubscription, NotifierItem>
+    core::Subscriber<NotifierSubscription, NotifierItem, Error>
+    for NotifierSubscriber<Subscription, Item, Error, NotifierSubscription>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NotifierSubscription: core::Subscription + Send + Sync + 'static,
+    NotifierItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: NotifierSubscription) {
+        let subscription = Arc::new(subscription);
+        let cancel: BoxedCancel = {
+            let subscription = subscription.clone();
+            Arc::new(move || {
+                let subscription = subscription.clone();
+                Box::pin(async move { subscription.cancel().await })
+            })
+        };
+        if self.resubscriber.wait_on(cancel).await {
+            subscription.request(1).await;
+        } else {
+            subscription.cancel().await;
+        }
+        self.subscription = Some(subscription);
+    }
+
+    async fn on_next(&mut self, _: NotifierItem) {
+        self.resubscriber.clone().resubscribe().await;
+        if let Some(subscription) = self.subscription.as_ref() {
+            subscription.request(1).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.resubscriber.clone().on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.resubscriber.clone().on_completed().await;
+    }
+}
+
+pub struct ResubscribeSubscription<Upstream> {
+    state: Arc<Mutex<State<Upstream>>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for ResubscribeSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        let (upstream, notifier) = {
+            let mut state = self.state.lock().await;
+            state.is_cancelled = true;
+            (state.upstream.clone(), state.notifier.take())
+        };
+        if let Some(upstream) = upstream {
+            upstream.cancel().await;
+        }
+        if let Some(cancel) = notifier {
+            cancel().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.state.lock().await.is_cancelled
+    }
+
+    async fn request(&self, count: usize) {
+        let upstream = {
+            let mut state = self.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+            state.upstream.clone()
+        };
+        if let Some(upstream) = upstream {
+            upstream.request(count).await;
+        }
+    }
+}
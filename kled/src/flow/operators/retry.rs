@@ -0,0 +1,402 @@
+use super::resubscribe::*;
+use crate::core;
+use crate::flow;
+use crate::processor::UnicastProcessor;
+use crate::Never;
+use async_trait::async_trait;
+use futures::future::{self, BoxFuture};
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+pub type RetrySubscription<Upstream> = ResubscribeSubscription<Upstream>;
+
+pub struct Retry<Flow, Subscription, Item, Error>(Resubscribe<Flow, Subscription, Item, Error>);
+
+impl<Flow, Subscription, Item, Error> Retry<Flow, Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    /// Resubscribes to `flow` on up to `count` errors in total.
+    pub fn new(flow: Flow, count: usize) -> Self {
+        let mut retries = 0;
+        Self::with_retry_fn(flow, move |error, resubscriber| {
+            if retries < count {
+                retries += 1;
+                Box::pin(resubscriber.resubscribe())
+            } else {
+                Box::pin(resubscriber.on_error(flow::Error::Upstream(error)))
+            }
+        })
+    }
+
+    /// Resubscribes to `flow` on each error for which `predicate` returns
+    /// true.
+    pub fn with_predicate<Predicate>(flow: Flow, mut predicate: Predicate) -> Self
+    where
+        Predicate: FnMut(&Error) -> bool + Send + 'static,
+    {
+        Self::with_retry_fn(flow, move |error, resubscriber| {
+            if predicate(&error) {
+                Box::pin(resubscriber.resubscribe())
+            } else {
+                Box::pin(resubscriber.on_error(flow::Error::Upstream(error)))
+            }
+        })
+    }
+
+    /// Resubscribes to `flow` on each error after an exponentially growing
+    /// delay, which is timed on the given scheduler. Once the upstream emits
+    /// an item, the delay starts over.
+    pub fn with_backoff<Scheduler>(
+        flow: Flow,
+        initial: Duration,
+        max: Duration,
+        factor: f64,
+        jitter: f64,
+        scheduler: Scheduler,
+    ) -> Self
+    where
+        Scheduler: core::Scheduler,
+    {
+        let mut attempts = 0;
+        Self::with_retry_fn(flow, move |_, resubscriber| {
+            if resubscriber.has_emitted {
+                attempts = 0;
+            }
+            let delay = backoff_delay(initial, max, factor, jitter, attempts);
+            attempts = attempts.saturating_add(1);
+            let (delay, handle) = future::abortable(Delay::new(delay));
+            let cancel: BoxedCancel = Arc::new(move || {
+                handle.abort();
+                Box::pin(future::ready(()))
+            });
+            scheduler.schedule(async move {
+                if resubscriber.wait_on(cancel).await && delay.await.is_ok() {
+                    resubscriber.resubscribe().await;
+                }
+            });
+            Box::pin(future::ready(()))
+        })
+    }
+
+    /// Passes the flow of all errors to `retry_when` and subscribes to the
+    /// returned notifier before subscribing to `flow`. Each item of the
+    /// notifier resubscribes to `flow` if it failed, while the returned flow
+    /// terminates once the notifier terminates.
+    pub fn when<RetryWhen, Notifier, NotifierSubscription, NotifierItem>(
+        flow: Flow,
+        retry_when: RetryWhen,
+    ) -> Self
+    where
+        RetryWhen: FnOnce(UnicastProcessor<Never, Error, Error>) -> Notifier + Send + 'static,
+        Notifier: core::Flow<NotifierSubscription, NotifierItem, Error> + Send + 'static,
+        NotifierSubscription: core::Subscription + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Self(Resubscribe::with_setup(flow, move |resubscriber| {
+            Box::pin(async move {
+                let errors = UnicastProcessor::default();
+                retry_when(errors.clone())
+                    .subscribe(NotifierSubscriber::new(resubscriber))
+                    .await;
+                let on_termination: OnTermination<Subscription, Item, Error> =
+                    Box::new(move |termination, resubscriber| match termination {
+                        Termination::Completed => Box::pin(resubscriber.on_completed()),
+                        Termination::Failed(error) => {
+                            let mut errors = errors.clone();
+                            Box::pin(async move {
+                                core::Subscriber::on_next(&mut errors, error).await;
+                            })
+                        }
+                    });
+                on_termination
+            })
+        }))
+    }
+
+    fn with_retry_fn<F>(flow: Flow, mut retry_fn: F) -> Self
+    where
+        F: FnMut(Error, Resubscriber<Subscription, Item, Error>) -> BoxFuture<'static, ()>
+            + Send
+            + 'static,
+    {
+        Self(Resubscribe::new(
+            flow,
+            move |termination, resubscriber| match termination {
+                Termination::Completed => Box::pin(resubscriber.on_completed()),
+                Termination::Failed(error) => retry_fn(error, resubscriber),
+            },
+        ))
+    }
+}
+
+fn backoff_delay(
+    initial: Duration,
+    max: Duration,
+    factor: f64,
+    jitter: f64,
+    attempt: usize,
+) -> Duration {
+    let exponent = attempt.min(i32::MAX as usize) as i32;
+    let secs = initial.as_secs_f64()
+        * factor.powi(exponent)
+        * (1.0 + jitter * (2.0 * fastrand::f64() - 1.0));
+    Duration::from_secs_f64(secs.min(max.as_secs_f64()))
+}
+
+#[async_trait]
+impl<Flow, Subscription, Item, Error> core::Flow<RetrySubscription<Subscription>, Item, Error>
+    for Retry<Flow, Subscription, Item, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Clone + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<RetrySubscription<Subscription>, Item, Error> + Send + 'static,
+    {
+        self.0.subscribe(subscriber).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::core;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    use crate::subscription::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Emits `0` and then fails with the number of the attempt until it
+    /// failed `failures` times, after which it emits `1` and completes.
+    #[derive(Clone)]
+    struct FlakyFlow {
+        failures: usize,
+        is_silent: bool,
+        attempts: Arc<AtomicUsize>,
+        requested: Arc<std::sync::Mutex<Vec<usize>>>,
+    }
+
+    impl FlakyFlow {
+        fn new(failures: usize) -> Self {
+            Self {
+                failures,
+                is_silent: false,
+                attempts: Arc::new(AtomicUsize::new(0)),
+                requested: Arc::new(std::sync::Mutex::new(vec![])),
+            }
+        }
+
+        /// Like `new`, but without emitting `0` before each attempt.
+        fn silent(failures: usize) -> Self {
+            Self {
+                is_silent: true,
+                ..Self::new(failures)
+            }
+        }
+    }
+
+    #[async_trait]
+    impl core::Flow<ArcSubscription, i32, usize> for FlakyFlow {
+        async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+        where
+            Subscriber: core::Subscriber<ArcSubscription, i32, usize> + Send + 'static,
+        {
+            let mut emitter = BoxEmitter::from(subscriber).await;
+            self.requested.lock().unwrap().push(emitter.requested());
+            if !self.is_silent {
+                emitter.on_next(0).await;
+            }
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            if attempt < self.failures {
+                emitter.on_error(attempt).await;
+            } else {
+                emitter.on_next(1).await;
+                emitter.on_completed().await;
+            }
+        }
+    }
+
+    #[async_std::test]
+    async fn retry() {
+        let test_subscriber = TestSubscriber::new(5);
+        let flaky_flow = FlakyFlow::new(2);
+        flaky_flow
+            .clone()
+            .retry(2)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 0, 0, 1]);
+        assert_eq!(*flaky_flow.requested.lock().unwrap(), vec![5, 4, 3]);
+    }
+
+    #[async_std::test]
+    async fn retry_many() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        FlakyFlow::silent(20000)
+            .retry(20000)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1]);
+    }
+
+    #[async_std::test]
+    async fn retry_if() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        FlakyFlow::new(2)
+            .retry_if(|attempt| *attempt == 0)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.items().await, vec![0, 0]);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(1))
+        );
+    }
+
+    #[chronobreak::test]
+    async fn retry_with_backoff() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let start = Instant::now();
+        FlakyFlow::silent(2)
+            .retry_with_backoff(
+                Duration::from_millis(10),
+                Duration::from_millis(15),
+                2.0,
+                0.0,
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() >= start + Duration::from_millis(25));
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1]);
+    }
+
+    #[chronobreak::test]
+    async fn retry_with_backoff_reset() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let start = Instant::now();
+        FlakyFlow::new(3)
+            .retry_with_backoff(
+                Duration::from_millis(10),
+                Duration::from_secs(10),
+                1000.0,
+                0.0,
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() < start + Duration::from_secs(10));
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 0, 0, 0, 1]);
+    }
+
+    #[chronobreak::test]
+    async fn cancel_backoff() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_subscriber = TestSubscriber::new(usize::MAX);
+        let flaky_flow = FlakyFlow::silent(1);
+        let start = Instant::now();
+        flaky_flow
+            .clone()
+            .retry_with_backoff(
+                Duration::from_secs(10),
+                Duration::from_secs(10),
+                1.0,
+                0.0,
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_subscriber.cancel().await;
+        scheduler.join();
+
+        assert!(Instant::now() < start + Duration::from_secs(10));
+        assert_eq!(flaky_flow.attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn retry_when() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        FlakyFlow::new(2)
+            .retry_when(|errors| errors.map(|attempt| attempt))
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 0, 0, 1]);
+    }
+
+    #[async_std::test]
+    async fn retry_when_notifier_completes() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let flaky_flow = FlakyFlow::new(2);
+        flaky_flow
+            .clone()
+            .retry_when(|errors| errors.element_at(0))
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(flaky_flow.attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn retry_when_notifier() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let notifier = TestFlow::default().annotate_item_type(());
+        let retry_notifier = notifier.clone();
+        FlakyFlow::new(1)
+            .retry_when(move |_| retry_notifier)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        assert_eq!(notifier.requested().await, 1);
+        notifier.emit(()).await;
+
+        assert!(notifier.is_cancelled().await);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 0, 1]);
+    }
+
+    #[async_std::test]
+    async fn cancel_notifier() {
+        let mut test_subscriber = TestSubscriber::new(usize::MAX);
+        let notifier = TestFlow::default().annotate_item_type(());
+        let retry_notifier = notifier.clone();
+        FlakyFlow::new(1)
+            .retry_when(move |_| retry_notifier)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_subscriber.cancel().await;
+
+        assert!(notifier.is_cancelled().await);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow", item = "Output")]
+pub struct RunningAggregate<Aggregator, Output>
+where
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+{
+    aggregator: Aggregator,
+}
+
+#[derive(new)]
+struct RunningAggregateSubscriber<Subscriber, Aggregator> {
+    subscriber: Subscriber,
+    aggregator: Aggregator,
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Aggregator, Output>
+    core::Subscriber<Subscription, Item, Error>
+    for RunningAggregateSubscriber<Subscriber, Aggregator>
+where
+    Subscriber: core::Subscriber<Subscription, Output, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+    Output: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.aggregator.update(item);
+        if let Some(output) = self.aggregator.output() {
+            self.subscriber.on_next(output).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn running_sum() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .running_sum()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 2);
+        test_flow.emit_all(vec![1, 2]).await;
+        test_subscriber.request_direct(1).await;
+        test_flow.emit(3).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 3, 6]);
+    }
+
+    #[async_std::test]
+    async fn running_max() {
+        let test_subscriber = TestSubscriber::default();
+        vec![1, 3, 2, 4]
+            .into_flow()
+            .running_max()
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.items().await, vec![1, 3, 3, 4]);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(type = "flow", subscription = "SampleSubscription<Subscription>")]
+pub struct Sample<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    period: Duration,
+    scheduler: Scheduler,
+}
+
+pub struct SampleSubscriber<Subscriber, Subscription, Item, Scheduler> {
+    data: Arc<Mutex<Data<Subscriber, Subscription, Item>>>,
+    start: Instant,
+    period: Duration,
+    scheduler: Scheduler,
+}
+
+struct Data<Subscriber, Subscription, Item> {
+    subscriber: Subscriber,
+    shared: Option<Arc<Shared<Subscription>>>,
+    latest: Option<Item>,
+    is_scheduled: bool,
+    is_done: bool,
+}
+
+struct Shared<Upstream> {
+    upstream: Upstream,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Subscriber, Subscription, Item, Scheduler>
+    SampleSubscriber<Subscriber, Subscription, Item, Scheduler>
+{
+    pub fn new(subscriber: Subscriber, period: Duration, scheduler: Scheduler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                subscriber,
+                shared: None,
+                latest: None,
+                is_scheduled: false,
+                is_done: false,
+            })),
+            start: Instant::now(),
+            period,
+            scheduler,
+        }
+    }
+
+    /// Returns the time until the end of the current sampling period.
+    fn until_next_sample(&self) -> Duration {
+        let period = self.period.as_nanos().max(1);
+        let elapsed = Instant::now().duration_since(self.start).as_nanos();
+        Duration::from_nanos((period - elapsed % period) as u64)
+    }
+}
+
+impl<Subscriber, Subscription, Item> Data<Subscriber, Subscription, Item> {
+    async fn emit_latest<Error>(&mut self)
+    where
+        Subscriber: core::Subscriber<SampleSubscription<Subscription>, Item, Error> + Send,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        Item: Send + 'static,
+        Error: Send + 'static,
+    {
+        let (item, shared) = match (self.latest.take(), self.shared.as_ref()) {
+            (Some(item), Some(shared)) => (item, shared),
+            _ => return,
+        };
+        let requested = shared.requested.load(Ordering::Relaxed);
+        if requested == 0 {
+            self.is_done = true;
+            self.subscriber
+                .on_error(flow::Error::MissingBackpressure)
+                .await;
+            shared.upstream.cancel().await;
+            return;
+        }
+        if requested != usize::MAX {
+            shared.requested.fetch_sub(1, Ordering::Relaxed);
+        }
+        self.subscriber.on_next(item).await;
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for SampleSubscriber<Subscriber, Subscription, Item, Scheduler>
+where
+    Subscriber: core::Subscriber<SampleSubscription<Subscription>, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            requested: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+        });
+        let mut data = self.data.lock().await;
+        data.shared = Some(shared.clone());
+        data.subscriber
+            .on_subscribe(SampleSubscription { shared })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        data.latest = Some(item);
+        if data.is_scheduled {
+            return;
+        }
+        data.is_scheduled = true;
+        drop(data);
+
+        let data = self.data.clone();
+        let delay = self.until_next_sample();
+        self.scheduler.schedule(async move {
+            Delay::new(delay).await;
+            let mut data = data.lock().await;
+            data.is_scheduled = false;
+            let cancelled = match data.shared.as_ref() {
+                Some(shared) => shared.cancelled.load(Ordering::Relaxed),
+                None => true,
+            };
+            if !data.is_done && !cancelled {
+                data.emit_latest::<Error>().await;
+            }
+        });
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_completed().await;
+        }
+    }
+}
+
+pub struct SampleSubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for SampleSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+        self.shared.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn sample() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .sample(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, usize::MAX);
+        test_flow.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        test_flow.emit_all(vec![2, 3, 4]).await;
+        scheduler.join();
+        test_flow.emit(5).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 4]);
+    }
+
+    #[chronobreak::test]
+    async fn missing_backpressure() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .throttle_last(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::MissingBackpressure)
+        );
+    }
+}
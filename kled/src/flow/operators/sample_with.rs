@@ -0,0 +1,315 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "SampleWithSubscription<Subscription, SamplerSubscription>"
+)]
+pub struct SampleWith<Sampler, SamplerSubscription, SamplerItem>
+where
+    Sampler: core::Flow<SamplerSubscription, SamplerItem, Error>,
+    SamplerSubscription: core::Subscription + Send + Sync + 'static,
+    SamplerItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    sampler: Sampler,
+}
+
+struct SampleWithSubscriber<
+    Subscriber,
+    Subscription,
+    Item,
+    Sampler,
+    SamplerSubscription,
+    SamplerItem,
+> {
+    data: Arc<Mutex<Data<Subscriber, Item>>>,
+    shared: Option<Arc<Shared<Subscription, SamplerSubscription>>>,
+    sampler: Option<Sampler>,
+    stub: LazySubscriptionStub<SamplerSubscription>,
+    phantom: PhantomData<SamplerItem>,
+}
+
+struct Data<Subscriber, Item> {
+    subscriber: Subscriber,
+    latest: Option<Item>,
+    is_done: bool,
+}
+
+struct Shared<Upstream, Sampler> {
+    upstream: Upstream,
+    sampler: LazySubscription<Sampler>,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Subscriber, Subscription, Item, Sampler, SamplerSubscription, SamplerItem>
+    SampleWithSubscriber<Subscriber, Subscription, Item, Sampler, SamplerSubscription, SamplerItem>
+{
+    fn new(subscriber: Subscriber, sampler: Sampler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                subscriber,
+                latest: None,
+                is_done: false,
+            })),
+            shared: None,
+            sampler: Some(sampler),
+            stub: LazySubscriptionStub::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Subscriber, Item> Data<Subscriber, Item> {
+    async fn emit_latest<Subscription, SamplerSubscription, Error>(
+        &mut self,
+        shared: &Shared<Subscription, SamplerSubscription>,
+    ) where
+        Subscriber: core::Subscriber<SampleWithSubscription<Subscription, SamplerSubscription>, Item, Error>
+            + Send,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        SamplerSubscription: core::Subscription + Send + Sync + 'static,
+        Item: Send + 'static,
+        Error: Send + 'static,
+    {
+        let item = match self.latest.take() {
+            Some(item) if !self.is_done => item,
+            _ => return,
+        };
+        let requested = shared.requested.load(Ordering::Relaxed);
+        if requested == 0 {
+            self.is_done = true;
+            self.subscriber
+                .on_error(flow::Error::MissingBackpressure)
+                .await;
+            shared.upstream.cancel().await;
+            core::Subscription::cancel(&shared.sampler).await;
+            return;
+        }
+        if requested != usize::MAX {
+            shared.requested.fetch_sub(1, Ordering::Relaxed);
+        }
+        self.subscriber.on_next(item).await;
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Sampler, SamplerSubscription, SamplerItem>
+    core::Subscriber<Subscription, Item, Error>
+    for SampleWithSubscriber<
+        Subscriber,
+        Subscription,
+        Item,
+        Sampler,
+        SamplerSubscription,
+        SamplerItem,
+    >
+where
+    Subscriber: core::Subscriber<SampleWithSubscription<Subscription, SamplerSubscription>, Item, Error>
+        + Send
+        + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Sampler: core::Flow<SamplerSubscription, SamplerItem, Error> + Send,
+    SamplerSubscription: core::Subscription + Send + Sync + 'static,
+    SamplerItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            sampler: self.stub.subscription(),
+            requested: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+        });
+        self.shared = Some(shared.clone());
+        self.data
+            .lock()
+            .await
+            .subscriber
+            .on_subscribe(SampleWithSubscription {
+                shared: shared.clone(),
+            })
+            .await;
+        if let Some(sampler) = self.sampler.take() {
+            sampler
+                .subscribe(SamplerSubscriber {
+                    data: self.data.clone(),
+                    shared,
+                    stub: std::mem::take(&mut self.stub),
+                })
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.latest = Some(item);
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+            if let Some(shared) = self.shared.as_ref() {
+                core::Subscription::cancel(&shared.sampler).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_completed().await;
+            if let Some(shared) = self.shared.as_ref() {
+                core::Subscription::cancel(&shared.sampler).await;
+            }
+        }
+    }
+}
+
+struct SamplerSubscriber<Subscriber, Subscription, Item, SamplerSubscription> {
+    data: Arc<Mutex<Data<Subscriber, Item>>>,
+    shared: Arc<Shared<Subscription, SamplerSubscription>>,
+    stub: LazySubscriptionStub<SamplerSubscription>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, Item, Error, SamplerSubscription, SamplerItem>
+    core::Subscriber<SamplerSubscription, SamplerItem, Error>
+    for SamplerSubscriber<Subscriber, Subscription, Item, SamplerSubscription>
+where
+    Subscriber: core::Subscriber<SampleWithSubscription<Subscription, SamplerSubscription>, Item, Error>
+        + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SamplerSubscription: core::Subscription + Send + Sync + 'static,
+    SamplerItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: SamplerSubscription) {
+        subscription.request(usize::MAX).await;
+        self.stub.set_subscription(subscription).await;
+    }
+
+    async fn on_next(&mut self, _: SamplerItem) {
+        self.data
+            .lock()
+            .await
+            .emit_latest::<_, _, Error>(&self.shared)
+            .await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+            self.shared.upstream.cancel().await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_completed().await;
+            self.shared.upstream.cancel().await;
+        }
+    }
+}
+
+pub struct SampleWithSubscription<Upstream, Sampler> {
+    shared: Arc<Shared<Upstream, Sampler>>,
+}
+
+#[async_trait]
+impl<Upstream, Sampler> core::Subscription for SampleWithSubscription<Upstream, Sampler>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Sampler: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+        self.shared.upstream.cancel().await;
+        self.shared.sampler.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn sample_with() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let sampler = TestFlow::default();
+        test_flow
+            .clone()
+            .sample_with(sampler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, usize::MAX);
+        assert_eq!(sampler.requested().await, usize::MAX);
+        sampler.emit(()).await;
+        test_flow.emit_all(vec![0, 1]).await;
+        sampler.emit(()).await;
+        sampler.emit(()).await;
+        test_flow.emit(2).await;
+        sampler.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1]);
+        assert!(test_flow.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn missing_backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let sampler = TestFlow::default();
+        test_flow
+            .clone()
+            .sample_with(sampler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        sampler.emit(()).await;
+        test_flow.emit(1).await;
+        sampler.emit(()).await;
+
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::MissingBackpressure)
+        );
+        assert!(test_flow.is_cancelled().await);
+        assert!(sampler.is_cancelled().await);
+    }
+}
@@ -0,0 +1,449 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::Arc;
+
+/// The default number of items requested from both sources ahead of their
+/// comparison.
+const PREFETCH: usize = 128;
+
+type BoxedSource<A, B, Error> =
+    Box<dyn FnOnce(Arc<Shared<A, B, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedSubscriber<A, B, Error> =
+    Box<dyn core::Subscriber<SequenceEqualSubscription<A, B, Error>, bool, Error> + Send + 'static>;
+
+type BoxedSubscription = Arc<dyn core::Subscription + Send + Sync>;
+
+type EqFn<A, B> = Box<dyn FnMut(&A, &B) -> bool + Send>;
+
+/// Compares the items of two sources pairwise and emits whether both sources
+/// emitted the same number of items and `eq_fn` returned true for each pair.
+/// Both sources are cancelled as soon as the result is known.
+pub struct SequenceEqual<A, B, Error> {
+    a: BoxedSource<A, B, Error>,
+    b: BoxedSource<A, B, Error>,
+    eq_fn: EqFn<A, B>,
+    prefetch: usize,
+}
+
+impl<A, B, Error> SequenceEqual<A, B, Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn new<FlowA, SubscriptionA, FlowB, SubscriptionB, F>(a: FlowA, b: FlowB, eq_fn: F) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        F: FnMut(&A, &B) -> bool + Send + 'static,
+    {
+        Self {
+            a: Box::new(move |shared| {
+                Box::pin(a.subscribe(SequenceEqualSubscriber {
+                    shared,
+                    index: 0,
+                    push: |state: &mut State<A, B, Error>, item| state.a.push_back(item),
+                }))
+            }),
+            b: Box::new(move |shared| {
+                Box::pin(b.subscribe(SequenceEqualSubscriber {
+                    shared,
+                    index: 1,
+                    push: |state: &mut State<A, B, Error>, item| state.b.push_back(item),
+                }))
+            }),
+            eq_fn: Box::new(eq_fn),
+            prefetch: PREFETCH,
+        }
+    }
+
+    /// Requests `prefetch` items from both sources ahead of their comparison
+    /// instead of 128. Both sources are requested again once three quarters
+    /// of the prefetch window were compared.
+    pub fn with_prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+}
+
+/// Returns a [`Flow`] that emits whether `a` and `b` emit sequences of the same
+/// length for which `eq_fn` returns true on each pair of items.
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+pub fn sequence_equal<FlowA, SubscriptionA, A, FlowB, SubscriptionB, B, Error, EqFn>(
+    a: FlowA,
+    b: FlowB,
+    eq_fn: EqFn,
+) -> SequenceEqual<A, B, Error>
+where
+    FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+    SubscriptionA: core::Subscription + Send + Sync + 'static,
+    A: Send + 'static,
+    FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+    SubscriptionB: core::Subscription + Send + Sync + 'static,
+    B: Send + 'static,
+    Error: Send + 'static,
+    EqFn: FnMut(&A, &B) -> bool + Send + 'static,
+{
+    SequenceEqual::new(a, b, eq_fn)
+}
+
+#[async_trait]
+impl<A, B, Error> core::Flow<SequenceEqualSubscription<A, B, Error>, bool, Error>
+    for SequenceEqual<A, B, Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber:
+            core::Subscriber<SequenceEqualSubscription<A, B, Error>, bool, Error> + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            subscriber: Mutex::new(None),
+            subscriptions: Mutex::new([None, None]),
+            state: Mutex::new(State {
+                a: VecDeque::new(),
+                b: VecDeque::new(),
+                eq_fn: self.eq_fn,
+                done: [false, false],
+                consumed: 0,
+                prefetch: self.prefetch,
+                result: None,
+                error: None,
+                is_requested: false,
+                is_cancelled: false,
+                is_finished: false,
+                is_terminated: false,
+            }),
+        });
+        subscriber
+            .on_subscribe(SequenceEqualSubscription {
+                shared: shared.clone(),
+            })
+            .await;
+        *shared.subscriber.lock().await = Some(Box::new(subscriber));
+        drain(&shared).await;
+        (self.a)(shared.clone()).await;
+        (self.b)(shared).await;
+    }
+}
+
+struct Shared<A, B, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<A, B, Error>>>,
+    subscriptions: Mutex<[Option<BoxedSubscription>; 2]>,
+    state: Mutex<State<A, B, Error>>,
+}
+
+struct State<A, B, Error> {
+    a: VecDeque<A>,
+    b: VecDeque<B>,
+    eq_fn: EqFn<A, B>,
+    done: [bool; 2],
+    consumed: usize,
+    prefetch: usize,
+    result: Option<bool>,
+    error: Option<flow::Error<Error>>,
+    is_requested: bool,
+    is_cancelled: bool,
+    /// Whether the sources are cancelled or about to be.
+    is_finished: bool,
+    is_terminated: bool,
+}
+
+impl<A, B, Error> State<A, B, Error> {
+    /// The number of compared pairs after which both sources are requested
+    /// again.
+    fn limit(&self) -> usize {
+        self.prefetch - self.prefetch / 4
+    }
+
+    /// Compares all available pairs and returns whether the sources should be
+    /// requested again.
+    fn compare(&mut self) -> bool {
+        while self.result.is_none() && !self.a.is_empty() && !self.b.is_empty() {
+            if let (Some(a), Some(b)) = (self.a.pop_front(), self.b.pop_front()) {
+                if !(self.eq_fn)(&a, &b) {
+                    self.result = Some(false);
+                }
+                self.consumed += 1;
+            }
+        }
+        if self.result.is_none() {
+            let a_done = self.done[0] && self.a.is_empty();
+            let b_done = self.done[1] && self.b.is_empty();
+            if a_done && b_done {
+                self.result = Some(true);
+            } else if a_done || b_done {
+                // The finished source is shorter if the other one has items left.
+                if !self.a.is_empty() || !self.b.is_empty() {
+                    self.result = Some(false);
+                }
+            }
+        }
+        let limit = self.limit();
+        if self.consumed >= limit {
+            self.consumed -= limit;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<A, B, Error> Shared<A, B, Error> {
+    async fn subscriptions(&self) -> Vec<BoxedSubscription> {
+        self.subscriptions
+            .lock()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect()
+    }
+}
+
+async fn drain<A, B, Error>(shared: &Shared<A, B, Error>)
+where
+    Error: Send + 'static,
+{
+    let (replenish, cancel, signal, limit) = {
+        let mut state = shared.state.lock().await;
+        let replenish = !state.is_finished && state.compare();
+        let cancel = !state.is_finished
+            && (state.is_cancelled || state.error.is_some() || state.result.is_some());
+        if cancel {
+            state.is_finished = true;
+        }
+        let signal = if state.is_terminated || state.is_cancelled {
+            None
+        } else if let Some(error) = state.error.take() {
+            Some(Err(error))
+        } else if state.is_requested {
+            state.result.map(Ok)
+        } else {
+            None
+        };
+        if signal.is_some() {
+            state.is_terminated = true;
+        }
+        (replenish, cancel, signal, state.limit())
+    };
+    if replenish && !cancel {
+        for subscription in shared.subscriptions().await {
+            subscription.request(limit).await;
+        }
+    }
+    if cancel {
+        for subscription in shared.subscriptions().await {
+            subscription.cancel().await;
+        }
+    }
+    if let Some(signal) = signal {
+        if let Some(mut subscriber) = shared.subscriber.lock().await.take() {
+            match signal {
+                Ok(result) => {
+                    subscriber.on_next(result).await;
+                    subscriber.on_completed().await;
+                }
+                Err(error) => subscriber.on_error(error).await,
+            }
+        }
+    }
+}
+
+struct SequenceEqualSubscriber<A, B, Error, Item> {
+    shared: Arc<Shared<A, B, Error>>,
+    index: usize,
+    push: fn(&mut State<A, B, Error>, Item),
+}
+
+#[async_trait]
+impl<Subscription, A, B, Error, Item> core::Subscriber<Subscription, Item, Error>
+    for SequenceEqualSubscriber<A, B, Error, Item>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    A: Send + 'static,
+    B: Send + 'static,
+    Error: Send + 'static,
+    Item: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription: BoxedSubscription = Arc::new(subscription);
+        self.shared.subscriptions.lock().await[self.index] = Some(subscription.clone());
+        let (is_finished, prefetch) = {
+            let state = self.shared.state.lock().await;
+            (state.is_finished, state.prefetch)
+        };
+        if is_finished {
+            subscription.cancel().await;
+        } else {
+            subscription.request(prefetch).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.is_finished {
+                return;
+            }
+            (self.push)(&mut state, item);
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.is_finished {
+                return;
+            }
+            state.error = Some(error);
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.done[self.index] = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct SequenceEqualSubscription<A, B, Error> {
+    shared: Arc<Shared<A, B, Error>>,
+}
+
+#[async_trait]
+impl<A, B, Error> core::Subscription for SequenceEqualSubscription<A, B, Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.state.lock().await.is_cancelled = true;
+        drain(&self.shared).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.state.lock().await.is_cancelled
+    }
+
+    async fn request(&self, count: usize) {
+        if count > 0 {
+            self.shared.state.lock().await.is_requested = true;
+            drain(&self.shared).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn sequence_equal() {
+        let test_subscriber = TestSubscriber::new(1);
+        vec![0, 1, 2]
+            .into_flow()
+            .sequence_equal(vec!["0", "1", "2"].into_flow(), |a, b| a.to_string() == *b)
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![true]);
+    }
+
+    #[async_std::test]
+    async fn mismatch() {
+        let test_subscriber = TestSubscriber::new(0);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        operators::sequence_equal(a.clone(), b.clone(), |a, b| a == b)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(a.requested().await, 128);
+        assert_eq!(b.requested().await, 128);
+        a.emit_all(vec![0, 1]).await;
+        b.emit_all(vec![0, 2]).await;
+
+        assert!(a.is_cancelled().await);
+        assert!(b.is_cancelled().await);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(test_subscriber.items().await, vec![false]);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+    }
+
+    #[async_std::test]
+    async fn prefetch() {
+        let test_subscriber = TestSubscriber::new(1);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .sequence_equal(b.clone(), |a, b| a == b)
+            .with_prefetch(4)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(a.requested().await, 4);
+        assert_eq!(b.requested().await, 4);
+        a.emit_all(vec![0, 1, 2]).await;
+        b.emit_all(vec![0, 1]).await;
+        assert_eq!(a.requested().await, 4);
+        b.emit(2).await;
+        assert_eq!(a.requested().await, 7);
+        assert_eq!(b.requested().await, 7);
+        a.emit_completed().await;
+        b.emit_completed().await;
+
+        assert_eq!(test_subscriber.items().await, vec![true]);
+    }
+
+    #[async_std::test]
+    async fn different_length() {
+        let test_subscriber = TestSubscriber::new(1);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .sequence_equal(b.clone(), |a, b| a == b)
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_all(vec![0, 1]).await;
+        a.emit_completed().await;
+        b.emit_all(vec![0, 1, 2]).await;
+
+        assert!(b.is_cancelled().await);
+        assert_eq!(test_subscriber.items().await, vec![false]);
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_subscriber = TestSubscriber::new(1);
+        let a = TestFlow::default().annotate_item_type(0);
+        let b = TestFlow::default().annotate_item_type(0);
+        a.clone()
+            .sequence_equal(b.clone(), |a, b| a == b)
+            .subscribe(test_subscriber.clone())
+            .await;
+        b.emit_error(()).await;
+
+        assert!(a.is_cancelled().await);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::ElementSubscription;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "flow", subscription = "ElementSubscription<Subscription>")]
+pub struct Single {}
+
+struct SingleSubscriber<Subscriber, Subscription, Item> {
+    subscriber: Subscriber,
+    upstream: Option<Arc<Subscription>>,
+    item: Option<Item>,
+    is_done: bool,
+}
+
+impl<Subscriber, Subscription, Item> SingleSubscriber<Subscriber, Subscription, Item> {
+    fn new(subscriber: Subscriber) -> Self {
+        Self {
+            subscriber,
+            upstream: None,
+            item: None,
+            is_done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for SingleSubscriber<Subscriber, Subscription, Item>
+where
+    Subscriber: core::Subscriber<ElementSubscription<Subscription>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let upstream = Arc::new(subscription);
+        self.upstream = Some(upstream.clone());
+        // A second item is requested to be able to detect a violation.
+        self.subscriber
+            .on_subscribe(ElementSubscription::new(upstream, 2))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.is_done {
+            return;
+        }
+        if self.item.is_none() {
+            self.item = Some(item);
+            return;
+        }
+        self.is_done = true;
+        self.item = None;
+        self.subscriber.on_error(flow::Error::TooManyElements).await;
+        if let Some(upstream) = self.upstream.as_ref() {
+            upstream.cancel().await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if !self.is_done {
+            self.is_done = true;
+            self.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if !self.is_done {
+            self.is_done = true;
+            match self.item.take() {
+                Some(item) => {
+                    self.subscriber.on_next(item).await;
+                    self.subscriber.on_completed().await;
+                }
+                None => self.subscriber.on_error(flow::Error::NoSuchElement).await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn single() {
+        let test_subscriber = TestSubscriber::new(1);
+        vec![0]
+            .into_flow()
+            .single()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn empty() {
+        let test_subscriber = TestSubscriber::new(1);
+        Vec::<i32>::new()
+            .into_flow()
+            .single()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::NoSuchElement)
+        );
+    }
+
+    #[async_std::test]
+    async fn too_many_elements() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .single()
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 2);
+        test_flow.emit_all(vec![0, 1]).await;
+
+        assert_eq!(test_subscriber.items().await, Vec::<i32>::new());
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::TooManyElements)
+        );
+        assert!(test_flow.is_cancelled().await);
+    }
+}
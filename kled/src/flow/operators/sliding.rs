@@ -0,0 +1,171 @@
+use crate::core;
+use crate::flow;
+use crate::sliding_window::{SlidingBuffer, SlidingWindow};
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[operator(
+    type = "flow",
+    subscription = "SlidingSubscription<Subscription>",
+    item = "SlidingWindow<Item>"
+)]
+pub struct Sliding
+where
+    Item: Sync,
+{
+    count: usize,
+}
+
+struct SlidingSubscriber<Subscriber, Item> {
+    subscriber: Subscriber,
+    count: usize,
+    buffer: SlidingBuffer<Item>,
+}
+
+impl<Subscriber, Item> SlidingSubscriber<Subscriber, Item> {
+    fn new(subscriber: Subscriber, count: usize) -> Self {
+        Self {
+            subscriber,
+            count,
+            buffer: SlidingBuffer::new(count),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for SlidingSubscriber<Subscriber, Item>
+where
+    Subscriber:
+        core::Subscriber<SlidingSubscription<Subscription>, SlidingWindow<Item>, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + Sync + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(SlidingSubscription::new(subscription, self.count - 1))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(window) = self.buffer.push(item) {
+            self.subscriber.on_next(window).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+/// The subscription of an operator that has to receive `offset` additional
+/// items from upstream before it emits its first item. The first request of
+/// the downstream subscriber is increased by `offset`, all further requests
+/// are forwarded unchanged.
+pub struct SlidingSubscription<Upstream> {
+    upstream: Upstream,
+    offset: usize,
+    requested: AtomicBool,
+}
+
+impl<Upstream> SlidingSubscription<Upstream> {
+    pub fn new(upstream: Upstream, offset: usize) -> Self {
+        Self {
+            upstream,
+            offset,
+            requested: AtomicBool::new(false),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for SlidingSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        if count > 0 && !self.requested.swap(true, Ordering::Relaxed) {
+            self.upstream
+                .request(count.saturating_add(self.offset))
+                .await
+        } else {
+            self.upstream.request(count).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn sliding() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .sliding(3)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        let items: Vec<Vec<i32>> = test_subscriber
+            .items()
+            .await
+            .iter()
+            .map(|window| window.to_vec())
+            .collect();
+        assert_eq!(items, vec![vec![0, 1, 2], vec![1, 2, 3]]);
+    }
+
+    #[async_std::test]
+    async fn sliding_many() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        (0..7)
+            .into_flow()
+            .sliding(3)
+            .subscribe(test_subscriber.clone())
+            .await;
+        let items: Vec<Vec<i32>> = test_subscriber
+            .items()
+            .await
+            .iter()
+            .map(|window| window.to_vec())
+            .collect();
+        let expected: Vec<Vec<i32>> = (0..5).map(|i| vec![i, i + 1, i + 2]).collect();
+        assert_eq!(items, expected);
+    }
+
+    #[async_std::test]
+    async fn sliding_backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .sliding(3)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 3);
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        test_subscriber.request_direct(1).await;
+        assert_eq!(test_flow.requested().await, 4);
+        test_flow.emit(3).await;
+
+        assert_eq!(test_subscriber.items().await.len(), 2);
+        assert_eq!(test_subscriber.items().await[1].to_vec(), vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[operator(
+    type = "flow",
+    subscription = "SwitchIfEmptySubscription<Subscription, FallbackSubscription>"
+)]
+pub struct SwitchIfEmpty<Fallback, FallbackSubscription>
+where
+    Fallback: core::Flow<FallbackSubscription, Item, Error>,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fallback: Fallback,
+}
+
+struct SwitchIfEmptySubscriber<Subscriber, Fallback, FallbackSubscription> {
+    subscriber: Option<Subscriber>,
+    fallback: Option<Fallback>,
+    stub: LazySubscriptionStub<FallbackSubscription>,
+    has_items: bool,
+}
+
+impl<Subscriber, Fallback, FallbackSubscription>
+    SwitchIfEmptySubscriber<Subscriber, Fallback, FallbackSubscription>
+{
+    fn new(subscriber: Subscriber, fallback: Fallback) -> Self {
+        Self {
+            subscriber: Some(subscriber),
+            fallback: Some(fallback),
+            stub: LazySubscriptionStub::default(),
+            has_items: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Fallback, FallbackSubscription>
+    core::Subscriber<Subscription, Item, Error>
+    for SwitchIfEmptySubscriber<Subscriber, Fallback, FallbackSubscription>
+where
+    Subscriber: core::Subscriber<SwitchIfEmptySubscription<Subscription, FallbackSubscription>, Item, Error>
+        + Send
+        + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Fallback: core::Flow<FallbackSubscription, Item, Error> + Send,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber
+                .on_subscribe(SwitchIfEmptySubscription::new(
+                    subscription,
+                    self.stub.subscription(),
+                ))
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.has_items = true;
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if self.has_items {
+            if let Some(subscriber) = self.subscriber.as_mut() {
+                subscriber.on_completed().await;
+            }
+            return;
+        }
+        if let (Some(subscriber), Some(fallback)) = (self.subscriber.take(), self.fallback.take()) {
+            // All items requested so far have been recorded by the lazy
+            // subscription and are requested from the fallback as soon as it
+            // is subscribed to.
+            fallback
+                .subscribe(FallbackSubscriber {
+                    subscriber,
+                    stub: std::mem::take(&mut self.stub),
+                    phantom: PhantomData,
+                })
+                .await;
+        }
+    }
+}
+
+struct FallbackSubscriber<Subscriber, Subscription, FallbackSubscription> {
+    subscriber: Subscriber,
+    stub: LazySubscriptionStub<FallbackSubscription>,
+    phantom: PhantomData<Subscription>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, Item, Error, FallbackSubscription>
+    core::Subscriber<FallbackSubscription, Item, Error>
+    for FallbackSubscriber<Subscriber, Subscription, FallbackSubscription>
+where
+    Subscriber: core::Subscriber<SwitchIfEmptySubscription<Subscription, FallbackSubscription>, Item, Error>
+        + Send,
+    Subscription: Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: FallbackSubscription) {
+        self.stub.set_subscription(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct SwitchIfEmptySubscription<Upstream, Fallback> {
+    upstream: Upstream,
+    fallback: LazySubscription<Fallback>,
+}
+
+#[async_trait]
+impl<Upstream, Fallback> core::Subscription for SwitchIfEmptySubscription<Upstream, Fallback>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Fallback: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.fallback.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await;
+        self.fallback.request(count).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn switch_if_empty() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let fallback = TestFlow::default();
+        test_flow
+            .clone()
+            .switch_if_empty(fallback.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_completed().await;
+        assert_eq!(fallback.requested().await, 2);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(fallback.requested().await, 3);
+        fallback.emit_all(vec![0, 1, 2]).await;
+        fallback.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn not_empty() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let fallback = TestFlow::default();
+        test_flow
+            .clone()
+            .switch_if_empty(fallback.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert!(!fallback.has_observer().await);
+    }
+}
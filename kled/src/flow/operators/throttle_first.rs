@@ -0,0 +1,210 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(
+    type = "flow",
+    subscription = "ThrottleFirstSubscription<Subscription>"
+)]
+pub struct ThrottleFirst<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+pub struct ThrottleFirstSubscriber<Subscriber, Subscription, Scheduler> {
+    data: Arc<Mutex<Data<Subscriber, Subscription>>>,
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+struct Data<Subscriber, Subscription> {
+    subscriber: Subscriber,
+    shared: Option<Arc<Shared<Subscription>>>,
+    is_gated: bool,
+    is_done: bool,
+}
+
+struct Shared<Upstream> {
+    upstream: Upstream,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Subscriber, Subscription, Scheduler>
+    ThrottleFirstSubscriber<Subscriber, Subscription, Scheduler>
+{
+    pub fn new(subscriber: Subscriber, duration: Duration, scheduler: Scheduler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                subscriber,
+                shared: None,
+                is_gated: false,
+                is_done: false,
+            })),
+            duration,
+            scheduler,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for ThrottleFirstSubscriber<Subscriber, Subscription, Scheduler>
+where
+    Subscriber:
+        core::Subscriber<ThrottleFirstSubscription<Subscription>, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            requested: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+        });
+        let mut data = self.data.lock().await;
+        data.shared = Some(shared.clone());
+        data.subscriber
+            .on_subscribe(ThrottleFirstSubscription { shared })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done || data.is_gated {
+            return;
+        }
+        let shared = match data.shared.clone() {
+            Some(shared) => shared,
+            None => return,
+        };
+        let requested = shared.requested.load(Ordering::Relaxed);
+        if requested == 0 {
+            data.is_done = true;
+            data.subscriber
+                .on_error(flow::Error::MissingBackpressure)
+                .await;
+            shared.upstream.cancel().await;
+            return;
+        }
+        if requested != usize::MAX {
+            shared.requested.fetch_sub(1, Ordering::Relaxed);
+        }
+        data.is_gated = true;
+        data.subscriber.on_next(item).await;
+        drop(data);
+
+        let data = self.data.clone();
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            Delay::new(duration).await;
+            data.lock().await.is_gated = false;
+        });
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_completed().await;
+        }
+    }
+}
+
+pub struct ThrottleFirstSubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for ThrottleFirstSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+        self.shared.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn throttle_first() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .throttle_first(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, usize::MAX);
+        test_flow.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        test_flow.emit_all(vec![2, 3]).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn missing_backpressure() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .throttle_first(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::MissingBackpressure)
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(
+    type = "flow",
+    subscription = "ThrottleLatestSubscription<Subscription>"
+)]
+pub struct ThrottleLatest<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+    emit_last: bool,
+}
+
+pub struct ThrottleLatestSubscriber<Subscriber, Subscription, Item, Scheduler> {
+    data: Arc<Mutex<Data<Subscriber, Subscription, Item>>>,
+    duration: Duration,
+    scheduler: Scheduler,
+    emit_last: bool,
+}
+
+struct Data<Subscriber, Subscription, Item> {
+    subscriber: Subscriber,
+    shared: Option<Arc<Shared<Subscription>>>,
+    latest: Option<Item>,
+    is_gated: bool,
+    is_done: bool,
+}
+
+struct Shared<Upstream> {
+    upstream: Upstream,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Subscriber, Subscription, Item, Scheduler>
+    ThrottleLatestSubscriber<Subscriber, Subscription, Item, Scheduler>
+{
+    pub fn new(
+        subscriber: Subscriber,
+        duration: Duration,
+        scheduler: Scheduler,
+        emit_last: bool,
+    ) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                subscriber,
+                shared: None,
+                latest: None,
+                is_gated: false,
+                is_done: false,
+            })),
+            duration,
+            scheduler,
+            emit_last,
+        }
+    }
+}
+
+impl<Subscriber, Subscription, Item> Data<Subscriber, Subscription, Item> {
+    async fn emit<Error>(&mut self, item: Item)
+    where
+        Subscriber: core::Subscriber<ThrottleLatestSubscription<Subscription>, Item, Error> + Send,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        Item: Send + 'static,
+        Error: Send + 'static,
+    {
+        let shared = match self.shared.as_ref() {
+            Some(shared) => shared,
+            None => return,
+        };
+        let requested = shared.requested.load(Ordering::Relaxed);
+        if requested == 0 {
+            self.is_done = true;
+            self.subscriber
+                .on_error(flow::Error::MissingBackpressure)
+                .await;
+            shared.upstream.cancel().await;
+            return;
+        }
+        if requested != usize::MAX {
+            shared.requested.fetch_sub(1, Ordering::Relaxed);
+        }
+        self.subscriber.on_next(item).await;
+    }
+
+    fn is_cancelled(&self) -> bool {
+        match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::Relaxed),
+            None => true,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for ThrottleLatestSubscriber<Subscriber, Subscription, Item, Scheduler>
+where
+    Subscriber:
+        core::Subscriber<ThrottleLatestSubscription<Subscription>, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            requested: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+        });
+        let mut data = self.data.lock().await;
+        data.shared = Some(shared.clone());
+        data.subscriber
+            .on_subscribe(ThrottleLatestSubscription { shared })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        if data.is_gated {
+            data.latest = Some(item);
+            return;
+        }
+        data.is_gated = true;
+        data.emit::<Error>(item).await;
+        drop(data);
+
+        let data = self.data.clone();
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            loop {
+                Delay::new(duration).await;
+                let mut data = data.lock().await;
+                if data.is_done || data.is_cancelled() {
+                    break;
+                }
+                match data.latest.take() {
+                    Some(item) => data.emit::<Error>(item).await,
+                    None => {
+                        data.is_gated = false;
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        if self.emit_last {
+            if let Some(item) = data.latest.take() {
+                data.emit::<Error>(item).await;
+                if data.is_done {
+                    return;
+                }
+            }
+        }
+        data.is_done = true;
+        data.subscriber.on_completed().await;
+    }
+}
+
+pub struct ThrottleLatestSubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for ThrottleLatestSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+        self.shared.upstream.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn throttle_latest() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .throttle_latest(Duration::from_millis(10), scheduler.clone(), true)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, usize::MAX);
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        scheduler.join();
+        test_flow.emit_all(vec![3, 4]).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 2, 3, 4]);
+    }
+
+    #[chronobreak::test]
+    async fn drop_last() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .throttle_latest(Duration::from_millis(10), scheduler.clone(), false)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(type = "flow", item = "(Duration, Item)")]
+pub struct TimeInterval<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    scheduler: Scheduler,
+}
+
+#[derive(new)]
+struct TimeIntervalSubscriber<Subscriber, Scheduler> {
+    subscriber: Subscriber,
+    scheduler: Scheduler,
+    #[new(default)]
+    last: Option<Instant>,
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for TimeIntervalSubscriber<Subscriber, Scheduler>
+where
+    Subscriber: core::Subscriber<Subscription, (Duration, Item), Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.last = Some(self.scheduler.now());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let now = self.scheduler.now();
+        let last = self.last.replace(now).unwrap_or(now);
+        self.subscriber.on_next((now - last, item)).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+
+    #[chronobreak::test]
+    async fn time_interval() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .time_interval(scheduler)
+            .subscribe(test_subscriber.clone())
+            .await;
+        clock::advance(Duration::from_millis(10));
+        test_flow.emit(0).await;
+        test_flow.emit(1).await;
+        clock::advance(Duration::from_millis(20));
+        test_flow.emit(2).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(
+            test_subscriber.items().await,
+            vec![
+                (Duration::from_millis(10), 0),
+                (Duration::from_millis(0), 1),
+                (Duration::from_millis(20), 2),
+            ]
+        );
+    }
+}
@@ -0,0 +1,455 @@
+use crate::core;
+use crate::flow;
+use crate::scheduler::{unbounded, DelaySender};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use futures::prelude::*;
+
+#[chronobreak]
+mod mock {
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+type BoxedSource<Subscription, Item, Error> =
+    Box<dyn FnOnce(FallbackSubscriber<Subscription, Item, Error>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedSubscriber<Subscription, Item, Error> =
+    Box<dyn core::Subscriber<TimeoutSubscription<Subscription>, Item, Error> + Send + 'static>;
+
+type BoxedSubscription = Arc<dyn core::Subscription + Send + Sync>;
+
+pub type TimeoutFn<Item> = Box<dyn FnMut(&Item) -> Duration + Send>;
+
+/// A source which is switched to once the upstream timed out.
+pub struct TimeoutFallback<Subscription, Item, Error> {
+    source: BoxedSource<Subscription, Item, Error>,
+}
+
+impl<Subscription, Item, Error> TimeoutFallback<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn new<Flow, FallbackSubscription>(flow: Flow) -> Self
+    where
+        Flow: core::Flow<FallbackSubscription, Item, Error> + Send + 'static,
+        FallbackSubscription: core::Subscription + Send + Sync + 'static,
+    {
+        Self {
+            source: Box::new(move |subscriber| Box::pin(flow.subscribe(subscriber))),
+        }
+    }
+}
+
+#[operator(type = "flow", subscription = "TimeoutSubscription<Subscription>")]
+pub struct Timeout<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    first: Duration,
+    timeout_fn: TimeoutFn<Item>,
+    scheduler: Scheduler,
+    fallback: Option<TimeoutFallback<Subscription, Item, Error>>,
+}
+
+pub struct TimeoutSubscriber<Subscription, Item, Error, Scheduler> {
+    data: Arc<Mutex<Data<Subscription, Item, Error>>>,
+    sender: Option<DelaySender<usize>>,
+    first: Duration,
+    timeout_fn: TimeoutFn<Item>,
+    scheduler: Scheduler,
+}
+
+struct Data<Subscription, Item, Error> {
+    subscriber: BoxedSubscriber<Subscription, Item, Error>,
+    shared: Option<Arc<Shared<Subscription>>>,
+    fallback: Option<TimeoutFallback<Subscription, Item, Error>>,
+    index: usize,
+    is_switched: bool,
+    is_done: bool,
+}
+
+struct Shared<Upstream> {
+    upstream: Upstream,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    fallback: Option<BoxedSubscription>,
+    requested: usize,
+    is_cancelled: bool,
+}
+
+impl<Subscription, Item, Error, Scheduler> TimeoutSubscriber<Subscription, Item, Error, Scheduler> {
+    pub fn new<Subscriber>(
+        subscriber: Subscriber,
+        first: Duration,
+        timeout_fn: TimeoutFn<Item>,
+        scheduler: Scheduler,
+        fallback: Option<TimeoutFallback<Subscription, Item, Error>>,
+    ) -> Self
+    where
+        Subscriber:
+            core::Subscriber<TimeoutSubscription<Subscription>, Item, Error> + Send + 'static,
+    {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                subscriber: Box::new(subscriber),
+                shared: None,
+                fallback,
+                index: 0,
+                is_switched: false,
+                is_done: false,
+            })),
+            sender: None,
+            first,
+            timeout_fn,
+            scheduler,
+        }
+    }
+}
+
+impl<Subscription, Item, Error> Data<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn emit(&mut self, item: Item) {
+        if let Some(shared) = self.shared.as_ref() {
+            let mut state = shared.state.lock().await;
+            if state.requested != usize::MAX {
+                state.requested = state.requested.saturating_sub(1);
+            }
+        }
+        self.subscriber.on_next(item).await;
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for TimeoutSubscriber<Subscription, Item, Error, Scheduler>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            state: Mutex::new(State::default()),
+        });
+        {
+            let mut data = self.data.lock().await;
+            data.shared = Some(shared.clone());
+            data.subscriber
+                .on_subscribe(TimeoutSubscription { shared })
+                .await;
+        }
+
+        let (mut sender, mut receiver) = unbounded();
+        sender.send_delayed(self.first, 0).await.unwrap();
+        self.sender = Some(sender);
+        let data = self.data.clone();
+        self.scheduler.schedule(async move {
+            while let Some(index) = receiver.next().await {
+                let mut guard = data.lock().await;
+                let shared = match guard.shared.clone() {
+                    Some(shared) => shared,
+                    None => break,
+                };
+                if guard.is_done || guard.is_switched || shared.state.lock().await.is_cancelled {
+                    break;
+                }
+                if index != guard.index {
+                    continue;
+                }
+                guard.is_switched = true;
+                shared.upstream.cancel().await;
+                match guard.fallback.take() {
+                    Some(fallback) => {
+                        drop(guard);
+                        (fallback.source)(FallbackSubscriber { data }).await;
+                    }
+                    None => {
+                        guard.is_done = true;
+                        guard.subscriber.on_error(flow::Error::Timeout).await;
+                    }
+                }
+                break;
+            }
+        });
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done || data.is_switched {
+            return;
+        }
+        let timeout = (self.timeout_fn)(&item);
+        data.index += 1;
+        // The timer task stops once the subscription was cancelled, in which
+        // case the item is dropped.
+        if let Some(sender) = self.sender.as_mut() {
+            if sender.send_delayed(timeout, data.index).await.is_err() {
+                self.sender = None;
+                return;
+            }
+        }
+        data.emit(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.sender = None;
+        let mut data = self.data.lock().await;
+        if !data.is_done && !data.is_switched {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        self.sender = None;
+        let mut data = self.data.lock().await;
+        if !data.is_done && !data.is_switched {
+            data.is_done = true;
+            data.subscriber.on_completed().await;
+        }
+    }
+}
+
+struct FallbackSubscriber<Subscription, Item, Error> {
+    data: Arc<Mutex<Data<Subscription, Item, Error>>>,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, FallbackSubscription>
+    core::Subscriber<FallbackSubscription, Item, Error>
+    for FallbackSubscriber<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FallbackSubscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: FallbackSubscription) {
+        let shared = match self.data.lock().await.shared.clone() {
+            Some(shared) => shared,
+            None => return,
+        };
+        let subscription: BoxedSubscription = Arc::new(subscription);
+        let (requested, is_cancelled) = {
+            let mut state = shared.state.lock().await;
+            state.fallback = Some(subscription.clone());
+            (state.requested, state.is_cancelled)
+        };
+        if is_cancelled {
+            subscription.cancel().await;
+        } else if requested > 0 {
+            subscription.request(requested).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.emit(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.subscriber.on_completed().await;
+        }
+    }
+}
+
+pub struct TimeoutSubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for TimeoutSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        let fallback = {
+            let mut state = self.shared.state.lock().await;
+            state.is_cancelled = true;
+            state.fallback.take()
+        };
+        self.shared.upstream.cancel().await;
+        if let Some(fallback) = fallback {
+            fallback.cancel().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.state.lock().await.is_cancelled
+    }
+
+    async fn request(&self, count: usize) {
+        let fallback = {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+            state.fallback.clone()
+        };
+        match fallback {
+            Some(fallback) => fallback.request(count).await,
+            None => self.shared.upstream.request(count).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn timeout() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .timeout(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::Timeout));
+    }
+
+    #[chronobreak::test]
+    async fn in_time() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .timeout_first(
+                Duration::from_millis(100),
+                Duration::from_millis(10),
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+    }
+
+    #[chronobreak::test]
+    async fn timeout_with() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .timeout_with(
+                Duration::from_millis(100),
+                |item| Duration::from_millis(*item),
+                scheduler.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(10).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.items().await, vec![10]);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::Timeout));
+    }
+
+    #[chronobreak::test]
+    async fn timeout_or() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(3);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let fallback = TestFlow::default();
+        test_flow
+            .clone()
+            .timeout_or(
+                Duration::from_millis(10),
+                scheduler.clone(),
+                fallback.clone(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(fallback.requested().await, 2);
+        fallback.emit_all(vec![1, 2]).await;
+        fallback.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn emit_after_cancel() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .timeout(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_subscriber.cancel().await;
+        scheduler.join();
+        test_flow.emit(1).await;
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(test_subscriber.error().await, None);
+    }
+
+    #[chronobreak::test]
+    async fn emit_after_timeout() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .timeout(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        scheduler.join();
+        test_flow.emit(1).await;
+
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::Timeout));
+    }
+}
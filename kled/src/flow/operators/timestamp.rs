@@ -0,0 +1,83 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(type = "flow", item = "(Instant, Item)")]
+pub struct Timestamp<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    scheduler: Scheduler,
+}
+
+#[derive(new)]
+struct TimestampSubscriber<Subscriber, Scheduler> {
+    subscriber: Subscriber,
+    scheduler: Scheduler,
+}
+
+#[async_trait]
+impl<Subscription, Item, Subscriber, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for TimestampSubscriber<Subscriber, Scheduler>
+where
+    Subscriber: core::Subscriber<Subscription, (Instant, Item), Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let now = self.scheduler.now();
+        self.subscriber.on_next((now, item)).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+
+    #[chronobreak::test]
+    async fn timestamp() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .timestamp(scheduler)
+            .subscribe(test_subscriber.clone())
+            .await;
+        let start = Instant::now();
+        test_flow.emit(0).await;
+        clock::advance(Duration::from_millis(10));
+        test_flow.emit(1).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(
+            test_subscriber.items().await,
+            vec![(start, 0), (start + Duration::from_millis(10), 1)]
+        );
+    }
+}
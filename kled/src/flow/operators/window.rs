@@ -0,0 +1,296 @@
+use crate::core;
+use crate::flow;
+use crate::processor::UnicastProcessor;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "WindowSubscription<Subscription>",
+    item = "UnicastProcessor<Subscription, Item, Error>"
+)]
+pub struct Window
+where
+    Error: Clone,
+{
+    count: usize,
+}
+
+#[derive(new)]
+struct WindowSubscriber<Subscriber, Subscription, Item, Error> {
+    subscriber: Subscriber,
+    count: usize,
+    #[new(default)]
+    shared: Option<Arc<Shared<Subscription>>>,
+    #[new(default)]
+    window: Option<UnicastProcessor<Subscription, Item, Error>>,
+    #[new(default)]
+    size: usize,
+}
+
+/// The upstream shared by the downstream and the open window, which is only
+/// cancelled once both of them were cancelled.
+struct Shared<Upstream> {
+    upstream: Upstream,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream> Shared<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    fn new(upstream: Upstream) -> Self {
+        Self {
+            upstream,
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        }
+    }
+
+    fn window<Item, Error>(self: &Arc<Self>) -> UnicastProcessor<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastProcessor::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.upstream.cancel().await;
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for WindowSubscriber<Subscriber, Subscription, Item, Error>
+where
+    Subscriber: core::Subscriber<
+            WindowSubscription<Subscription>,
+            UnicastProcessor<Subscription, Item, Error>,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let shared = Arc::new(Shared::new(subscription));
+        self.shared = Some(shared.clone());
+        self.subscriber
+            .on_subscribe(WindowSubscription {
+                shared,
+                count: self.count,
+            })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let shared = match self.shared.as_ref() {
+            Some(shared) => shared,
+            None => return,
+        };
+        if self.window.is_none() {
+            // Once the downstream cancelled, no further windows are opened.
+            if shared.cancelled.load(Ordering::SeqCst) {
+                return;
+            }
+            let window = shared.window();
+            self.window = Some(window.clone());
+            self.subscriber.on_next(window).await;
+        }
+        if let Some(window) = self.window.as_mut() {
+            core::Subscriber::on_next(window, item).await;
+        }
+        self.size += 1;
+        if self.size == self.count {
+            if let Some(mut window) = self.window.take() {
+                core::Subscriber::on_completed(&mut window).await;
+            }
+            self.size = 0;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if let Some(mut window) = self.window.take() {
+            core::Subscriber::on_error(&mut window, error.clone()).await;
+        }
+        if !self.is_cancelled() {
+            self.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(mut window) = self.window.take() {
+            core::Subscriber::on_completed(&mut window).await;
+        }
+        if !self.is_cancelled() {
+            self.subscriber.on_completed().await;
+        }
+    }
+}
+
+impl<Subscriber, Subscription, Item, Error>
+    WindowSubscriber<Subscriber, Subscription, Item, Error>
+{
+    fn is_cancelled(&self) -> bool {
+        match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::SeqCst),
+            None => false,
+        }
+    }
+}
+
+pub struct WindowSubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+    count: usize,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for WindowSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::SeqCst) || self.shared.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared
+            .upstream
+            .request(count.saturating_mul(self.count))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn count() {
+        let test_subscriber = TestSubscriber::new(3);
+        vec![0, 1, 2, 3, 4]
+            .into_flow()
+            .window(2)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+
+        let mut items = vec![];
+        for window in test_subscriber.items().await {
+            let window_subscriber = TestSubscriber::new(2);
+            window.subscribe(window_subscriber.clone()).await;
+            assert_eq!(
+                window_subscriber.status().await,
+                SubscriberStatus::Completed
+            );
+            items.push(window_subscriber.items().await);
+        }
+        assert_eq!(items, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[async_std::test]
+    async fn window_backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .window(3)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1, 2]).await;
+
+        let window_subscriber = TestSubscriber::new(1);
+        let window = test_subscriber.items().await.remove(0);
+        window.subscribe(window_subscriber.clone()).await;
+        assert_eq!(window_subscriber.items().await, vec![0]);
+        window_subscriber.request_direct(2).await;
+        assert_eq!(window_subscriber.items().await, vec![0, 1, 2]);
+        assert_eq!(
+            window_subscriber.status().await,
+            SubscriberStatus::Completed
+        );
+    }
+
+    #[async_std::test]
+    async fn cancel_with_open_window() {
+        let mut test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .window(3)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        let window_subscriber = TestSubscriber::new(3);
+        let window = test_subscriber.items().await.remove(0);
+        window.subscribe(window_subscriber.clone()).await;
+        test_subscriber.cancel().await;
+        assert!(!test_flow.is_cancelled().await);
+
+        test_flow.emit_all(vec![1, 2]).await;
+        assert_eq!(window_subscriber.items().await, vec![0, 1, 2]);
+        assert_eq!(
+            window_subscriber.status().await,
+            SubscriberStatus::Completed
+        );
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.items().await.len(), 1);
+    }
+
+    #[async_std::test]
+    async fn complete_after_cancel() {
+        let mut test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .window(3)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        let window_subscriber = TestSubscriber::new(3);
+        let window = test_subscriber.items().await.remove(0);
+        window.subscribe(window_subscriber.clone()).await;
+        test_subscriber.cancel().await;
+        test_flow.emit_completed().await;
+
+        // Only the open window is completed, the downstream already cancelled.
+        assert_eq!(
+            window_subscriber.status().await,
+            SubscriberStatus::Completed
+        );
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Cancelled);
+    }
+
+    #[async_std::test]
+    async fn cancel_window_after_outer() {
+        let mut test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .window(3)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        let mut window_subscriber = TestSubscriber::new(3);
+        let window = test_subscriber.items().await.remove(0);
+        window.subscribe(window_subscriber.clone()).await;
+        test_subscriber.cancel().await;
+        assert!(!test_flow.is_cancelled().await);
+        window_subscriber.cancel().await;
+
+        assert!(test_flow.is_cancelled().await);
+    }
+}
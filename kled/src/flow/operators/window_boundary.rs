@@ -0,0 +1,411 @@
+use crate::core;
+use crate::flow;
+use crate::processor::UnicastProcessor;
+use crate::subscription::*;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct WindowBoundary<
+    Flow,
+    Subscription,
+    Item,
+    Error,
+    Boundary,
+    BoundarySubscription,
+    BoundaryItem,
+> {
+    flow: Flow,
+    boundary: Boundary,
+    phantom: PhantomData<(
+        Subscription,
+        Item,
+        Error,
+        BoundarySubscription,
+        BoundaryItem,
+    )>,
+}
+
+type SharedData<Subscriber, Subscription, Item, Error, BoundarySubscription> =
+    Arc<Mutex<Data<Subscriber, Subscription, Item, Error, BoundarySubscription>>>;
+
+struct Data<Subscriber, Subscription, Item, Error, BoundarySubscription> {
+    subscriber: Subscriber,
+    window: Option<UnicastProcessor<Subscription, Item, Error>>,
+    shared: Arc<Shared<Subscription, BoundarySubscription>>,
+    is_done: bool,
+}
+
+/// The upstream and boundary shared by the downstream and the open window,
+/// which are only cancelled once both of them were cancelled.
+struct Shared<Upstream, Boundary> {
+    upstream: LazySubscription<Upstream>,
+    boundary: LazySubscription<Boundary>,
+    requested: AtomicUsize,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream, Boundary> Shared<Upstream, Boundary>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Boundary: core::Subscription + Send + Sync + 'static,
+{
+    fn window<Item, Error>(self: &Arc<Self>) -> UnicastProcessor<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastProcessor::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.cancel().await;
+        }
+    }
+
+    async fn cancel(&self) {
+        core::Subscription::cancel(&self.upstream).await;
+        core::Subscription::cancel(&self.boundary).await;
+    }
+}
+
+impl<Subscriber, Subscription, Item, Error, BoundarySubscription>
+    Data<Subscriber, Subscription, Item, Error, BoundarySubscription>
+where
+    Subscriber: core::Subscriber<
+            WindowBoundarySubscription<Subscription, BoundarySubscription>,
+            UnicastProcessor<Subscription, Item, Error>,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    BoundarySubscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn open_window(&mut self) {
+        if self.shared.requested.load(Ordering::Relaxed) == 0 {
+            self.error(flow::Error::MissingBackpressure).await;
+            return;
+        }
+        self.shared.requested.fetch_sub(1, Ordering::Relaxed);
+        let window = self.shared.window();
+        self.window = Some(window.clone());
+        self.subscriber.on_next(window).await;
+    }
+
+    async fn close_window(&mut self) {
+        if let Some(mut window) = self.window.take() {
+            core::Subscriber::on_completed(&mut window).await;
+        }
+    }
+
+    async fn error(&mut self, error: flow::Error<Error>) {
+        if !self.is_done {
+            self.is_done = true;
+            if let Some(mut window) = self.window.take() {
+                core::Subscriber::on_error(&mut window, error.clone()).await;
+            }
+            if !self.shared.cancelled.load(Ordering::SeqCst) {
+                self.subscriber.on_error(error).await;
+            }
+            self.shared.cancel().await;
+        }
+    }
+
+    async fn complete(&mut self) {
+        if !self.is_done {
+            self.is_done = true;
+            self.close_window().await;
+            if !self.shared.cancelled.load(Ordering::SeqCst) {
+                self.subscriber.on_completed().await;
+            }
+            self.shared.cancel().await;
+        }
+    }
+}
+
+#[async_trait]
+impl<Flow, Subscription, Item, Error, Boundary, BoundarySubscription, BoundaryItem>
+    core::Flow<
+        WindowBoundarySubscription<Subscription, BoundarySubscription>,
+        UnicastProcessor<Subscription, Item, Error>,
+        Error,
+    >
+    for WindowBoundary<
+        Flow,
+        Subscription,
+        Item,
+        Error,
+        Boundary,
+        BoundarySubscription,
+        BoundaryItem,
+    >
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    Boundary: core::Flow<BoundarySubscription, BoundaryItem, Error> + Send,
+    BoundarySubscription: core::Subscription + Send + Sync + 'static,
+    BoundaryItem: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<
+                WindowBoundarySubscription<Subscription, BoundarySubscription>,
+                UnicastProcessor<Subscription, Item, Error>,
+                Error,
+            > + Send
+            + 'static,
+    {
+        let stub = LazySubscriptionStub::default();
+        let boundary_stub = LazySubscriptionStub::default();
+        let shared = Arc::new(Shared {
+            upstream: stub.subscription(),
+            boundary: boundary_stub.subscription(),
+            requested: AtomicUsize::new(0),
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        });
+        subscriber
+            .on_subscribe(WindowBoundarySubscription {
+                shared: shared.clone(),
+            })
+            .await;
+        let data = Arc::new(Mutex::new(Data {
+            subscriber,
+            window: None,
+            shared,
+            is_done: false,
+        }));
+        data.lock().await.open_window().await;
+        self.boundary
+            .subscribe(BoundarySubscriber {
+                data: data.clone(),
+                stub: boundary_stub,
+                phantom: PhantomData,
+            })
+            .await;
+        self.flow
+            .subscribe(WindowBoundarySubscriber { data, stub })
+            .await;
+    }
+}
+
+struct WindowBoundarySubscriber<Subscriber, Subscription, Item, Error, BoundarySubscription> {
+    data: SharedData<Subscriber, Subscription, Item, Error, BoundarySubscription>,
+    stub: LazySubscriptionStub<Subscription>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, Item, Error, BoundarySubscription>
+    core::Subscriber<Subscription, Item, Error>
+    for WindowBoundarySubscriber<Subscriber, Subscription, Item, Error, BoundarySubscription>
+where
+    Subscriber: core::Subscriber<
+            WindowBoundarySubscription<Subscription, BoundarySubscription>,
+            UnicastProcessor<Subscription, Item, Error>,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    BoundarySubscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        self.stub.set_subscription(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if let Some(window) = data.window.as_mut() {
+            core::Subscriber::on_next(window, item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.data.lock().await.error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.data.lock().await.complete().await;
+    }
+}
+
+struct BoundarySubscriber<Subscriber, Subscription, Item, Error, BoundarySubscription, BoundaryItem>
+{
+    data: SharedData<Subscriber, Subscription, Item, Error, BoundarySubscription>,
+    stub: LazySubscriptionStub<BoundarySubscription>,
+    phantom: PhantomData<BoundaryItem>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, Item, Error, BoundarySubscription, BoundaryItem>
+    core::Subscriber<BoundarySubscription, BoundaryItem, Error>
+    for BoundarySubscriber<
+        Subscriber,
+        Subscription,
+        Item,
+        Error,
+        BoundarySubscription,
+        BoundaryItem,
+    >
+where
+    Subscriber: core::Subscriber<
+            WindowBoundarySubscription<Subscription, BoundarySubscription>,
+            UnicastProcessor<Subscription, Item, Error>,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    BoundarySubscription: core::Subscription + Send + Sync + 'static,
+    BoundaryItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: BoundarySubscription) {
+        subscription.request(usize::MAX).await;
+        self.stub.set_subscription(subscription).await;
+    }
+
+    async fn on_next(&mut self, _: BoundaryItem) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.close_window().await;
+            // Once the downstream cancelled, the open window is still
+            // completed but no further windows are opened.
+            if !data.shared.cancelled.load(Ordering::SeqCst) {
+                data.open_window().await;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.data.lock().await.error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.data.lock().await.complete().await;
+    }
+}
+
+pub struct WindowBoundarySubscription<Upstream, Boundary> {
+    shared: Arc<Shared<Upstream, Boundary>>,
+}
+
+#[async_trait]
+impl<Upstream, Boundary> core::Subscription for WindowBoundarySubscription<Upstream, Boundary>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Boundary: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn boundary() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let boundary = TestFlow::default().annotate_item_type(());
+        test_flow
+            .clone()
+            .window_boundary(boundary.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        boundary.emit(()).await;
+        test_flow.emit(2).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert!(boundary.is_cancelled().await);
+        let mut items = vec![];
+        for window in test_subscriber.items().await {
+            let window_subscriber = TestSubscriber::new(2);
+            window.subscribe(window_subscriber.clone()).await;
+            assert_eq!(
+                window_subscriber.status().await,
+                SubscriberStatus::Completed
+            );
+            items.push(window_subscriber.items().await);
+        }
+        assert_eq!(items, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[async_std::test]
+    async fn missing_backpressure() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let boundary = TestFlow::default().annotate_item_type(());
+        test_flow
+            .clone()
+            .annotate_item_type(0)
+            .window_boundary(boundary.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        boundary.emit(()).await;
+
+        assert_eq!(test_subscriber.items().await.len(), 1);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::MissingBackpressure)
+        );
+        assert!(test_flow.is_cancelled().await);
+        assert!(boundary.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn cancel_with_open_window() {
+        let mut test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let boundary = TestFlow::default().annotate_item_type(());
+        test_flow
+            .clone()
+            .window_boundary(boundary.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_subscriber.cancel().await;
+        assert!(!test_flow.is_cancelled().await);
+        test_flow.emit(1).await;
+        boundary.emit(()).await;
+
+        assert!(test_flow.is_cancelled().await);
+        assert!(boundary.is_cancelled().await);
+        let windows = test_subscriber.items().await;
+        assert_eq!(windows.len(), 1);
+        let window_subscriber = TestSubscriber::new(2);
+        windows[0]
+            .clone()
+            .subscribe(window_subscriber.clone())
+            .await;
+        assert_eq!(
+            window_subscriber.status().await,
+            SubscriberStatus::Completed
+        );
+        assert_eq!(window_subscriber.items().await, vec![0, 1]);
+    }
+}
@@ -0,0 +1,367 @@
+use crate::core;
+use crate::flow;
+use crate::processor::UnicastProcessor;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+/// Emits a new window every `duration`, timed on the given scheduler. Windows
+/// are opened by the timer rather than by the downstream demand, so one window
+/// must have been requested by the time each window opens, otherwise the flow
+/// terminates with [`Error::MissingBackpressure`] and cancels its upstream.
+/// The first window opens on subscription.
+///
+/// [`Error::MissingBackpressure`]: ../enum.Error.html#variant.MissingBackpressure
+#[operator(
+    type = "flow",
+    subscription = "WindowTimeSubscription<Subscription>",
+    item = "UnicastProcessor<Subscription, Item, Error>"
+)]
+pub struct WindowTime<Scheduler>
+where
+    Error: Clone,
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+pub struct WindowTimeSubscriber<Subscriber, Subscription, Item, Error, Scheduler> {
+    data: Arc<Mutex<Data<Subscriber, Subscription, Item, Error>>>,
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+struct Data<Subscriber, Subscription, Item, Error> {
+    subscriber: Subscriber,
+    shared: Option<Arc<Shared<Subscription>>>,
+    window: Option<UnicastProcessor<Subscription, Item, Error>>,
+    is_done: bool,
+}
+
+/// The upstream shared by the downstream and the open window, which is only
+/// cancelled once both of them were cancelled.
+struct Shared<Upstream> {
+    upstream: Upstream,
+    requested: AtomicUsize,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream> Shared<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    fn window<Item, Error>(self: &Arc<Self>) -> UnicastProcessor<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastProcessor::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.upstream.cancel().await;
+        }
+    }
+}
+
+impl<Subscriber, Subscription, Item, Error, Scheduler>
+    WindowTimeSubscriber<Subscriber, Subscription, Item, Error, Scheduler>
+{
+    pub fn new(subscriber: Subscriber, duration: Duration, scheduler: Scheduler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                subscriber,
+                shared: None,
+                window: None,
+                is_done: false,
+            })),
+            duration,
+            scheduler,
+        }
+    }
+}
+
+impl<Subscriber, Subscription, Item, Error> Data<Subscriber, Subscription, Item, Error>
+where
+    Subscriber: core::Subscriber<
+            WindowTimeSubscription<Subscription>,
+            UnicastProcessor<Subscription, Item, Error>,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+{
+    async fn open_window(&mut self) {
+        let shared = match self.shared.as_ref() {
+            Some(shared) => shared,
+            None => return,
+        };
+        if shared.requested.load(Ordering::Relaxed) == 0 {
+            self.is_done = true;
+            self.subscriber
+                .on_error(flow::Error::MissingBackpressure)
+                .await;
+            shared.upstream.cancel().await;
+            return;
+        }
+        shared.requested.fetch_sub(1, Ordering::Relaxed);
+        let window = shared.window();
+        self.window = Some(window.clone());
+        self.subscriber.on_next(window).await;
+    }
+
+    async fn close_window(&mut self) {
+        if let Some(mut window) = self.window.take() {
+            core::Subscriber::on_completed(&mut window).await;
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::SeqCst),
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for WindowTimeSubscriber<Subscriber, Subscription, Item, Error, Scheduler>
+where
+    Subscriber: core::Subscriber<
+            WindowTimeSubscription<Subscription>,
+            UnicastProcessor<Subscription, Item, Error>,
+            Error,
+        > + Send
+        + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        let shared = Arc::new(Shared {
+            upstream: subscription,
+            requested: AtomicUsize::new(0),
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        });
+        let mut data = self.data.lock().await;
+        data.shared = Some(shared.clone());
+        data.subscriber
+            .on_subscribe(WindowTimeSubscription {
+                shared: shared.clone(),
+            })
+            .await;
+        data.open_window().await;
+        drop(data);
+
+        let data = self.data.clone();
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            loop {
+                Delay::new(duration).await;
+                let mut data = data.lock().await;
+                if data.is_done {
+                    break;
+                }
+                data.close_window().await;
+                // Once the downstream cancelled, the open window is still
+                // completed but no further windows are opened.
+                if shared.cancelled.load(Ordering::SeqCst) {
+                    break;
+                }
+                data.open_window().await;
+            }
+        });
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if let Some(window) = data.window.as_mut() {
+            core::Subscriber::on_next(window, item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            if let Some(mut window) = data.window.take() {
+                core::Subscriber::on_error(&mut window, error.clone()).await;
+            }
+            if !data.is_cancelled() {
+                data.subscriber.on_error(error).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.close_window().await;
+            if !data.is_cancelled() {
+                data.subscriber.on_completed().await;
+            }
+        }
+    }
+}
+
+pub struct WindowTimeSubscription<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for WindowTimeSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::SeqCst) || self.shared.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.shared.requested.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn time() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .window_time(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        test_flow.emit_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        let windows = test_subscriber.items().await;
+        assert_eq!(windows.len(), 1);
+        let window_subscriber = TestSubscriber::new(2);
+        windows[0]
+            .clone()
+            .subscribe(window_subscriber.clone())
+            .await;
+        assert_eq!(
+            window_subscriber.status().await,
+            SubscriberStatus::Completed
+        );
+        assert_eq!(window_subscriber.items().await, vec![0, 1]);
+    }
+
+    #[chronobreak::test]
+    async fn missing_backpressure_on_next_window() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .window_time(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::MissingBackpressure)
+        );
+        let windows = test_subscriber.items().await;
+        assert_eq!(windows.len(), 1);
+        let window_subscriber = TestSubscriber::new(1);
+        windows[0]
+            .clone()
+            .subscribe(window_subscriber.clone())
+            .await;
+        assert_eq!(
+            window_subscriber.status().await,
+            SubscriberStatus::Completed
+        );
+        assert_eq!(window_subscriber.items().await, vec![0]);
+    }
+
+    #[chronobreak::test]
+    async fn missing_backpressure() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .annotate_item_type(0)
+            .window_time(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Cancelled);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::MissingBackpressure)
+        );
+    }
+
+    #[chronobreak::test]
+    async fn cancel_with_open_window() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .window_time(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_subscriber.cancel().await;
+        assert!(!test_flow.is_cancelled().await);
+        test_flow.emit(1).await;
+        scheduler.join();
+
+        assert!(test_flow.is_cancelled().await);
+        let windows = test_subscriber.items().await;
+        assert_eq!(windows.len(), 1);
+        let window_subscriber = TestSubscriber::new(2);
+        windows[0]
+            .clone()
+            .subscribe(window_subscriber.clone())
+            .await;
+        assert_eq!(
+            window_subscriber.status().await,
+            SubscriberStatus::Completed
+        );
+        assert_eq!(window_subscriber.items().await, vec![0, 1]);
+    }
+}
@@ -0,0 +1,358 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// Emits `prefix` before and `suffix` after the items of the upstream flow,
+/// followed by `default` if the upstream flow did not emit any items. This
+/// is the common implementation of [`Flow::start_with`], [`Flow::end_with`]
+/// and [`Flow::default_if_empty`].
+///
+/// [`Flow::start_with`]: ../../core/trait.Flow.html#method.start_with
+/// [`Flow::end_with`]: ../../core/trait.Flow.html#method.end_with
+/// [`Flow::default_if_empty`]: ../../core/trait.Flow.html#method.default_if_empty
+#[operator(
+    type = "flow",
+    subscription = "WithItemsSubscription<Subscription, Item, Error>"
+)]
+pub struct WithItems {
+    prefix: Vec<Item>,
+    suffix: Vec<Item>,
+    default: Option<Item>,
+}
+
+type BoxedSubscriber<Subscription, Item, Error> = Box<
+    dyn core::Subscriber<WithItemsSubscription<Subscription, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+struct Data<Subscription, Item, Error> {
+    upstream: Subscription,
+    subscriber: Mutex<Option<BoxedSubscriber<Subscription, Item, Error>>>,
+    state: Mutex<State<Item, Error>>,
+    wip: AtomicUsize,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Phase {
+    Prefix,
+    Upstream,
+    Suffix,
+}
+
+struct State<Item, Error> {
+    phase: Phase,
+    prefix: VecDeque<Item>,
+    queue: VecDeque<Item>,
+    suffix: VecDeque<Item>,
+    default: Option<Item>,
+    requested: usize,
+    error: Option<flow::Error<Error>>,
+    has_upstream_items: bool,
+    is_upstream_done: bool,
+    is_cancelled: bool,
+}
+
+impl<Item, Error> State<Item, Error> {
+    fn next_item(&mut self) -> Option<Item> {
+        if self.requested == 0 {
+            return None;
+        }
+        let item = match self.phase {
+            Phase::Prefix => self.prefix.pop_front(),
+            Phase::Upstream => self.queue.pop_front(),
+            Phase::Suffix => self.suffix.pop_front(),
+        };
+        if item.is_some() && self.requested != usize::MAX {
+            self.requested -= 1;
+        }
+        item
+    }
+}
+
+async fn drain<Subscription, Item, Error>(data: &Data<Subscription, Item, Error>)
+where
+    Subscription: core::Subscription,
+{
+    // Only one caller may emit at a time, reentrant calls from within the
+    // subscriber only mark that another iteration is required.
+    if data.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = data.subscriber.lock().await;
+        let mut is_terminated = false;
+        if let Some(subscriber) = subscriber.as_mut() {
+            loop {
+                let mut state = data.state.lock().await;
+                if state.is_cancelled {
+                    is_terminated = true;
+                    break;
+                }
+                if let Some(item) = state.next_item() {
+                    drop(state);
+                    subscriber.on_next(item).await;
+                    continue;
+                }
+                match state.phase {
+                    Phase::Prefix if state.prefix.is_empty() => {
+                        // The demand that was not used up by the prefix is
+                        // handed over to upstream.
+                        state.phase = Phase::Upstream;
+                        let requested = state.requested;
+                        drop(state);
+                        if requested > 0 {
+                            data.upstream.request(requested).await;
+                        }
+                        continue;
+                    }
+                    Phase::Upstream if state.is_upstream_done && state.queue.is_empty() => {
+                        if let Some(error) = state.error.take() {
+                            drop(state);
+                            subscriber.on_error(error).await;
+                            is_terminated = true;
+                            break;
+                        }
+                        state.phase = Phase::Suffix;
+                        if !state.has_upstream_items {
+                            if let Some(default) = state.default.take() {
+                                state.suffix.push_back(default);
+                            }
+                        }
+                        continue;
+                    }
+                    Phase::Suffix if state.suffix.is_empty() => {
+                        drop(state);
+                        subscriber.on_completed().await;
+                        is_terminated = true;
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        if is_terminated {
+            *subscriber = None;
+        }
+        drop(subscriber);
+        missed = data.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+pub struct WithItemsSubscriber<Subscriber, Subscription, Item, Error> {
+    subscriber: Option<Subscriber>,
+    state: Option<State<Item, Error>>,
+    data: Option<Arc<Data<Subscription, Item, Error>>>,
+}
+
+impl<Subscriber, Subscription, Item, Error>
+    WithItemsSubscriber<Subscriber, Subscription, Item, Error>
+{
+    pub fn new(
+        subscriber: Subscriber,
+        prefix: Vec<Item>,
+        suffix: Vec<Item>,
+        default: Option<Item>,
+    ) -> Self {
+        let phase = if prefix.is_empty() {
+            Phase::Upstream
+        } else {
+            Phase::Prefix
+        };
+        Self {
+            subscriber: Some(subscriber),
+            state: Some(State {
+                phase,
+                prefix: prefix.into(),
+                queue: VecDeque::new(),
+                suffix: suffix.into(),
+                default,
+                requested: 0,
+                error: None,
+                has_upstream_items: false,
+                is_upstream_done: false,
+                is_cancelled: false,
+            }),
+            data: None,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Subscriber, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for WithItemsSubscriber<Subscriber, Subscription, Item, Error>
+where
+    Subscriber: core::Subscriber<WithItemsSubscription<Subscription, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let (mut subscriber, state) = match (self.subscriber.take(), self.state.take()) {
+            (Some(subscriber), Some(state)) => (subscriber, state),
+            _ => return,
+        };
+        let data = Arc::new(Data {
+            upstream: subscription,
+            subscriber: Mutex::new(None),
+            state: Mutex::new(state),
+            wip: AtomicUsize::new(0),
+        });
+        self.data = Some(data.clone());
+        subscriber
+            .on_subscribe(WithItemsSubscription { data: data.clone() })
+            .await;
+        *data.subscriber.lock().await = Some(Box::new(subscriber));
+        drain(&data).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(data) = self.data.as_ref() {
+            {
+                let mut state = data.state.lock().await;
+                state.has_upstream_items = true;
+                state.queue.push_back(item);
+            }
+            drain(data).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if let Some(data) = self.data.as_ref() {
+            {
+                let mut state = data.state.lock().await;
+                state.error = Some(error);
+                state.is_upstream_done = true;
+            }
+            drain(data).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(data) = self.data.as_ref() {
+            data.state.lock().await.is_upstream_done = true;
+            drain(data).await;
+        }
+    }
+}
+
+pub struct WithItemsSubscription<Upstream, Item, Error> {
+    data: Arc<Data<Upstream, Item, Error>>,
+}
+
+#[async_trait]
+impl<Upstream, Item, Error> core::Subscription for WithItemsSubscription<Upstream, Item, Error>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.data.state.lock().await.is_cancelled = true;
+        self.data.upstream.cancel().await;
+        drain(&self.data).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.state.lock().await.is_cancelled
+    }
+
+    async fn request(&self, count: usize) {
+        let is_upstream_phase = {
+            let mut state = self.data.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+            state.phase == Phase::Upstream
+        };
+        if is_upstream_phase {
+            self.data.upstream.request(count).await;
+        }
+        drain(&self.data).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn start_with() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .start_with(vec![0, 1])
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(test_flow.requested().await, 0);
+        test_subscriber.request_direct(3).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+        assert_eq!(test_flow.requested().await, 2);
+        test_flow.emit_all(vec![2, 3]).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn end_with() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .end_with(vec![1, 2])
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit(0).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+        test_subscriber.request_direct(1).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn default_if_empty() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .default_if_empty(0)
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.items().await, Vec::<i32>::new());
+        test_subscriber.request_direct(1).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn not_empty() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        vec![1, 2]
+            .into_flow()
+            .default_if_empty(0)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2]);
+    }
+}
@@ -0,0 +1,294 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "WithLatestFromSubscription<Subscription, OtherSubscription>",
+    item = "ItemOut"
+)]
+pub struct WithLatestFrom<Other, OtherSubscription, OtherItem, ItemOut, CombineFn>
+where
+    Other: core::Flow<OtherSubscription, OtherItem, Error>,
+    OtherSubscription: core::Subscription + Send + Sync + 'static,
+    OtherItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CombineFn: FnMut(Item, &OtherItem) -> ItemOut,
+{
+    other: Other,
+    combiner: CombineFn,
+}
+
+struct WithLatestFromSubscriber<
+    Subscriber,
+    Subscription,
+    Other,
+    OtherSubscription,
+    OtherItem,
+    CombineFn,
+> {
+    subscriber: Arc<Mutex<Option<Subscriber>>>,
+    upstream: Option<Arc<Subscription>>,
+    other: Option<Other>,
+    stub: LazySubscriptionStub<OtherSubscription>,
+    other_subscription: Option<LazySubscription<OtherSubscription>>,
+    latest: Arc<Mutex<Option<OtherItem>>>,
+    combiner: CombineFn,
+}
+
+impl<Subscriber, Subscription, Other, OtherSubscription, OtherItem, CombineFn>
+    WithLatestFromSubscriber<
+        Subscriber,
+        Subscription,
+        Other,
+        OtherSubscription,
+        OtherItem,
+        CombineFn,
+    >
+{
+    fn new(subscriber: Subscriber, other: Other, combiner: CombineFn) -> Self {
+        Self {
+            subscriber: Arc::new(Mutex::new(Some(subscriber))),
+            upstream: None,
+            other: Some(other),
+            stub: LazySubscriptionStub::default(),
+            other_subscription: None,
+            latest: Arc::new(Mutex::new(None)),
+            combiner,
+        }
+    }
+}
+
+#[async_trait]
+impl<
+        Subscription,
+        Subscriber,
+        Item,
+        Error,
+        Other,
+        OtherSubscription,
+        OtherItem,
+        ItemOut,
+        CombineFn,
+    > core::Subscriber<Subscription, Item, Error>
+    for WithLatestFromSubscriber<
+        Subscriber,
+        Subscription,
+        Other,
+        OtherSubscription,
+        OtherItem,
+        CombineFn,
+    >
+where
+    Subscriber: core::Subscriber<
+            WithLatestFromSubscription<Subscription, OtherSubscription>,
+            ItemOut,
+            Error,
+        > + Send
+        + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Other: core::Flow<OtherSubscription, OtherItem, Error> + Send,
+    OtherSubscription: core::Subscription + Send + Sync + 'static,
+    OtherItem: Send + 'static,
+    ItemOut: Send + 'static,
+    CombineFn: FnMut(Item, &OtherItem) -> ItemOut + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let upstream = Arc::new(subscription);
+        self.upstream = Some(upstream.clone());
+        self.other_subscription = Some(self.stub.subscription());
+        if let Some(subscriber) = self.subscriber.lock().await.as_mut() {
+            subscriber
+                .on_subscribe(WithLatestFromSubscription::new(
+                    upstream.clone(),
+                    self.stub.subscription(),
+                ))
+                .await;
+        }
+        if let Some(other) = self.other.take() {
+            other
+                .subscribe(OtherSubscriber {
+                    subscriber: self.subscriber.clone(),
+                    upstream,
+                    stub: std::mem::take(&mut self.stub),
+                    latest: self.latest.clone(),
+                    phantom: PhantomData,
+                })
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let item = match self.latest.lock().await.as_ref() {
+            Some(latest) => (self.combiner)(item, latest),
+            None => {
+                // The item is dropped, so the demand it consumed is requested
+                // from upstream again.
+                if let Some(upstream) = self.upstream.as_ref() {
+                    upstream.request(1).await;
+                }
+                return;
+            }
+        };
+        if let Some(subscriber) = self.subscriber.lock().await.as_mut() {
+            subscriber.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let subscriber = self.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber.on_error(error).await;
+            if let Some(other) = self.other_subscription.as_ref() {
+                core::Subscription::cancel(other).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let subscriber = self.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber.on_completed().await;
+            if let Some(other) = self.other_subscription.as_ref() {
+                core::Subscription::cancel(other).await;
+            }
+        }
+    }
+}
+
+struct OtherSubscriber<Subscriber, Subscription, OtherSubscription, OtherItem, ItemOut> {
+    subscriber: Arc<Mutex<Option<Subscriber>>>,
+    upstream: Arc<Subscription>,
+    stub: LazySubscriptionStub<OtherSubscription>,
+    latest: Arc<Mutex<Option<OtherItem>>>,
+    phantom: PhantomData<(OtherSubscription, ItemOut)>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, OtherSubscription, OtherItem, ItemOut, Error>
+    core::Subscriber<OtherSubscription, OtherItem, Error>
+    for OtherSubscriber<Subscriber, Subscription, OtherSubscription, OtherItem, ItemOut>
+where
+    Subscriber: core::Subscriber<
+            WithLatestFromSubscription<Subscription, OtherSubscription>,
+            ItemOut,
+            Error,
+        > + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    OtherSubscription: core::Subscription + Send + Sync + 'static,
+    OtherItem: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: OtherSubscription) {
+        // Only the latest item of the other flow is of interest.
+        subscription.request(usize::MAX).await;
+        self.stub.set_subscription(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: OtherItem) {
+        *self.latest.lock().await = Some(item);
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        let subscriber = self.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber.on_error(error).await;
+            self.upstream.cancel().await;
+        }
+    }
+
+    async fn on_completed(&mut self) {}
+}
+
+#[derive(new)]
+pub struct WithLatestFromSubscription<Upstream, Other> {
+    upstream: Arc<Upstream>,
+    other: LazySubscription<Other>,
+    #[new(default)]
+    cancelled: AtomicBool,
+}
+
+#[async_trait]
+impl<Upstream, Other> core::Subscription for WithLatestFromSubscription<Upstream, Other>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Other: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.upstream.cancel().await;
+        self.other.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn with_latest_from() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let other = TestFlow::default();
+        test_flow
+            .clone()
+            .with_latest_from(other.clone(), |item, latest: &i32| item + latest)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_flow.requested().await, 2);
+        assert_eq!(other.requested().await, usize::MAX);
+        test_flow.emit(0).await;
+        assert_eq!(test_flow.requested().await, 3);
+        other.emit(10).await;
+        test_flow.emit(1).await;
+        other.emit(20).await;
+        other.emit(30).await;
+        test_flow.emit(2).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![11, 32]);
+        assert!(other.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn other_error() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_flow = TestFlow::default().annotate_item_type(0);
+        let other = TestFlow::default().annotate_item_type(0);
+        test_flow
+            .clone()
+            .with_latest_from(other.clone(), |item, latest| item + latest)
+            .subscribe(test_subscriber.clone())
+            .await;
+        other.emit_error(()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+        assert!(test_flow.is_cancelled().await);
+    }
+}
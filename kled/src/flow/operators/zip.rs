@@ -0,0 +1,652 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The default number of items requested from each source ahead of their
+/// consumption.
+const PREFETCH: usize = 128;
+
+/// The items taken from the sources of an operator such as [`Flow::zip`] to
+/// produce a single combined item. Each source pushes into its own typed
+/// queue of [`Queues`](ZipItems::Queues).
+///
+/// [`Flow::zip`]: ../../core/trait.Flow.html#method.zip
+pub trait ZipItems: Sized + Send + 'static {
+    type Queues: Send + 'static;
+
+    /// Returns whether any source is `done` and its queue is empty.
+    fn is_exhausted(queues: &Self::Queues, done: &[bool]) -> bool;
+
+    /// Pops the next item of each queue if none of them is empty.
+    fn pop(queues: &mut Self::Queues) -> Option<Self>;
+}
+
+impl<Item> ZipItems for Vec<Item>
+where
+    Item: Send + 'static,
+{
+    type Queues = Vec<VecDeque<Item>>;
+
+    fn is_exhausted(queues: &Self::Queues, done: &[bool]) -> bool {
+        queues.is_empty()
+            || queues
+                .iter()
+                .zip(done)
+                .any(|(queue, done)| *done && queue.is_empty())
+    }
+
+    fn pop(queues: &mut Self::Queues) -> Option<Self> {
+        if queues.iter().any(VecDeque::is_empty) {
+            return None;
+        }
+        Some(queues.iter_mut().filter_map(VecDeque::pop_front).collect())
+    }
+}
+
+macro_rules! zip_items {
+    ($($item:ident $index:tt),+) => {
+        impl<$($item),+> ZipItems for ($($item,)+)
+        where
+            $($item: Send + 'static,)+
+        {
+            type Queues = ($(VecDeque<$item>,)+);
+
+            fn is_exhausted(queues: &Self::Queues, done: &[bool]) -> bool {
+                $((done[$index] && queues.$index.is_empty()))||+
+            }
+
+            fn pop(queues: &mut Self::Queues) -> Option<Self> {
+                if $(queues.$index.is_empty())||+ {
+                    return None;
+                }
+                Some(($(queues.$index.pop_front()?,)+))
+            }
+        }
+    };
+}
+
+zip_items!(A 0, B 1);
+zip_items!(A 0, B 1, C 2);
+zip_items!(A 0, B 1, C 2, D 3);
+
+type BoxedSource<Items, Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Items, Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedSubscriber<Items, Item, Error> =
+    Box<dyn core::Subscriber<ZipSubscription<Items, Item, Error>, Item, Error> + Send + 'static>;
+
+type BoxedSubscription = Arc<dyn core::Subscription + Send + Sync>;
+
+type Combiner<Items, Item> = Box<dyn FnMut(Items) -> Item + Send>;
+
+/// Combines the items of multiple sources positionally. Each source is
+/// requested independently with a prefetch window and the zipped flow
+/// completes as soon as any source completed and all of its items were
+/// consumed.
+pub struct Zip<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    sources: Vec<BoxedSource<Items, Item, Error>>,
+    queues: Items::Queues,
+    combiner: Combiner<Items, Item>,
+    prefetch: usize,
+}
+
+/// A [`Zip`] of a fixed number of sources, such as [`Flow::zip`], which emits
+/// the items of its sources as tuples.
+///
+/// [`Flow::zip`]: ../../core/trait.Flow.html#method.zip
+pub type ZipTuple<Items, Error> = Zip<Items, Items, Error>;
+
+impl<Items, Item, Error> Zip<Items, Item, Error>
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn new<CombineFn>(queues: Items::Queues, combiner: CombineFn) -> Self
+    where
+        CombineFn: FnMut(Items) -> Item + Send + 'static,
+    {
+        Self {
+            sources: vec![],
+            queues,
+            combiner: Box::new(combiner),
+            prefetch: PREFETCH,
+        }
+    }
+
+    fn with_source<Flow, Subscription, SourceItem, Push>(mut self, flow: Flow, push: Push) -> Self
+    where
+        Flow: core::Flow<Subscription, SourceItem, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        SourceItem: Send + 'static,
+        Push: FnMut(&mut Items::Queues, SourceItem) + Send + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move {
+                flow.subscribe(ZipSubscriber {
+                    shared,
+                    index,
+                    push,
+                    phantom: PhantomData,
+                })
+                .await
+            })
+        }));
+        self
+    }
+
+    /// Requests `prefetch` items from each source ahead of their consumption
+    /// instead of 128. Each source is requested again once three quarters of
+    /// the prefetch window were consumed.
+    pub fn with_prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+}
+
+impl<SourceItem, Item, Error> Zip<Vec<SourceItem>, Item, Error>
+where
+    SourceItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip_all<Flow, Subscription, CombineFn>(sources: Vec<Flow>, combiner: CombineFn) -> Self
+    where
+        Flow: core::Flow<Subscription, SourceItem, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+        CombineFn: FnMut(Vec<SourceItem>) -> Item + Send + 'static,
+    {
+        let queues = sources.iter().map(|_| VecDeque::new()).collect();
+        sources
+            .into_iter()
+            .enumerate()
+            .fold(Self::new(queues, combiner), |zip, (index, source)| {
+                zip.with_source(source, move |queues, item| queues[index].push_back(item))
+            })
+    }
+}
+
+impl<A, B, Error> Zip<(A, B), (A, B), Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip2<FlowA, SubscriptionA, FlowB, SubscriptionB>(a: FlowA, b: FlowB) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), |items| items)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+    }
+}
+
+impl<A, B, C, Error> Zip<(A, B, C), (A, B, C), Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    C: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip3<FlowA, SubscriptionA, FlowB, SubscriptionB, FlowC, SubscriptionC>(
+        a: FlowA,
+        b: FlowB,
+        c: FlowC,
+    ) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), |items| items)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+    }
+}
+
+impl<A, B, C, D, Error> Zip<(A, B, C, D), (A, B, C, D), Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    C: Send + 'static,
+    D: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip4<
+        FlowA,
+        SubscriptionA,
+        FlowB,
+        SubscriptionB,
+        FlowC,
+        SubscriptionC,
+        FlowD,
+        SubscriptionD,
+    >(
+        a: FlowA,
+        b: FlowB,
+        c: FlowC,
+        d: FlowD,
+    ) -> Self
+    where
+        FlowA: core::Flow<SubscriptionA, A, Error> + Send + 'static,
+        SubscriptionA: core::Subscription + Send + Sync + 'static,
+        FlowB: core::Flow<SubscriptionB, B, Error> + Send + 'static,
+        SubscriptionB: core::Subscription + Send + Sync + 'static,
+        FlowC: core::Flow<SubscriptionC, C, Error> + Send + 'static,
+        SubscriptionC: core::Subscription + Send + Sync + 'static,
+        FlowD: core::Flow<SubscriptionD, D, Error> + Send + 'static,
+        SubscriptionD: core::Subscription + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), |items| items)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+            .with_source(d, |queues, item| queues.3.push_back(item))
+    }
+}
+
+/// Returns a [`Flow`] that combines the items of all `sources` positionally by
+/// applying `combiner` to the next item of each source.
+///
+/// [`Flow`]: ../../core/trait.Flow.html
+pub fn zip_all<Flow, Subscription, Item, Error, ItemOut, CombineFn>(
+    sources: Vec<Flow>,
+    combiner: CombineFn,
+) -> Zip<Vec<Item>, ItemOut, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ItemOut: Send + 'static,
+    CombineFn: FnMut(Vec<Item>) -> ItemOut + Send + 'static,
+{
+    Zip::zip_all(sources, combiner)
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Flow<ZipSubscription<Items, Item, Error>, Item, Error>
+    for Zip<Items, Item, Error>
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber:
+            core::Subscriber<ZipSubscription<Items, Item, Error>, Item, Error> + Send + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            subscriber: Mutex::new(None),
+            combiner: Mutex::new(self.combiner),
+            subscriptions: Mutex::new((0..count).map(|_| None).collect()),
+            state: Mutex::new(State {
+                queues: self.queues,
+                done: vec![false; count],
+                consumed: 0,
+                prefetch: self.prefetch,
+                requested: 0,
+                error: None,
+                is_cancelled: false,
+                is_terminated: false,
+            }),
+            wip: AtomicUsize::new(0),
+        });
+        subscriber
+            .on_subscribe(ZipSubscription {
+                shared: shared.clone(),
+                cancelled: AtomicBool::new(false),
+            })
+            .await;
+        *shared.subscriber.lock().await = Some(Box::new(subscriber));
+        drain(&shared).await;
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    subscriber: Mutex<Option<BoxedSubscriber<Items, Item, Error>>>,
+    combiner: Mutex<Combiner<Items, Item>>,
+    subscriptions: Mutex<Vec<Option<BoxedSubscription>>>,
+    state: Mutex<State<Items, Error>>,
+    wip: AtomicUsize,
+}
+
+struct State<Items, Error>
+where
+    Items: ZipItems,
+{
+    queues: Items::Queues,
+    done: Vec<bool>,
+    consumed: usize,
+    prefetch: usize,
+    requested: usize,
+    error: Option<flow::Error<Error>>,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Items, Error> State<Items, Error>
+where
+    Items: ZipItems,
+{
+    /// The number of consumed items after which each source is requested
+    /// again.
+    fn limit(&self) -> usize {
+        self.prefetch - self.prefetch / 4
+    }
+}
+
+impl<Items, Item, Error> Shared<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    async fn subscriptions(&self) -> Vec<BoxedSubscription> {
+        self.subscriptions
+            .lock()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect()
+    }
+}
+
+async fn drain<Items, Item, Error>(shared: &Shared<Items, Item, Error>)
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = shared.subscriber.lock().await;
+        let mut is_terminated = false;
+        if let Some(downstream) = subscriber.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    state.is_terminated = true;
+                    is_terminated = true;
+                    break;
+                }
+                if let Some(error) = state.error.take() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_error(error).await;
+                    is_terminated = true;
+                    break;
+                }
+                if Items::is_exhausted(&state.queues, &state.done) {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_completed().await;
+                    is_terminated = true;
+                    break;
+                }
+                if state.requested == 0 {
+                    break;
+                }
+                let items = match Items::pop(&mut state.queues) {
+                    Some(items) => items,
+                    None => break,
+                };
+                if state.requested != usize::MAX {
+                    state.requested -= 1;
+                }
+                let limit = state.limit();
+                state.consumed += 1;
+                let replenish = state.consumed == limit;
+                if replenish {
+                    state.consumed = 0;
+                }
+                drop(state);
+                if replenish {
+                    for subscription in shared.subscriptions().await {
+                        subscription.request(limit).await;
+                    }
+                }
+                let item = (shared.combiner.lock().await)(items);
+                downstream.on_next(item).await;
+            }
+        }
+        if is_terminated {
+            *subscriber = None;
+        }
+        drop(subscriber);
+        if is_terminated {
+            for subscription in shared.subscriptions().await {
+                subscription.cancel().await;
+            }
+        }
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+struct ZipSubscriber<Items, Item, Error, SourceItem, Push>
+where
+    Items: ZipItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+    index: usize,
+    push: Push,
+    phantom: PhantomData<SourceItem>,
+}
+
+#[async_trait]
+impl<Subscription, Items, Item, Error, SourceItem, Push>
+    core::Subscriber<Subscription, SourceItem, Error>
+    for ZipSubscriber<Items, Item, Error, SourceItem, Push>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SourceItem: Send + 'static,
+    Push: FnMut(&mut Items::Queues, SourceItem) + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription: BoxedSubscription = Arc::new(subscription);
+        self.shared.subscriptions.lock().await[self.index] = Some(subscription.clone());
+        let state = self.shared.state.lock().await;
+        let (is_terminated, prefetch) = (state.is_terminated, state.prefetch);
+        drop(state);
+        if is_terminated {
+            subscription.cancel().await;
+        } else {
+            subscription.request(prefetch).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: SourceItem) {
+        (self.push)(&mut self.shared.state.lock().await.queues, item);
+        drain(&self.shared).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.error.is_none() {
+                state.error = Some(error);
+            }
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.done[self.index] = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct ZipSubscription<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+    cancelled: AtomicBool,
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Subscription for ZipSubscription<Items, Item, Error>
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.shared.state.lock().await.is_cancelled = true;
+        drain(&self.shared).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, count: usize) {
+        {
+            let mut state = self.shared.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+        }
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn zip() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        vec![0, 1, 2]
+            .into_flow()
+            .zip(vec!["a", "b"].into_flow())
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![(0, "a"), (1, "b")]);
+    }
+
+    #[async_std::test]
+    async fn zip4() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        vec![0]
+            .into_flow()
+            .zip4(
+                vec!['a'].into_flow(),
+                vec![true].into_flow(),
+                vec![1.0].into_flow(),
+            )
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![(0, 'a', true, 1.0)]);
+    }
+
+    #[async_std::test]
+    async fn zip_all() {
+        let test_subscriber = TestSubscriber::new(1);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        let c = TestFlow::default();
+        operators::zip_all(vec![a.clone(), b.clone(), c.clone()], |items| {
+            items.into_iter().sum::<i32>()
+        })
+        .subscribe(test_subscriber.clone())
+        .await;
+        assert_eq!(a.requested().await, 128);
+        a.emit_all(vec![1, 2]).await;
+        b.emit_all(vec![10, 20]).await;
+        c.emit(100).await;
+        b.emit_completed().await;
+        assert_eq!(test_subscriber.items().await, vec![111]);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        test_subscriber.request_direct(1).await;
+        c.emit(200).await;
+
+        assert_eq!(test_subscriber.items().await, vec![111, 222]);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert!(a.is_cancelled().await);
+        assert!(c.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn with_prefetch() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_error_type(());
+        let b = TestFlow::default();
+        a.clone()
+            .zip(b.clone())
+            .with_prefetch(4)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(a.requested().await, 4);
+        a.emit_all(vec![0, 1, 2]).await;
+        b.emit_all(vec!['a', 'b']).await;
+        assert_eq!(a.requested().await, 4);
+        b.emit('c').await;
+
+        assert_eq!(a.requested().await, 7);
+        assert_eq!(b.requested().await, 7);
+        assert_eq!(
+            test_subscriber.items().await,
+            vec![(0, 'a'), (1, 'b'), (2, 'c')]
+        );
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let a = TestFlow::default().annotate_item_type(0);
+        let b = TestFlow::default().annotate_item_type(0);
+        a.clone()
+            .zip(b.clone())
+            .subscribe(test_subscriber.clone())
+            .await;
+        a.emit_error(()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(()))
+        );
+        assert!(b.is_cancelled().await);
+    }
+}
@@ -44,6 +44,14 @@ impl<Item, Error> TestFlow<Item, Error> {
         }
     }
 
+    pub async fn requested(&self) -> usize {
+        assert!(self.has_observer().await);
+        match self.data.lock().await.emitter {
+            Some(ref consumer) => consumer.requested(),
+            None => panic!(),
+        }
+    }
+
     pub async fn emit(&self, item: Item) {
         assert!(self.has_observer().await);
         match self.data.lock().await.emitter {
@@ -12,7 +12,7 @@ extern crate derive_new;
 #[macro_use]
 extern crate kled_derive;
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Never {}
 
 macro_rules! reexport_all {
@@ -24,12 +24,15 @@ macro_rules! reexport_all {
     };
 }
 
+pub mod aggregator;
 pub mod cancellable;
 pub mod core;
 pub mod flow;
 pub mod observable;
 pub mod observer;
+pub mod processor;
 pub mod scheduler;
+pub mod sliding_window;
 pub mod subject;
 pub mod subscriber;
 pub mod subscription;
@@ -0,0 +1,8 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error<UpstreamError> {
+    Upstream(UpstreamError),
+    NoSuchElement,
+    TooManyElements,
+    Timeout,
+    Disconnected,
+}
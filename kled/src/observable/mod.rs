@@ -2,6 +2,7 @@ reexport_all! {
     mod box_emitter;
     mod create;
     mod emitter;
+    mod error;
     mod from_iter;
     mod signal;
     mod test_observable;
@@ -0,0 +1,125 @@
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+
+#[operator(
+    type = "observable",
+    item = "Output",
+    error = "observable::Error<Error>"
+)]
+pub struct Aggregate<Aggregator, Output>
+where
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+{
+    aggregator: Aggregator,
+}
+
+#[derive(new)]
+struct AggregateObserver<Observer, Aggregator> {
+    observer: Observer,
+    aggregator: Aggregator,
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Aggregator, Output>
+    core::Observer<Cancellable, Item, Error> for AggregateObserver<Observer, Aggregator>
+where
+    Observer: core::Observer<Cancellable, Output, observable::Error<Error>> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+    Output: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.aggregator.update(item);
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer
+            .on_error(observable::Error::Upstream(error))
+            .await;
+    }
+
+    async fn on_completed(&mut self) {
+        match self.aggregator.output() {
+            Some(output) => {
+                self.observer.on_next(output).await;
+                self.observer.on_completed().await;
+            }
+            None => {
+                self.observer
+                    .on_error(observable::Error::NoSuchElement)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::{self, *};
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn sum() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .sum()
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![1, 2, 3]).await;
+        assert_eq!(test_observer.items().await, vec![]);
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![6]);
+    }
+
+    #[async_std::test]
+    async fn average_by() {
+        let test_observer = TestObserver::default();
+        vec![1u64, 2, 6]
+            .into_observable()
+            .average_by(|item| item as f64)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![3.0]);
+    }
+
+    #[async_std::test]
+    async fn min_by_key() {
+        let test_observer = TestObserver::default();
+        vec![(0, 'a'), (-1, 'b'), (2, 'c')]
+            .into_observable()
+            .min_by_key(|(key, _)| *key)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.items().await, vec![(-1, 'b')]);
+    }
+
+    #[async_std::test]
+    async fn empty() {
+        let test_observer = TestObserver::default();
+        Vec::<i32>::new()
+            .into_observable()
+            .average()
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::NoSuchElement)
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedSource<Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedObserver<Item, Error> =
+    Box<dyn core::Observer<AmbCancellable<Item, Error>, Item, Error> + Send + 'static>;
+
+type BoxedCancel = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Mirrors the first of multiple sources to emit any signal. All sources are
+/// subscribed to, while all other sources are cancelled as soon as the
+/// winner is known.
+pub struct Amb<Item, Error> {
+    sources: Vec<BoxedSource<Item, Error>>,
+}
+
+impl<Item, Error> Amb<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn with_source<Observable, Cancellable>(mut self, observable: Observable) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move { observable.subscribe(AmbObserver { shared, index }).await })
+        }));
+        self
+    }
+
+    pub fn amb_all<Observable, Cancellable>(sources: Vec<Observable>) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        sources
+            .into_iter()
+            .fold(Self { sources: vec![] }, |amb, source| {
+                amb.with_source(source)
+            })
+    }
+
+    pub fn amb2<ObservableA, CancellableA, ObservableB, CancellableB>(
+        a: ObservableA,
+        b: ObservableB,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, Item, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, Item, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::amb_all::<ObservableA, CancellableA>(vec![])
+            .with_source(a)
+            .with_source(b)
+    }
+}
+
+/// Returns an [`Observable`] that mirrors the first of all `sources` to emit
+/// an item, an error or its completion and cancels all other sources.
+///
+/// [`Observable`]: ../../core/trait.Observable.html
+pub fn amb<Observable, Cancellable, Item, Error>(sources: Vec<Observable>) -> Amb<Item, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Amb::amb_all(sources)
+}
+
+#[async_trait]
+impl<Item, Error> core::Observable<AmbCancellable<Item, Error>, Item, Error> for Amb<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<AmbCancellable<Item, Error>, Item, Error> + Send + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            observer: Mutex::new(None),
+            state: Mutex::new(State {
+                cancellables: (0..count).map(|_| None).collect(),
+                winner: None,
+                is_cancelled: false,
+            }),
+        });
+        observer
+            .on_subscribe(AmbCancellable {
+                shared: shared.clone(),
+            })
+            .await;
+        if count == 0 {
+            observer.on_completed().await;
+            return;
+        }
+        *shared.observer.lock().await = Some(Box::new(observer));
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Item, Error> {
+    observer: Mutex<Option<BoxedObserver<Item, Error>>>,
+    state: Mutex<State>,
+}
+
+struct State {
+    cancellables: Vec<Option<BoxedCancel>>,
+    winner: Option<usize>,
+    is_cancelled: bool,
+}
+
+impl<Item, Error> Shared<Item, Error> {
+    /// Returns whether the source at `index` is the winner, deciding it to be
+    /// if there is none yet and cancelling all other sources in that case.
+    async fn win(&self, index: usize) -> bool {
+        let losers: Vec<BoxedCancel> = {
+            let mut state = self.state.lock().await;
+            match state.winner {
+                Some(winner) => return winner == index,
+                None => state.winner = Some(index),
+            }
+            state
+                .cancellables
+                .iter_mut()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .filter_map(|(_, cancellable)| cancellable.take())
+                .collect()
+        };
+        for cancel in losers {
+            cancel().await;
+        }
+        true
+    }
+}
+
+struct AmbObserver<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+    index: usize,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Observer<Cancellable, Item, Error> for AmbObserver<Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancel: BoxedCancel = Arc::new(move || {
+            let cancellable = cancellable.clone();
+            Box::pin(async move { cancellable.cancel().await })
+        });
+        let is_loser = {
+            let mut state = self.shared.state.lock().await;
+            let is_loser =
+                state.is_cancelled || matches!(state.winner, Some(winner) if winner != self.index);
+            if !is_loser {
+                state.cancellables[self.index] = Some(cancel.clone());
+            }
+            is_loser
+        };
+        if is_loser {
+            cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.shared.win(self.index).await {
+            if let Some(observer) = self.shared.observer.lock().await.as_mut() {
+                observer.on_next(item).await;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if self.shared.win(self.index).await {
+            let observer = self.shared.observer.lock().await.take();
+            if let Some(mut observer) = observer {
+                observer.on_error(error).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if self.shared.win(self.index).await {
+            let observer = self.shared.observer.lock().await.take();
+            if let Some(mut observer) = observer {
+                observer.on_completed().await;
+            }
+        }
+    }
+}
+
+pub struct AmbCancellable<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+}
+
+impl<Item, Error> Clone for AmbCancellable<Item, Error> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Item, Error> core::Cancellable for AmbCancellable<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        let cancellables: Vec<BoxedCancel> = {
+            let mut state = self.shared.state.lock().await;
+            state.is_cancelled = true;
+            state
+                .cancellables
+                .iter_mut()
+                .filter_map(Option::take)
+                .collect()
+        };
+        for cancel in cancellables {
+            cancel().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn amb() {
+        let test_observer = TestObserver::default();
+        let a = TestObservable::default().annotate_error_type(());
+        let b = TestObservable::default();
+        operators::amb(vec![a.clone(), b.clone()])
+            .subscribe(test_observer.clone())
+            .await;
+        b.emit(0).await;
+        assert!(a.is_cancelled().await);
+        b.emit(1).await;
+        b.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1]);
+    }
+
+    #[async_std::test]
+    async fn amb_with() {
+        let test_observer = TestObserver::default();
+        vec![0, 1]
+            .into_observable()
+            .amb_with(vec![2, 3].into_observable())
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1]);
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_observer = TestObserver::default();
+        let a = TestObservable::default().annotate_item_type(0);
+        let b = TestObservable::default().annotate_item_type(0);
+        a.clone()
+            .amb_with(b.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        a.emit_error(()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.error().await, Some(()));
+        assert!(b.is_cancelled().await);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::core;
+use async_trait::async_trait;
+
+/// The predicate of operators such as `contains` and `is_empty`.
+pub type BoxedPredicate<Item> = Box<dyn FnMut(&Item) -> bool + Send>;
+
+#[operator(type = "observable", item = "bool")]
+pub struct Any<PredicateFn>
+where
+    PredicateFn: FnMut(&Item) -> bool + Send,
+{
+    predicate_fn: PredicateFn,
+    until: bool,
+}
+
+/// Emits `until` as soon as `predicate_fn` returns `until` for an item and
+/// `!until` if the upstream completes before.
+struct AnyObserver<Observer, Cancellable, PredicateFn> {
+    observer: Option<Observer>,
+    upstream: Option<Cancellable>,
+    predicate_fn: PredicateFn,
+    until: bool,
+}
+
+impl<Observer, Cancellable, PredicateFn> AnyObserver<Observer, Cancellable, PredicateFn> {
+    fn new(observer: Observer, predicate_fn: PredicateFn, until: bool) -> Self {
+        Self {
+            observer: Some(observer),
+            upstream: None,
+            predicate_fn,
+            until,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, PredicateFn> core::Observer<Cancellable, Item, Error>
+    for AnyObserver<Observer, Cancellable, PredicateFn>
+where
+    Observer: core::Observer<Cancellable, bool, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    PredicateFn: FnMut(&Item) -> bool + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.upstream = Some(cancellable.clone());
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_subscribe(cancellable).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.observer.is_none() || (self.predicate_fn)(&item) != self.until {
+            return;
+        }
+        if let Some(upstream) = self.upstream.take() {
+            upstream.cancel().await;
+        }
+        if let Some(mut observer) = self.observer.take() {
+            observer.on_next(self.until).await;
+            observer.on_completed().await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if let Some(mut observer) = self.observer.take() {
+            observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(mut observer) = self.observer.take() {
+            observer.on_next(!self.until).await;
+            observer.on_completed().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn contains() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .contains(1)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![true]);
+    }
+
+    #[async_std::test]
+    async fn all() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2]
+            .into_observable()
+            .all(|item| *item < 3)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.items().await, vec![true]);
+    }
+
+    #[async_std::test]
+    async fn is_empty() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .is_empty()
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await, vec![false]);
+    }
+}
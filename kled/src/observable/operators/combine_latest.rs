@@ -0,0 +1,670 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The latest items of the sources of an operator such as
+/// [`Observable::combine_latest`], which are combined into a single item. Each
+/// source pushes into its own typed queue of
+/// [`Queues`](CombineLatestItems::Queues) and its latest item is kept in
+/// [`Partial`](CombineLatestItems::Partial) until every source has emitted an
+/// item.
+///
+/// [`Observable::combine_latest`]: ../../core/trait.Observable.html#method.combine_latest
+pub trait CombineLatestItems: Sized + Send + 'static {
+    type Queues: Send + 'static;
+    type Partial: Send + 'static;
+
+    /// Returns whether the source at `index` has not emitted an item yet.
+    fn is_missing(partial: &Self::Partial, index: usize) -> bool;
+
+    /// Moves the next queued item of the source at `index` into `partial` or,
+    /// once every source has emitted an item, into `latest`.
+    fn update(
+        queues: &mut Self::Queues,
+        partial: &mut Self::Partial,
+        latest: &mut Option<Self>,
+        index: usize,
+    );
+}
+
+impl<Item> CombineLatestItems for Vec<Item>
+where
+    Item: Send + 'static,
+{
+    type Queues = Vec<VecDeque<Item>>;
+    type Partial = Vec<Option<Item>>;
+
+    fn is_missing(partial: &Self::Partial, index: usize) -> bool {
+        partial[index].is_none()
+    }
+
+    fn update(
+        queues: &mut Self::Queues,
+        partial: &mut Self::Partial,
+        latest: &mut Option<Self>,
+        index: usize,
+    ) {
+        let item = match queues[index].pop_front() {
+            Some(item) => item,
+            None => return,
+        };
+        match latest.as_mut() {
+            Some(latest) => latest[index] = item,
+            None => {
+                partial[index] = Some(item);
+                if partial.iter().all(Option::is_some) {
+                    *latest = Some(partial.iter_mut().filter_map(Option::take).collect());
+                }
+            }
+        }
+    }
+}
+
+macro_rules! combine_latest_items {
+    ($($item:ident $name:ident $index:tt),+) => {
+        impl<$($item),+> CombineLatestItems for ($($item,)+)
+        where
+            $($item: Send + 'static,)+
+        {
+            type Queues = ($(VecDeque<$item>,)+);
+            type Partial = ($(Option<$item>,)+);
+
+            fn is_missing(partial: &Self::Partial, index: usize) -> bool {
+                match index {
+                    $($index => partial.$index.is_none(),)+
+                    _ => false,
+                }
+            }
+
+            fn update(
+                queues: &mut Self::Queues,
+                partial: &mut Self::Partial,
+                latest: &mut Option<Self>,
+                index: usize,
+            ) {
+                match index {
+                    $($index => match (queues.$index.pop_front(), latest.as_mut()) {
+                        (Some(item), Some(latest)) => latest.$index = item,
+                        (Some(item), None) => partial.$index = Some(item),
+                        (None, _) => return,
+                    },)+
+                    _ => return,
+                }
+                if latest.is_none() {
+                    if $(partial.$index.is_some())&&+ {
+                        if let ($(Some($name),)+) = std::mem::take(partial) {
+                            *latest = Some(($($name,)+));
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+combine_latest_items!(A a 0, B b 1);
+combine_latest_items!(A a 0, B b 1, C c 2);
+combine_latest_items!(A a 0, B b 1, C c 2, D d 3);
+
+type BoxedSource<Items, Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Items, Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedObserver<Items, Item, Error> = Box<
+    dyn core::Observer<CombineLatestCancellable<Items, Item, Error>, Item, Error> + Send + 'static,
+>;
+
+type BoxedCancel = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+type Combiner<Items, Item> = Box<dyn FnMut(&Items) -> Item + Send>;
+
+/// Combines the latest items of multiple sources. An item is emitted for each
+/// item of any source once every source has emitted at least one item.
+pub struct CombineLatest<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    sources: Vec<BoxedSource<Items, Item, Error>>,
+    queues: Items::Queues,
+    partial: Items::Partial,
+    combiner: Combiner<Items, Item>,
+    complete_on_first: bool,
+}
+
+/// A [`CombineLatest`] of a fixed number of sources, such as
+/// [`Observable::combine_latest`], which emits the latest items of its sources
+/// as tuples.
+///
+/// [`Observable::combine_latest`]: ../../core/trait.Observable.html#method.combine_latest
+pub type CombineLatestTuple<Items, Error> = CombineLatest<Items, Items, Error>;
+
+impl<Items, Item, Error> CombineLatest<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn new<CombineFn>(queues: Items::Queues, partial: Items::Partial, combiner: CombineFn) -> Self
+    where
+        CombineFn: FnMut(&Items) -> Item + Send + 'static,
+    {
+        Self {
+            sources: vec![],
+            queues,
+            partial,
+            combiner: Box::new(combiner),
+            complete_on_first: false,
+        }
+    }
+
+    fn with_source<Observable, Cancellable, SourceItem, Push>(
+        mut self,
+        observable: Observable,
+        push: Push,
+    ) -> Self
+    where
+        Observable: core::Observable<Cancellable, SourceItem, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+        SourceItem: Send + 'static,
+        Push: FnMut(&mut Items::Queues, SourceItem) + Send + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move {
+                observable
+                    .subscribe(CombineLatestObserver {
+                        shared,
+                        index,
+                        push,
+                        phantom: PhantomData,
+                    })
+                    .await
+            })
+        }));
+        self
+    }
+
+    /// Completes as soon as the first source completed and all of its items
+    /// were emitted instead of waiting for the completion of all sources.
+    pub fn complete_on_first(mut self) -> Self {
+        self.complete_on_first = true;
+        self
+    }
+}
+
+impl<SourceItem, Item, Error> CombineLatest<Vec<SourceItem>, Item, Error>
+where
+    SourceItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest_all<Observable, Cancellable, CombineFn>(
+        sources: Vec<Observable>,
+        mut combiner: CombineFn,
+    ) -> Self
+    where
+        Observable: core::Observable<Cancellable, SourceItem, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+        CombineFn: FnMut(&[SourceItem]) -> Item + Send + 'static,
+    {
+        let queues = sources.iter().map(|_| VecDeque::new()).collect();
+        let partial = sources.iter().map(|_| None).collect();
+        let combine_latest = Self::new(queues, partial, move |items: &Vec<SourceItem>| {
+            combiner(items)
+        });
+        sources
+            .into_iter()
+            .enumerate()
+            .fold(combine_latest, |combine_latest, (index, source)| {
+                combine_latest
+                    .with_source(source, move |queues, item| queues[index].push_back(item))
+            })
+    }
+}
+
+impl<A, B, Error> CombineLatest<(A, B), (A, B), Error>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest2<ObservableA, CancellableA, ObservableB, CancellableB>(
+        a: ObservableA,
+        b: ObservableB,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, A, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), Default::default(), Clone::clone)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+    }
+}
+
+impl<A, B, C, Error> CombineLatest<(A, B, C), (A, B, C), Error>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+    C: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest3<
+        ObservableA,
+        CancellableA,
+        ObservableB,
+        CancellableB,
+        ObservableC,
+        CancellableC,
+    >(
+        a: ObservableA,
+        b: ObservableB,
+        c: ObservableC,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, A, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), Default::default(), Clone::clone)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+    }
+}
+
+impl<A, B, C, D, Error> CombineLatest<(A, B, C, D), (A, B, C, D), Error>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+    C: Clone + Send + 'static,
+    D: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn combine_latest4<
+        ObservableA,
+        CancellableA,
+        ObservableB,
+        CancellableB,
+        ObservableC,
+        CancellableC,
+        ObservableD,
+        CancellableD,
+    >(
+        a: ObservableA,
+        b: ObservableB,
+        c: ObservableC,
+        d: ObservableD,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, A, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+        ObservableD: core::Observable<CancellableD, D, Error> + Send + 'static,
+        CancellableD: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), Default::default(), Clone::clone)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+            .with_source(d, |queues, item| queues.3.push_back(item))
+    }
+}
+
+/// Returns an [`Observable`] that applies `combiner` to the latest items of all
+/// `sources` whenever any of them emits an item, once every source has
+/// emitted at least one item.
+///
+/// [`Observable`]: ../../core/trait.Observable.html
+pub fn combine_latest_all<Observable, Cancellable, Item, Error, ItemOut, CombineFn>(
+    sources: Vec<Observable>,
+    combiner: CombineFn,
+) -> CombineLatest<Vec<Item>, ItemOut, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ItemOut: Send + 'static,
+    CombineFn: FnMut(&[Item]) -> ItemOut + Send + 'static,
+{
+    CombineLatest::combine_latest_all(sources, combiner)
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Observable<CombineLatestCancellable<Items, Item, Error>, Item, Error>
+    for CombineLatest<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<CombineLatestCancellable<Items, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            observer: Mutex::new(None),
+            cancellables: Mutex::new((0..count).map(|_| None).collect()),
+            state: Mutex::new(State {
+                combiner: self.combiner,
+                order: VecDeque::new(),
+                queues: self.queues,
+                partial: self.partial,
+                latest: None,
+                done: vec![false; count],
+                complete_on_first: self.complete_on_first,
+                error: None,
+                is_cancelled: false,
+                is_terminated: false,
+            }),
+            wip: AtomicUsize::new(0),
+        });
+        observer
+            .on_subscribe(CombineLatestCancellable {
+                shared: shared.clone(),
+            })
+            .await;
+        *shared.observer.lock().await = Some(Box::new(observer));
+        drain(&shared).await;
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    observer: Mutex<Option<BoxedObserver<Items, Item, Error>>>,
+    cancellables: Mutex<Vec<Option<BoxedCancel>>>,
+    state: Mutex<State<Items, Item, Error>>,
+    wip: AtomicUsize,
+}
+
+struct State<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    combiner: Combiner<Items, Item>,
+    /// The indices of the sources of all queued items in emission order.
+    order: VecDeque<usize>,
+    queues: Items::Queues,
+    partial: Items::Partial,
+    latest: Option<Items>,
+    done: Vec<bool>,
+    complete_on_first: bool,
+    error: Option<Error>,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Items, Item, Error> State<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    fn is_completed(&self) -> bool {
+        if !self.order.is_empty() {
+            return false;
+        }
+        let is_any_done = self.done.iter().any(|done| *done);
+        let is_starved = self.latest.is_none()
+            && self
+                .done
+                .iter()
+                .enumerate()
+                .any(|(index, done)| *done && Items::is_missing(&self.partial, index));
+        self.done.iter().all(|done| *done) || (self.complete_on_first && is_any_done) || is_starved
+    }
+
+    fn update(&mut self, index: usize) -> Option<Item> {
+        Items::update(&mut self.queues, &mut self.partial, &mut self.latest, index);
+        let combiner = &mut self.combiner;
+        self.latest.as_ref().map(combiner)
+    }
+}
+
+impl<Items, Item, Error> Shared<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    async fn cancel_sources(&self) {
+        let cancellables: Vec<_> = self
+            .cancellables
+            .lock()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect();
+        for cancel in cancellables {
+            cancel().await;
+        }
+    }
+}
+
+async fn drain<Items, Item, Error>(shared: &Shared<Items, Item, Error>)
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut observer = shared.observer.lock().await;
+        let mut is_terminated = false;
+        if let Some(downstream) = observer.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    state.is_terminated = true;
+                    is_terminated = true;
+                    break;
+                }
+                if let Some(error) = state.error.take() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_error(error).await;
+                    is_terminated = true;
+                    break;
+                }
+                if state.is_completed() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_completed().await;
+                    is_terminated = true;
+                    break;
+                }
+                let item = match state.order.pop_front() {
+                    Some(index) => state.update(index),
+                    None => break,
+                };
+                drop(state);
+                if let Some(item) = item {
+                    downstream.on_next(item).await;
+                }
+            }
+        }
+        if is_terminated {
+            *observer = None;
+        }
+        drop(observer);
+        if is_terminated {
+            shared.cancel_sources().await;
+        }
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+struct CombineLatestObserver<Items, Item, Error, SourceItem, Push>
+where
+    Items: CombineLatestItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+    index: usize,
+    push: Push,
+    phantom: PhantomData<SourceItem>,
+}
+
+#[async_trait]
+impl<Cancellable, Items, Item, Error, SourceItem, Push>
+    core::Observer<Cancellable, SourceItem, Error>
+    for CombineLatestObserver<Items, Item, Error, SourceItem, Push>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SourceItem: Send + 'static,
+    Push: FnMut(&mut Items::Queues, SourceItem) + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancel: BoxedCancel = Arc::new(move || {
+            let cancellable = cancellable.clone();
+            Box::pin(async move { cancellable.cancel().await })
+        });
+        self.shared.cancellables.lock().await[self.index] = Some(cancel.clone());
+        if self.shared.state.lock().await.is_terminated {
+            cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, item: SourceItem) {
+        let mut state = self.shared.state.lock().await;
+        (self.push)(&mut state.queues, item);
+        state.order.push_back(self.index);
+        drop(state);
+        drain(&self.shared).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.error.is_none() {
+                state.error = Some(error);
+            }
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.done[self.index] = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct CombineLatestCancellable<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+}
+
+impl<Items, Item, Error> Clone for CombineLatestCancellable<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+{
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Cancellable for CombineLatestCancellable<Items, Item, Error>
+where
+    Items: CombineLatestItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.state.lock().await.is_cancelled = true;
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn combine_latest() {
+        let test_observer = TestObserver::default();
+        let a = TestObservable::default().annotate_error_type(());
+        let b = TestObservable::default();
+        a.clone()
+            .combine_latest(b.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        a.emit_all(vec![0, 1]).await;
+        b.emit("a").await;
+        a.emit(2).await;
+        a.emit_on_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Subscribed);
+        b.emit("b").await;
+        b.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(
+            test_observer.items().await,
+            vec![(1, "a"), (2, "a"), (2, "b")]
+        );
+    }
+
+    #[async_std::test]
+    async fn complete_on_first() {
+        let test_observer = TestObserver::default();
+        let b = TestObservable::default();
+        vec![0]
+            .into_observable()
+            .combine_latest(b.clone())
+            .complete_on_first()
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, Vec::<(i32, i32)>::new());
+        assert!(b.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn combine_latest_all() {
+        let test_observer = TestObserver::default();
+        operators::combine_latest_all(
+            vec![vec![1, 2].into_observable(), vec![10].into_observable()],
+            |items| items.iter().sum::<i32>(),
+        )
+        .subscribe(test_observer.clone())
+        .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![12]);
+    }
+}
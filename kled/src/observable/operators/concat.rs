@@ -0,0 +1,292 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedSource<Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedObserver<Item, Error> =
+    Box<dyn core::Observer<ConcatCancellable<Item, Error>, Item, Error> + Send + 'static>;
+
+type BoxedCancel = Box<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Emits the items of multiple sources one after another. Each source is
+/// only subscribed to after the previous one completed.
+pub struct Concat<Item, Error> {
+    sources: VecDeque<BoxedSource<Item, Error>>,
+}
+
+impl<Item, Error> Concat<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn with_source<Observable, Cancellable>(mut self, observable: Observable) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        self.sources.push_back(Box::new(move |shared| {
+            Box::pin(async move { observable.subscribe(ConcatObserver { shared }).await })
+        }));
+        self
+    }
+
+    pub fn concat_all<Observable, Cancellable>(sources: Vec<Observable>) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        sources.into_iter().fold(
+            Self {
+                sources: VecDeque::new(),
+            },
+            |concat, source| concat.with_source(source),
+        )
+    }
+
+    pub fn concat2<ObservableA, CancellableA, ObservableB, CancellableB>(
+        a: ObservableA,
+        b: ObservableB,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, Item, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, Item, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::concat_all::<ObservableA, CancellableA>(vec![])
+            .with_source(a)
+            .with_source(b)
+    }
+}
+
+/// Returns an [`Observable`] that emits the items of all `sources` one after
+/// another, subscribing to each source after the previous one completed.
+///
+/// [`Observable`]: ../../core/trait.Observable.html
+pub fn concat<Observable, Cancellable, Item, Error>(sources: Vec<Observable>) -> Concat<Item, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Concat::concat_all(sources)
+}
+
+#[async_trait]
+impl<Item, Error> core::Observable<ConcatCancellable<Item, Error>, Item, Error>
+    for Concat<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<ConcatCancellable<Item, Error>, Item, Error> + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            observer: Mutex::new(None),
+            sources: Mutex::new(self.sources),
+            state: Mutex::new(State {
+                current: None,
+                is_cancelled: false,
+            }),
+            wip: AtomicUsize::new(0),
+        });
+        observer
+            .on_subscribe(ConcatCancellable {
+                shared: shared.clone(),
+            })
+            .await;
+        *shared.observer.lock().await = Some(Box::new(observer));
+        subscribe_next(shared).await;
+    }
+}
+
+struct Shared<Item, Error> {
+    observer: Mutex<Option<BoxedObserver<Item, Error>>>,
+    sources: Mutex<VecDeque<BoxedSource<Item, Error>>>,
+    state: Mutex<State>,
+    wip: AtomicUsize,
+}
+
+struct State {
+    current: Option<Arc<BoxedCancel>>,
+    is_cancelled: bool,
+}
+
+/// Subscribes to the next source or completes the downstream observer if
+/// there is none left. Sources which complete during their subscription are
+/// followed up iteratively instead of recursively, so that the stack stays
+/// shallow regardless of the number of sources.
+async fn subscribe_next<Item, Error>(shared: Arc<Shared<Item, Error>>)
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    loop {
+        if shared.state.lock().await.is_cancelled {
+            shared.sources.lock().await.clear();
+            *shared.observer.lock().await = None;
+            return;
+        }
+        let source = shared.sources.lock().await.pop_front();
+        match source {
+            Some(source) => source(shared.clone()).await,
+            None => {
+                let observer = shared.observer.lock().await.take();
+                if let Some(mut observer) = observer {
+                    observer.on_completed().await;
+                }
+                return;
+            }
+        }
+        if shared.wip.fetch_sub(1, Ordering::SeqCst) == 1 {
+            return;
+        }
+    }
+}
+
+struct ConcatObserver<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Observer<Cancellable, Item, Error>
+    for ConcatObserver<Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancel: BoxedCancel = Box::new(move || {
+            let cancellable = cancellable.clone();
+            Box::pin(async move { cancellable.cancel().await })
+        });
+        let cancel = Arc::new(cancel);
+        let is_cancelled = {
+            let mut state = self.shared.state.lock().await;
+            state.current = Some(cancel.clone());
+            state.is_cancelled
+        };
+        if is_cancelled {
+            cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(observer) = self.shared.observer.lock().await.as_mut() {
+            observer.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.shared.sources.lock().await.clear();
+        let observer = self.shared.observer.lock().await.take();
+        if let Some(mut observer) = observer {
+            observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.current = None;
+        subscribe_next(self.shared.clone()).await;
+    }
+}
+
+pub struct ConcatCancellable<Item, Error> {
+    shared: Arc<Shared<Item, Error>>,
+}
+
+impl<Item, Error> Clone for ConcatCancellable<Item, Error> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Item, Error> core::Cancellable for ConcatCancellable<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        let current = {
+            let mut state = self.shared.state.lock().await;
+            state.is_cancelled = true;
+            state.current.take()
+        };
+        if let Some(cancel) = current {
+            cancel().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn concat_with() {
+        let test_observer = TestObserver::default();
+        let live = TestObservable::default();
+        vec![0, 1]
+            .into_observable()
+            .concat_with(live.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        live.emit(2).await;
+        live.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn many_sources() {
+        let test_observer = TestObserver::default();
+        operators::concat(
+            (0..10000)
+                .map(|item| vec![item].into_observable())
+                .collect(),
+        )
+        .subscribe(test_observer.clone())
+        .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, (0..10000).collect::<Vec<_>>());
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_observer = TestObserver::default();
+        let a = TestObservable::default()
+            .annotate_item_type(0)
+            .annotate_error_type(());
+        let b = TestObservable::default();
+        operators::concat(vec![a.clone(), b.clone()])
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+        assert!(a.is_cancelled().await);
+        a.emit_on_completed().await;
+
+        assert!(!b.has_observer().await);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::core;
+use crate::scheduler::{unbounded, DelaySender};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::prelude::*;
+
+#[chronobreak]
+mod mock {
+    pub use std::sync::atomic::{AtomicBool, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+pub type DebounceFn<Item> = Box<dyn FnMut(&Item) -> Duration + Send>;
+
+#[operator(type = "observable", subscription = "DebounceCancellable<Cancellable>")]
+pub struct Debounce<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration_fn: DebounceFn<Item>,
+    scheduler: Scheduler,
+}
+
+pub struct DebounceObserver<Observer, Item, Scheduler> {
+    data: Arc<Mutex<Data<Observer, Item>>>,
+    cancelled: Arc<AtomicBool>,
+    duration_fn: DebounceFn<Item>,
+    scheduler: Scheduler,
+}
+
+struct Data<Observer, Item> {
+    observer: Observer,
+    sender: Option<DelaySender<usize>>,
+    pending: Option<Item>,
+    index: usize,
+    is_done: bool,
+}
+
+impl<Observer, Item, Scheduler> DebounceObserver<Observer, Item, Scheduler> {
+    pub fn new(observer: Observer, duration_fn: DebounceFn<Item>, scheduler: Scheduler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                observer,
+                sender: None,
+                pending: None,
+                index: 0,
+                is_done: false,
+            })),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            duration_fn,
+            scheduler,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for DebounceObserver<Observer, Item, Scheduler>
+where
+    Observer: core::Observer<DebounceCancellable<Cancellable>, Item, Error> + Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.data
+            .lock()
+            .await
+            .observer
+            .on_subscribe(DebounceCancellable::new(
+                cancellable,
+                self.cancelled.clone(),
+            ))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        let deadline = self.scheduler.now() + (self.duration_fn)(&item);
+        data.pending = Some(item);
+        data.index += 1;
+        // The timer task runs until the latest item was emitted and ignores
+        // the timers of all items that were superseded in the meantime.
+        if data.sender.is_none() {
+            let (sender, mut receiver) = unbounded();
+            data.sender = Some(sender);
+            let task_data = self.data.clone();
+            let cancelled = self.cancelled.clone();
+            self.scheduler.schedule(async move {
+                while let Some(index) = receiver.next().await {
+                    let mut data = task_data.lock().await;
+                    if data.is_done || cancelled.load(Ordering::Relaxed) {
+                        data.sender = None;
+                        break;
+                    }
+                    if index != data.index {
+                        continue;
+                    }
+                    data.sender = None;
+                    if let Some(item) = data.pending.take() {
+                        data.observer.on_next(item).await;
+                    }
+                    break;
+                }
+            });
+        }
+        let index = data.index;
+        if let Some(sender) = data.sender.as_mut() {
+            if sender.send_at(deadline, index).await.is_err() {
+                data.sender = None;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        data.sender = None;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        data.is_done = true;
+        data.sender = None;
+        if let Some(item) = data.pending.take() {
+            data.observer.on_next(item).await;
+        }
+        data.observer.on_completed().await;
+    }
+}
+
+#[derive(new, Clone)]
+pub struct DebounceCancellable<Upstream> {
+    upstream: Upstream,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for DebounceCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.upstream.cancel().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+
+    #[chronobreak::test]
+    async fn debounce() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .debounce(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        assert_eq!(test_observer.items().await, vec![1]);
+        test_observable.emit(2).await;
+        test_observable.emit_on_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn debounce_with() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .debounce_with(
+                |item| Duration::from_millis(if *item == 0 { 100 } else { 10 }),
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        assert_eq!(test_observer.items().await, vec![1]);
+        test_observable.emit(2).await;
+        scheduler.join();
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn debounce_with_shorter_duration() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .debounce_with(
+                |item| Duration::from_millis(if *item == 0 { 100 } else { 5 }),
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        let start = Instant::now();
+        test_observable.emit(0).await;
+        clock::advance(Duration::from_millis(10));
+        test_observable.emit(1).await;
+        scheduler.join();
+
+        // The later item is emitted after its own 5ms, the timer of the
+        // superseded one is not waited for.
+        assert_eq!(test_observer.items().await, vec![1]);
+        assert!(Instant::now() - start < Duration::from_millis(100));
+    }
+
+    #[chronobreak::test]
+    async fn burst() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .debounce(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(0..1000).await;
+        scheduler.join();
+        assert_eq!(test_observer.items().await, vec![999]);
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![999]);
+    }
+
+    #[chronobreak::test]
+    async fn cancel() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .debounce(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observer.cancel().await;
+        scheduler.join();
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await, Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::observable::operators::{Dematerialize, Materialize};
+use crate::observable::Signal;
+use crate::observer::ScheduledObserver;
+use crate::{core, Never};
+use async_trait::async_trait;
+#[chronobreak]
+use std::time::*;
+
+#[operator(
+    type = "observable",
+    subscriber = "DelayObserver",
+    upstream_subscription = "Never",
+    upstream_item = "Signal<Cancellable, Item, Error>",
+    upstream_error = "Never",
+    subscription = "Never",
+    item = "Signal<Cancellable, Item, Error>",
+    error = "Never"
+)]
+pub struct DelayRaw<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+    delay_error: bool,
+}
+
+pub type Delay<Upstream, Cancellable, Item, Error, Scheduler> = Dematerialize<
+    DelayRaw<Materialize<Upstream, Cancellable, Item, Error>, Cancellable, Item, Error, Scheduler>,
+    Cancellable,
+    Item,
+    Error,
+>;
+
+pub struct DelayObserver<Cancellable, Item, Error> {
+    scheduled: ScheduledObserver<Cancellable, Item, Error>,
+    duration: Duration,
+    delay_error: bool,
+}
+
+impl<Cancellable, Item, Error> DelayObserver<Cancellable, Item, Error> {
+    pub fn new<Observer, Scheduler>(
+        observer: Observer,
+        duration: Duration,
+        scheduler: Scheduler,
+        delay_error: bool,
+    ) -> Self
+    where
+        Cancellable: Send + 'static,
+        Item: Send + 'static,
+        Error: Send + 'static,
+        Observer: core::Observer<Never, Signal<Cancellable, Item, Error>, Never> + Send + 'static,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        Self {
+            scheduled: ScheduledObserver::new(observer, scheduler),
+            duration,
+            delay_error,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Observer<Never, Signal<Cancellable, Item, Error>, Never>
+    for DelayObserver<Cancellable, Item, Error>
+where
+    Cancellable: Send,
+    Item: Send,
+    Error: Send,
+{
+    async fn on_subscribe(&mut self, _: Never) {
+        unreachable! {};
+    }
+
+    async fn on_next(&mut self, signal: Signal<Cancellable, Item, Error>) {
+        if signal.is_subscribe() || (signal.is_error() && !self.delay_error) {
+            self.scheduled.on_next(signal).await;
+        } else {
+            self.scheduled.on_next_delayed(self.duration, signal).await;
+        }
+    }
+
+    async fn on_error(&mut self, _: Never) {
+        unreachable! {};
+    }
+
+    async fn on_completed(&mut self) {
+        self.scheduled.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn delay() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let start = Instant::now();
+        vec![0, 1, 2]
+            .into_observable()
+            .delay(Duration::from_millis(10), scheduler.clone(), true)
+            .subscribe(test_observer.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() >= start + Duration::from_millis(10));
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn delay_error() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default();
+        test_observable
+            .clone()
+            .delay(Duration::from_millis(10), scheduler.clone(), true)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(()).await;
+        scheduler.join();
+
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert_eq!(test_observer.error().await, Some(()));
+    }
+
+    #[chronobreak::test]
+    async fn immediate_error() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default();
+        test_observable
+            .clone()
+            .delay(Duration::from_millis(10), scheduler.clone(), false)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(()).await;
+        scheduler.join();
+
+        assert_eq!(test_observer.items().await, Vec::<i32>::new());
+        assert_eq!(test_observer.error().await, Some(()));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::cancellable::*;
+use crate::core;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[derive(new)]
+pub struct DelaySubscription<Observable, Cancellable, Item, Error, Scheduler> {
+    observable: Observable,
+    duration: Duration,
+    scheduler: Scheduler,
+    phantom: PhantomData<(Cancellable, Item, Error)>,
+}
+
+#[async_trait]
+impl<Observable, Cancellable, Item, Error, Scheduler>
+    core::Observable<LazyCancellable<Cancellable>, Item, Error>
+    for DelaySubscription<Observable, Cancellable, Item, Error, Scheduler>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<LazyCancellable<Cancellable>, Item, Error> + Send + 'static,
+    {
+        let stub = LazyCancellableStub::default();
+        observer.on_subscribe(stub.cancellable()).await;
+        let observable = self.observable;
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            Delay::new(duration).await;
+            // The upstream is not subscribed to at all once the downstream
+            // cancelled during the delay.
+            if stub.is_cancelled().await {
+                return;
+            }
+            observable
+                .subscribe(DelaySubscriptionObserver { observer, stub })
+                .await;
+        });
+    }
+}
+
+struct DelaySubscriptionObserver<Observer, Cancellable> {
+    observer: Observer,
+    stub: LazyCancellableStub<Cancellable>,
+}
+
+#[async_trait]
+impl<Observer, Cancellable, Item, Error> core::Observer<Cancellable, Item, Error>
+    for DelaySubscriptionObserver<Observer, Cancellable>
+where
+    Observer: core::Observer<LazyCancellable<Cancellable>, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.stub.set_cancellable(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn delay_subscription() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let start = Instant::now();
+        vec![0, 1, 2]
+            .into_observable()
+            .delay_subscription(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() >= start + Duration::from_millis(10));
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn cancel_during_delay() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(0)
+            .annotate_error_type(());
+        test_observable
+            .clone()
+            .delay_subscription(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+        scheduler.join();
+
+        assert!(!test_observable.has_observer().await);
+        assert!(test_observer.items().await.is_empty());
+    }
+}
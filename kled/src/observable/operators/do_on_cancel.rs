@@ -0,0 +1,122 @@
+use crate::core;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "observable",
+    subscription = "DoOnCancelCancellable<Cancellable, CancelFn>"
+)]
+pub struct DoOnCancel<CancelFn>
+where
+    CancelFn: Fn() + Send + Sync + 'static,
+{
+    cancel_fn: CancelFn,
+}
+
+struct DoOnCancelObserver<Observer, CancelFn> {
+    observer: Observer,
+    cancel_fn: Arc<CancelFn>,
+}
+
+impl<Observer, CancelFn> DoOnCancelObserver<Observer, CancelFn> {
+    fn new(observer: Observer, cancel_fn: CancelFn) -> Self {
+        Self {
+            observer,
+            cancel_fn: Arc::new(cancel_fn),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, CancelFn> core::Observer<Cancellable, Item, Error>
+    for DoOnCancelObserver<Observer, CancelFn>
+where
+    Observer: core::Observer<DoOnCancelCancellable<Cancellable, CancelFn>, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CancelFn: Fn() + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer
+            .on_subscribe(DoOnCancelCancellable::new(
+                cancellable,
+                self.cancel_fn.clone(),
+            ))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct DoOnCancelCancellable<Upstream, CancelFn> {
+    upstream: Upstream,
+    cancel_fn: Arc<CancelFn>,
+}
+
+impl<Upstream, CancelFn> Clone for DoOnCancelCancellable<Upstream, CancelFn>
+where
+    Upstream: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upstream: self.upstream.clone(),
+            cancel_fn: self.cancel_fn.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, CancelFn> core::Cancellable for DoOnCancelCancellable<Upstream, CancelFn>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    CancelFn: Fn() + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        (self.cancel_fn)();
+        self.upstream.cancel().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_cancel() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let cancellations = Arc::new(AtomicUsize::new(0));
+        let cancel_cancellations = cancellations.clone();
+        test_observable
+            .clone()
+            .do_on_cancel(move || {
+                cancel_cancellations.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(cancellations.load(Ordering::SeqCst), 0);
+        test_observer.cancel().await;
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(cancellations.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnComplete<CompleteFn>
+where
+    CompleteFn: FnMut() + Send,
+{
+    complete_fn: CompleteFn,
+}
+
+#[derive(new)]
+struct DoOnCompleteObserver<Observer, CompleteFn> {
+    observer: Observer,
+    complete_fn: CompleteFn,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, CompleteFn> core::Observer<Cancellable, Item, Error>
+    for DoOnCompleteObserver<Observer, CompleteFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CompleteFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        (self.complete_fn)();
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_complete() {
+        let test_observer = TestObserver::default();
+        let completions = Arc::new(AtomicUsize::new(0));
+        let complete_completions = completions.clone();
+        vec![0, 1]
+            .into_observable()
+            .do_on_complete(move || {
+                complete_completions.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(completions.load(Ordering::SeqCst), 1);
+    }
+}
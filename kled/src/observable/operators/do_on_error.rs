@@ -0,0 +1,71 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnError<ErrorFn>
+where
+    ErrorFn: FnMut(&Error) + Send,
+{
+    error_fn: ErrorFn,
+}
+
+#[derive(new)]
+struct DoOnErrorObserver<Observer, ErrorFn> {
+    observer: Observer,
+    error_fn: ErrorFn,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, ErrorFn> core::Observer<Cancellable, Item, Error>
+    for DoOnErrorObserver<Observer, ErrorFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorFn: FnMut(&Error) + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        (self.error_fn)(&error);
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn do_on_error() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(0);
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let error_errors = errors.clone();
+        test_observable
+            .clone()
+            .do_on_error(move |error| error_errors.lock().unwrap().push(*error))
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_error(0).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(*errors.lock().unwrap(), vec![0]);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnNext<NextFn>
+where
+    NextFn: FnMut(&Item) + Send,
+{
+    next_fn: NextFn,
+}
+
+#[derive(new)]
+struct DoOnNextObserver<Observer, NextFn> {
+    observer: Observer,
+    next_fn: NextFn,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, NextFn> core::Observer<Cancellable, Item, Error>
+    for DoOnNextObserver<Observer, NextFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NextFn: FnMut(&Item) + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        (self.next_fn)(&item);
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn do_on_next() {
+        let test_observer = TestObserver::default();
+        let items = Arc::new(Mutex::new(Vec::new()));
+        let next_items = items.clone();
+        vec![0, 1, 2]
+            .into_observable()
+            .do_on_next(move |item| next_items.lock().unwrap().push(*item))
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+        assert_eq!(*items.lock().unwrap(), vec![0, 1, 2]);
+    }
+}
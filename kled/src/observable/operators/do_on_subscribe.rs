@@ -0,0 +1,71 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnSubscribe<SubscribeFn>
+where
+    SubscribeFn: FnMut() + Send,
+{
+    subscribe_fn: SubscribeFn,
+}
+
+#[derive(new)]
+struct DoOnSubscribeObserver<Observer, SubscribeFn> {
+    observer: Observer,
+    subscribe_fn: SubscribeFn,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, SubscribeFn> core::Observer<Cancellable, Item, Error>
+    for DoOnSubscribeObserver<Observer, SubscribeFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SubscribeFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        (self.subscribe_fn)();
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_subscribe() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let subscribed = Arc::new(AtomicBool::new(false));
+        let subscribe_subscribed = subscribed.clone();
+        test_observable
+            .clone()
+            .do_on_subscribe(move || subscribe_subscribed.store(true, Ordering::SeqCst))
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert!(subscribed.load(Ordering::SeqCst));
+        assert_eq!(test_observer.status().await, ObserverStatus::Subscribed);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+
+#[operator(type = "observable", error = "observable::Error<Error>")]
+pub struct ElementAt {
+    index: usize,
+    default: Option<Item>,
+}
+
+struct ElementAtObserver<Observer, Cancellable, Item> {
+    observer: Observer,
+    upstream: Option<Cancellable>,
+    index: usize,
+    default: Option<Item>,
+    is_done: bool,
+}
+
+impl<Observer, Cancellable, Item> ElementAtObserver<Observer, Cancellable, Item> {
+    fn new(observer: Observer, index: usize, default: Option<Item>) -> Self {
+        Self {
+            observer,
+            upstream: None,
+            index,
+            default,
+            is_done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error> core::Observer<Cancellable, Item, Error>
+    for ElementAtObserver<Observer, Cancellable, Item>
+where
+    Observer: core::Observer<Cancellable, Item, observable::Error<Error>> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.upstream = Some(cancellable.clone());
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.is_done {
+            return;
+        }
+        if self.index > 0 {
+            self.index -= 1;
+            return;
+        }
+        self.is_done = true;
+        if let Some(upstream) = self.upstream.take() {
+            upstream.cancel().await;
+        }
+        self.observer.on_next(item).await;
+        self.observer.on_completed().await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if !self.is_done {
+            self.is_done = true;
+            self.observer
+                .on_error(observable::Error::Upstream(error))
+                .await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if !self.is_done {
+            self.is_done = true;
+            match self.default.take() {
+                Some(item) => {
+                    self.observer.on_next(item).await;
+                    self.observer.on_completed().await;
+                }
+                None => {
+                    self.observer
+                        .on_error(observable::Error::NoSuchElement)
+                        .await
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::{self, *};
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn first() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .first()
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert!(test_observable.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn first_or() {
+        let test_observer = TestObserver::default();
+        Vec::new()
+            .into_observable()
+            .first_or(1)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1]);
+    }
+
+    #[async_std::test]
+    async fn element_at() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let cancel_cancelled = cancelled.clone();
+        test_observable
+            .clone()
+            .do_on_cancel(move || cancel_cancelled.store(true, Ordering::SeqCst))
+            .element_at(2)
+            .do_on_complete(move || assert!(cancelled.load(Ordering::SeqCst)))
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1, 2]).await;
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![2]);
+    }
+
+    #[async_std::test]
+    async fn no_such_element() {
+        let test_observer = TestObserver::default();
+        vec![0, 1]
+            .into_observable()
+            .element_at(2)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::NoSuchElement)
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "observable",
+    subscription = "FinallyCancellable<Cancellable, FinallyFn>"
+)]
+pub struct Finally<FinallyFn>
+where
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    finally_fn: FinallyFn,
+}
+
+/// Holds the callback until it is taken by whichever of completion, error or
+/// cancellation happens first.
+struct Shared<FinallyFn> {
+    finally_fn: Mutex<Option<FinallyFn>>,
+}
+
+impl<FinallyFn> Shared<FinallyFn>
+where
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    async fn run(&self) {
+        let finally_fn = self.finally_fn.lock().await.take();
+        if let Some(finally_fn) = finally_fn {
+            finally_fn();
+        }
+    }
+}
+
+struct FinallyObserver<Observer, FinallyFn> {
+    observer: Observer,
+    shared: Arc<Shared<FinallyFn>>,
+}
+
+impl<Observer, FinallyFn> FinallyObserver<Observer, FinallyFn> {
+    fn new(observer: Observer, finally_fn: FinallyFn) -> Self {
+        Self {
+            observer,
+            shared: Arc::new(Shared {
+                finally_fn: Mutex::new(Some(finally_fn)),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, FinallyFn> core::Observer<Cancellable, Item, Error>
+    for FinallyObserver<Observer, FinallyFn>
+where
+    Observer: core::Observer<FinallyCancellable<Cancellable, FinallyFn>, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer
+            .on_subscribe(FinallyCancellable {
+                upstream: cancellable,
+                shared: self.shared.clone(),
+            })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+        self.shared.run().await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+        self.shared.run().await;
+    }
+}
+
+pub struct FinallyCancellable<Upstream, FinallyFn> {
+    upstream: Upstream,
+    shared: Arc<Shared<FinallyFn>>,
+}
+
+impl<Upstream, FinallyFn> Clone for FinallyCancellable<Upstream, FinallyFn>
+where
+    Upstream: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upstream: self.upstream.clone(),
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, FinallyFn> core::Cancellable for FinallyCancellable<Upstream, FinallyFn>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    FinallyFn: FnOnce() + Send + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.shared.run().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn finally_completed() {
+        let mut test_observer = TestObserver::default();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let finally_runs = runs.clone();
+        vec![0, 1]
+            .into_observable()
+            .finally(move || {
+                finally_runs.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        test_observer.cancel().await;
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn finally_cancelled() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let finally_runs = runs.clone();
+        test_observable
+            .clone()
+            .finally(move || {
+                finally_runs.fetch_add(1, Ordering::SeqCst);
+            })
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+        assert!(test_observable.is_cancelled().await);
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
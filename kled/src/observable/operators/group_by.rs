@@ -0,0 +1,529 @@
+use crate::cancellable::*;
+use crate::core;
+use crate::subject::UnicastSubject;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(
+    type = "observable",
+    subscription = "GroupByCancellable<Cancellable>",
+    item = "GroupedObservable<Key, Cancellable, Item, Error>"
+)]
+pub struct GroupBy<Key, KeyFn>
+where
+    Key: Clone + Eq + Hash,
+    KeyFn: FnMut(&Item) -> Key,
+    Error: Clone,
+{
+    key_fn: KeyFn,
+    eviction: Option<Eviction>,
+}
+
+/// The idle duration after which [`Observable::group_by_with_eviction`]
+/// completes a group, together with the clock and the timers of the scheduler
+/// it was given.
+///
+/// [`Observable::group_by_with_eviction`]: ../../core/trait.Observable.html#method.group_by_with_eviction
+pub struct Eviction {
+    duration: Duration,
+    now: Box<dyn Fn() -> Instant + Send + Sync>,
+    schedule: Box<dyn Fn(BoxFuture<'static, ()>) + Send + Sync>,
+}
+
+impl Eviction {
+    pub fn new<Scheduler>(duration: Duration, scheduler: Scheduler) -> Self
+    where
+        Scheduler: core::Scheduler,
+    {
+        let clock = scheduler.clone();
+        Self {
+            duration,
+            now: Box::new(move || clock.now()),
+            schedule: Box::new(move |future| scheduler.schedule(future)),
+        }
+    }
+}
+
+/// An [`Observable`] of all items that were mapped to the same key by
+/// [`Observable::group_by`].
+///
+/// [`Observable`]: ../../core/trait.Observable.html
+/// [`Observable::group_by`]: ../../core/trait.Observable.html#method.group_by
+pub struct GroupedObservable<Key, Cancellable, Item, Error> {
+    key: Key,
+    subject: UnicastSubject<Cancellable, Item, Error>,
+}
+
+impl<Key, Cancellable, Item, Error> GroupedObservable<Key, Cancellable, Item, Error> {
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+}
+
+impl<Key, Cancellable, Item, Error> Clone for GroupedObservable<Key, Cancellable, Item, Error>
+where
+    Key: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            subject: self.subject.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Key, Cancellable, Item, Error> core::Observable<ArcCancellable, Item, Error>
+    for GroupedObservable<Key, Cancellable, Item, Error>
+where
+    Key: Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<ArcCancellable, Item, Error> + Send + 'static,
+    {
+        self.subject.subscribe(observer).await;
+    }
+}
+
+/// The upstream shared by the downstream and the groups, which is only
+/// cancelled once the downstream and every group were cancelled.
+struct Shared<Upstream> {
+    upstream: Upstream,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream> Shared<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    fn group<Item, Error>(self: &Arc<Self>) -> UnicastSubject<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastSubject::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.upstream.cancel().await;
+        }
+    }
+}
+
+struct Group<Cancellable, Item, Error> {
+    subject: UnicastSubject<Cancellable, Item, Error>,
+    tick: usize,
+}
+
+struct Groups<Key, Cancellable, Item, Error> {
+    groups: HashMap<Key, Group<Cancellable, Item, Error>>,
+    /// The keys of all groups together with the instant of their last item,
+    /// ordered by that instant. Only maintained if groups are evicted.
+    by_activity: BTreeMap<usize, (Key, Instant)>,
+    tick: usize,
+    is_timer_armed: bool,
+    is_done: bool,
+}
+
+impl<Key, Cancellable, Item, Error> Groups<Key, Cancellable, Item, Error>
+where
+    Key: Clone + Eq + Hash,
+{
+    fn touch(&mut self, key: &Key, now: Instant) {
+        if let Some(group) = self.groups.get_mut(key) {
+            self.by_activity.remove(&group.tick);
+            self.tick += 1;
+            group.tick = self.tick;
+            self.by_activity.insert(self.tick, (key.clone(), now));
+        }
+    }
+
+    fn remove(&mut self, key: &Key) -> Option<Group<Cancellable, Item, Error>> {
+        let group = self.groups.remove(key)?;
+        self.by_activity.remove(&group.tick);
+        Some(group)
+    }
+
+    fn remove_idle(
+        &mut self,
+        now: Instant,
+        eviction: Duration,
+    ) -> Vec<Group<Cancellable, Item, Error>> {
+        let mut idle = vec![];
+        while let Some((key, last_active)) = self.by_activity.values().next().cloned() {
+            if now.saturating_duration_since(last_active) < eviction {
+                break;
+            }
+            idle.extend(self.remove(&key));
+        }
+        idle
+    }
+
+    fn drain(&mut self) -> Vec<Group<Cancellable, Item, Error>> {
+        self.is_done = true;
+        self.by_activity.clear();
+        self.groups.drain().map(|(_, group)| group).collect()
+    }
+}
+
+type SharedGroups<Key, Cancellable, Item, Error> =
+    Arc<Mutex<Groups<Key, Cancellable, Item, Error>>>;
+
+fn schedule_eviction<Key, Cancellable, Item, Error>(
+    groups: SharedGroups<Key, Cancellable, Item, Error>,
+    eviction: Arc<Eviction>,
+    delay: Duration,
+) where
+    Key: Clone + Eq + Hash + Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    let timer = eviction.clone();
+    (timer.schedule)(Box::pin(async move {
+        Delay::new(delay).await;
+        evict_idle_groups(groups, eviction).await;
+    }));
+}
+
+async fn evict_idle_groups<Key, Cancellable, Item, Error>(
+    groups: SharedGroups<Key, Cancellable, Item, Error>,
+    eviction: Arc<Eviction>,
+) where
+    Key: Clone + Eq + Hash + Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    let (idle, next) = {
+        let mut locked = groups.lock().await;
+        let now = (eviction.now)();
+        let idle = locked.remove_idle(now, eviction.duration);
+        // The timer is rearmed for the group that becomes idle next.
+        let next = match locked.by_activity.values().next() {
+            Some((_, last_active)) if !locked.is_done => {
+                Some((*last_active + eviction.duration).saturating_duration_since(now))
+            }
+            _ => None,
+        };
+        locked.is_timer_armed = next.is_some();
+        (idle, next)
+    };
+    if let Some(delay) = next {
+        schedule_eviction(groups, eviction, delay);
+    }
+    for mut group in idle {
+        core::Observer::on_completed(&mut group.subject).await;
+    }
+}
+
+pub struct GroupByObserver<Observer, Cancellable, Item, Error, Key, KeyFn> {
+    observer: Observer,
+    key_fn: KeyFn,
+    eviction: Option<Arc<Eviction>>,
+    shared: Option<Arc<Shared<Cancellable>>>,
+    groups: SharedGroups<Key, Cancellable, Item, Error>,
+}
+
+impl<Observer, Cancellable, Item, Error, Key, KeyFn>
+    GroupByObserver<Observer, Cancellable, Item, Error, Key, KeyFn>
+where
+    Key: Eq + Hash,
+{
+    pub fn new(observer: Observer, key_fn: KeyFn, eviction: Option<Eviction>) -> Self {
+        Self {
+            observer,
+            key_fn,
+            eviction: eviction.map(Arc::new),
+            shared: None,
+            groups: Arc::new(Mutex::new(Groups {
+                groups: HashMap::new(),
+                by_activity: BTreeMap::new(),
+                tick: 0,
+                is_timer_armed: false,
+                is_done: false,
+            })),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Key, KeyFn> core::Observer<Cancellable, Item, Error>
+    for GroupByObserver<Observer, Cancellable, Item, Error, Key, KeyFn>
+where
+    Observer: core::Observer<
+            GroupByCancellable<Cancellable>,
+            GroupedObservable<Key, Cancellable, Item, Error>,
+            Error,
+        > + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    Key: Clone + Eq + Hash + Send + 'static,
+    KeyFn: FnMut(&Item) -> Key + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let shared = Arc::new(Shared {
+            upstream: cancellable,
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        });
+        self.shared = Some(shared.clone());
+        self.observer
+            .on_subscribe(GroupByCancellable { shared })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let shared = match self.shared.as_ref() {
+            Some(shared) => shared.clone(),
+            None => return,
+        };
+        let key = (self.key_fn)(&item);
+        let mut groups = self.groups.lock().await;
+        if groups.is_done {
+            return;
+        }
+        let is_cancelled = match groups.groups.get(&key) {
+            Some(group) => group.subject.is_cancelled().await,
+            None => false,
+        };
+        if is_cancelled {
+            // A cancelled group is replaced by a new one with the same key,
+            // completing it releases its share of the upstream.
+            if let Some(mut group) = groups.remove(&key) {
+                core::Observer::on_completed(&mut group.subject).await;
+            }
+        }
+        let mut new_group = None;
+        if !groups.groups.contains_key(&key) {
+            // Once the downstream cancelled, no further groups are opened.
+            if shared.cancelled.load(Ordering::SeqCst) {
+                return;
+            }
+            let group = GroupedObservable {
+                key: key.clone(),
+                subject: shared.group(),
+            };
+            groups.groups.insert(
+                key.clone(),
+                Group {
+                    subject: group.subject.clone(),
+                    tick: 0,
+                },
+            );
+            new_group = Some(group);
+        }
+        let mut schedule = false;
+        if let Some(eviction) = self.eviction.as_ref() {
+            groups.touch(&key, (eviction.now)());
+            schedule = !groups.is_timer_armed;
+            groups.is_timer_armed = true;
+        }
+        let mut subject = match groups.groups.get(&key) {
+            Some(group) => group.subject.clone(),
+            None => return,
+        };
+        drop(groups);
+
+        if let (true, Some(eviction)) = (schedule, self.eviction.as_ref()) {
+            schedule_eviction(self.groups.clone(), eviction.clone(), eviction.duration);
+        }
+        if let Some(group) = new_group {
+            self.observer.on_next(group).await;
+        }
+        core::Observer::on_next(&mut subject, item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let groups = self.groups.lock().await.drain();
+        for mut group in groups {
+            core::Observer::on_error(&mut group.subject, error.clone()).await;
+        }
+        if !self.is_cancelled() {
+            self.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let groups = self.groups.lock().await.drain();
+        for mut group in groups {
+            core::Observer::on_completed(&mut group.subject).await;
+        }
+        if !self.is_cancelled() {
+            self.observer.on_completed().await;
+        }
+    }
+}
+
+impl<Observer, Cancellable, Item, Error, Key, KeyFn>
+    GroupByObserver<Observer, Cancellable, Item, Error, Key, KeyFn>
+{
+    fn is_cancelled(&self) -> bool {
+        match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::SeqCst),
+            None => false,
+        }
+    }
+}
+
+pub struct GroupByCancellable<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+impl<Upstream> Clone for GroupByCancellable<Upstream> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for GroupByCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+
+    #[async_std::test]
+    async fn group_by() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3, 4]
+            .into_observable()
+            .group_by(|item| item % 2)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+
+        let mut items = vec![];
+        for group in test_observer.items().await {
+            let group_observer = TestObserver::default();
+            let key = *group.key();
+            group.subscribe(group_observer.clone()).await;
+            assert_eq!(group_observer.status().await, ObserverStatus::Completed);
+            items.push((key, group_observer.items().await));
+        }
+        assert_eq!(items, vec![(0, vec![0, 2, 4]), (1, vec![1, 3])]);
+    }
+
+    #[async_std::test]
+    async fn cancelled_group() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .group_by(|_: &i32| ())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        let mut group_observer = TestObserver::default();
+        let group = test_observer.items().await.remove(0);
+        group.subscribe(group_observer.clone()).await;
+        group_observer.cancel().await;
+        test_observable.emit(1).await;
+
+        assert_eq!(group_observer.items().await, vec![0]);
+        assert_eq!(test_observer.items().await.len(), 2);
+    }
+
+    #[async_std::test]
+    async fn cancel_with_live_group() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .group_by(|item: &i32| item % 2)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        let group_observer = TestObserver::default();
+        let group = test_observer.items().await.remove(0);
+        group.subscribe(group_observer.clone()).await;
+        test_observer.cancel().await;
+        assert!(!test_observable.is_cancelled().await);
+
+        // Items of new keys are dropped, the live group keeps receiving.
+        test_observable.emit_all(vec![1, 2]).await;
+        test_observable.emit_on_completed().await;
+        assert_eq!(test_observer.items().await.len(), 1);
+        assert_eq!(group_observer.items().await, vec![0, 2]);
+        assert_eq!(group_observer.status().await, ObserverStatus::Completed);
+    }
+
+    #[async_std::test]
+    async fn cancel_after_live_group() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .group_by(|_: &i32| ())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        let mut group_observer = TestObserver::default();
+        let group = test_observer.items().await.remove(0);
+        group.subscribe(group_observer.clone()).await;
+        test_observer.cancel().await;
+        group_observer.cancel().await;
+        test_observable.emit(1).await;
+
+        assert_eq!(group_observer.items().await, vec![0]);
+        assert!(test_observable.is_cancelled().await);
+    }
+
+    #[chronobreak::test]
+    async fn eviction() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .group_by_with_eviction(|_: &i32| (), Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        scheduler.join();
+
+        // The idle group is evicted without another item arriving.
+        let group_observer = TestObserver::default();
+        let group = test_observer.items().await.remove(0);
+        group.subscribe(group_observer.clone()).await;
+        assert_eq!(group_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(group_observer.items().await, vec![0]);
+
+        test_observable.emit(1).await;
+        test_observable.emit_on_completed().await;
+        scheduler.join();
+        assert_eq!(test_observer.items().await.len(), 2);
+    }
+}
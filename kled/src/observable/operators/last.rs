@@ -0,0 +1,87 @@
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+
+#[operator(type = "observable", error = "observable::Error<Error>")]
+pub struct Last {}
+
+#[derive(new)]
+struct LastObserver<Observer, Item> {
+    observer: Observer,
+    #[new(default)]
+    last: Option<Item>,
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error> core::Observer<Cancellable, Item, Error>
+    for LastObserver<Observer, Item>
+where
+    Observer: core::Observer<Cancellable, Item, observable::Error<Error>> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.last = Some(item);
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer
+            .on_error(observable::Error::Upstream(error))
+            .await;
+    }
+
+    async fn on_completed(&mut self) {
+        match self.last.take() {
+            Some(item) => {
+                self.observer.on_next(item).await;
+                self.observer.on_completed().await;
+            }
+            None => {
+                self.observer
+                    .on_error(observable::Error::NoSuchElement)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::{self, *};
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn last() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2]
+            .into_observable()
+            .last()
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![2]);
+    }
+
+    #[async_std::test]
+    async fn upstream_error() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(0);
+        test_observable
+            .clone()
+            .last()
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(1).await;
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::Upstream(1))
+        );
+    }
+}
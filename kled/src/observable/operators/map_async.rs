@@ -0,0 +1,391 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::channel::mpsc;
+use futures::future::BoxFuture;
+use futures::StreamExt;
+use std::collections::VecDeque;
+use std::future::Future;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The type-erased asynchronous function of operators such as
+/// [`Observable::map_async`] and [`Observable::filter_async`]. An output of
+/// `None` drops the item.
+///
+/// [`Observable::map_async`]: ../../core/trait.Observable.html#method.map_async
+/// [`Observable::filter_async`]: ../../core/trait.Observable.html#method.filter_async
+pub type AsyncFn<Item, ItemOut> =
+    Box<dyn FnMut(Item) -> BoxFuture<'static, Option<ItemOut>> + Send>;
+
+pub(crate) fn map_async_fn<Item, ItemOut, UnaryOp, Fut>(
+    mut unary_op: UnaryOp,
+) -> AsyncFn<Item, ItemOut>
+where
+    UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+    Fut: Future<Output = ItemOut> + Send + 'static,
+{
+    Box::new(move |item| {
+        let future = unary_op(item);
+        Box::pin(async move { Some(future.await) })
+    })
+}
+
+pub(crate) fn filter_async_fn<Item, Predicate, Fut>(mut predicate: Predicate) -> AsyncFn<Item, Item>
+where
+    Item: Send + 'static,
+    Predicate: FnMut(&Item) -> Fut + Send + 'static,
+    Fut: Future<Output = bool> + Send + 'static,
+{
+    Box::new(move |item| {
+        let future = predicate(&item);
+        Box::pin(async move {
+            if future.await {
+                Some(item)
+            } else {
+                None
+            }
+        })
+    })
+}
+
+type BoxedObserver<Cancellable, ItemOut, Error> = Box<
+    dyn core::Observer<MapAsyncCancellable<Cancellable, ItemOut, Error>, ItemOut, Error>
+        + Send
+        + 'static,
+>;
+
+#[operator(
+    type = "observable",
+    item = "ItemOut",
+    subscription = "MapAsyncCancellable<Cancellable, ItemOut, Error>"
+)]
+pub struct MapAsync<ItemOut, Scheduler>
+where
+    Item: Send + 'static,
+    ItemOut: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async_fn: AsyncFn<Item, ItemOut>,
+    concurrency: usize,
+    ordered: bool,
+    scheduler: Scheduler,
+}
+
+struct MapAsyncObserver<Cancellable, Item, ItemOut, Error, Scheduler> {
+    observer: Option<BoxedObserver<Cancellable, ItemOut, Error>>,
+    shared: Arc<Shared<Cancellable, ItemOut, Error>>,
+    /// Holds one permit per item which may be running or buffered.
+    permits: mpsc::UnboundedReceiver<()>,
+    async_fn: AsyncFn<Item, ItemOut>,
+    ordered: bool,
+    scheduler: Scheduler,
+}
+
+impl<Cancellable, Item, ItemOut, Error, Scheduler>
+    MapAsyncObserver<Cancellable, Item, ItemOut, Error, Scheduler>
+{
+    fn new<Observer>(
+        observer: Observer,
+        async_fn: AsyncFn<Item, ItemOut>,
+        concurrency: usize,
+        ordered: bool,
+        scheduler: Scheduler,
+    ) -> Self
+    where
+        Observer: core::Observer<MapAsyncCancellable<Cancellable, ItemOut, Error>, ItemOut, Error>
+            + Send
+            + 'static,
+    {
+        let (sender, permits) = mpsc::unbounded();
+        for _ in 0..concurrency {
+            sender.unbounded_send(()).unwrap();
+        }
+        Self {
+            observer: Some(Box::new(observer)),
+            shared: Arc::new(Shared {
+                observer: Mutex::new(None),
+                state: Mutex::new(State {
+                    results: VecDeque::new(),
+                    popped: 0,
+                    in_flight: 0,
+                    error: None,
+                    is_done: false,
+                    is_cancelled: false,
+                    is_terminated: false,
+                }),
+                permits: sender,
+                wip: AtomicUsize::new(0),
+            }),
+            permits,
+            async_fn,
+            ordered,
+            scheduler,
+        }
+    }
+}
+
+struct Shared<Cancellable, ItemOut, Error> {
+    observer: Mutex<Option<BoxedObserver<Cancellable, ItemOut, Error>>>,
+    state: Mutex<State<ItemOut, Error>>,
+    permits: mpsc::UnboundedSender<()>,
+    wip: AtomicUsize,
+}
+
+struct State<ItemOut, Error> {
+    /// The results in emission order. A slot is `None` while the result of an
+    /// ordered item is still pending.
+    results: VecDeque<Option<Option<ItemOut>>>,
+    /// The number of slots removed from the front of `results` so far.
+    popped: usize,
+    in_flight: usize,
+    error: Option<Error>,
+    is_done: bool,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Cancellable, ItemOut, Error> Shared<Cancellable, ItemOut, Error> {
+    async fn complete(&self, slot: Option<usize>, result: Option<ItemOut>) {
+        let mut state = self.state.lock().await;
+        state.in_flight -= 1;
+        match slot {
+            Some(slot) => {
+                let index = slot - state.popped;
+                state.results[index] = Some(result);
+            }
+            None => state.results.push_back(Some(result)),
+        }
+    }
+}
+
+async fn drain<Cancellable, ItemOut, Error>(shared: &Shared<Cancellable, ItemOut, Error>)
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut observer = shared.observer.lock().await;
+        let mut is_terminated = false;
+        if let Some(downstream) = observer.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    is_terminated = true;
+                    break;
+                }
+                if let Some(error) = state.error.take() {
+                    drop(state);
+                    downstream.on_error(error).await;
+                    is_terminated = true;
+                    break;
+                }
+                match state.results.front() {
+                    Some(Some(_)) => {
+                        let item = state.results.pop_front().flatten().flatten();
+                        state.popped += 1;
+                        drop(state);
+                        // The consumed result frees up room for another upstream item.
+                        let _ = shared.permits.unbounded_send(());
+                        if let Some(item) = item {
+                            downstream.on_next(item).await;
+                        }
+                    }
+                    None if state.is_done && state.in_flight == 0 => {
+                        drop(state);
+                        downstream.on_completed().await;
+                        is_terminated = true;
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        if is_terminated {
+            shared.state.lock().await.is_terminated = true;
+            shared.permits.close_channel();
+            *observer = None;
+        }
+        drop(observer);
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, ItemOut, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for MapAsyncObserver<Cancellable, Item, ItemOut, Error, Scheduler>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        if let Some(mut observer) = self.observer.take() {
+            observer
+                .on_subscribe(MapAsyncCancellable {
+                    upstream: cancellable,
+                    shared: self.shared.clone(),
+                })
+                .await;
+            *self.shared.observer.lock().await = Some(observer);
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        // Waits until fewer than `concurrency` items are running or buffered.
+        if self.permits.next().await.is_none() {
+            return;
+        }
+        let slot = {
+            let mut state = self.shared.state.lock().await;
+            if state.is_terminated {
+                return;
+            }
+            state.in_flight += 1;
+            if self.ordered {
+                state.results.push_back(None);
+                Some(state.popped + state.results.len() - 1)
+            } else {
+                None
+            }
+        };
+        let future = (self.async_fn)(item);
+        let shared = self.shared.clone();
+        self.scheduler.schedule(async move {
+            let result = future.await;
+            shared.complete(slot, result).await;
+            drain(&shared).await;
+        });
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.error.is_none() {
+                state.error = Some(error);
+            }
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.is_done = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct MapAsyncCancellable<Upstream, ItemOut, Error> {
+    upstream: Upstream,
+    shared: Arc<Shared<Upstream, ItemOut, Error>>,
+}
+
+impl<Upstream, ItemOut, Error> Clone for MapAsyncCancellable<Upstream, ItemOut, Error>
+where
+    Upstream: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upstream: self.upstream.clone(),
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, ItemOut, Error> core::Cancellable for MapAsyncCancellable<Upstream, ItemOut, Error>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.state.lock().await.is_cancelled = true;
+        self.upstream.cancel().await;
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use futures_timer::Delay;
+    use std::time::Duration;
+
+    #[async_std::test]
+    async fn map_async() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_observer = TestObserver::default();
+        vec![30, 10, 20]
+            .into_observable()
+            .map_async(
+                |millis| async move {
+                    Delay::new(Duration::from_millis(millis)).await;
+                    millis / 10
+                },
+                3,
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![3, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn map_async_unordered() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_observer = TestObserver::default();
+        vec![30, 10, 20]
+            .into_observable()
+            .map_async_unordered(
+                |millis| async move {
+                    Delay::new(Duration::from_millis(millis)).await;
+                    millis / 10
+                },
+                3,
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn filter_async() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3, 4]
+            .into_observable()
+            .filter_async(
+                |item| futures::future::ready(item % 2 == 0),
+                2,
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 2, 4]);
+    }
+}
@@ -0,0 +1,414 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedSource<Item, Error, ErrorOut> =
+    Box<dyn FnOnce(Arc<Shared<Item, Error, ErrorOut>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedObserver<Item, Error, ErrorOut> = Box<
+    dyn core::Observer<MergeCancellable<Item, Error, ErrorOut>, Item, ErrorOut> + Send + 'static,
+>;
+
+type BoxedCancel = Box<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+type Composite<Error, ErrorOut> = fn(Vec<Error>) -> ErrorOut;
+
+/// Merges the items of multiple sources into a single observable.
+///
+/// If errors are delayed, the errors of all sources are collected and
+/// emitted as a single composite error once all sources terminated.
+pub struct Merge<Item, Error, ErrorOut> {
+    sources: Vec<BoxedSource<Item, Error, ErrorOut>>,
+    delay_error: bool,
+    composite: Composite<Error, ErrorOut>,
+}
+
+impl<Item, Error, ErrorOut> Merge<Item, Error, ErrorOut>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    fn with_source<Observable, Cancellable>(mut self, observable: Observable) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move { observable.subscribe(MergeObserver { shared, index }).await })
+        }));
+        self
+    }
+
+    fn with_sources<Observable, Cancellable>(self, sources: Vec<Observable>) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        sources
+            .into_iter()
+            .fold(self, |merge, source| merge.with_source(source))
+    }
+}
+
+impl<Item, Error> Merge<Item, Error, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn merge_all<Observable, Cancellable>(sources: Vec<Observable>) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Self {
+            sources: vec![],
+            delay_error: false,
+            composite: |errors| errors.into_iter().next().unwrap(),
+        }
+        .with_sources(sources)
+    }
+
+    pub fn merge2<ObservableA, CancellableA, ObservableB, CancellableB>(
+        a: ObservableA,
+        b: ObservableB,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, Item, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, Item, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::merge_all::<ObservableA, CancellableA>(vec![])
+            .with_source(a)
+            .with_source(b)
+    }
+}
+
+impl<Item, Error> Merge<Item, Error, Vec<Error>>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn merge_delay_error<Observable, Cancellable>(sources: Vec<Observable>) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Self {
+            sources: vec![],
+            delay_error: true,
+            composite: |errors| errors,
+        }
+        .with_sources(sources)
+    }
+}
+
+/// Returns an [`Observable`] that emits the items of all `sources` as they
+/// arrive. The first error of any source is emitted immediately.
+///
+/// [`Observable`]: ../../core/trait.Observable.html
+pub fn merge<Observable, Cancellable, Item, Error>(
+    sources: Vec<Observable>,
+) -> Merge<Item, Error, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Merge::merge_all(sources)
+}
+
+/// Returns an [`Observable`] that emits the items of all `sources` as they
+/// arrive. Errors are collected and emitted as a single composite error once
+/// all sources terminated.
+///
+/// [`Observable`]: ../../core/trait.Observable.html
+pub fn merge_delay_error<Observable, Cancellable, Item, Error>(
+    sources: Vec<Observable>,
+) -> Merge<Item, Error, Vec<Error>>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    Merge::merge_delay_error(sources)
+}
+
+#[async_trait]
+impl<Item, Error, ErrorOut>
+    core::Observable<MergeCancellable<Item, Error, ErrorOut>, Item, ErrorOut>
+    for Merge<Item, Error, ErrorOut>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<MergeCancellable<Item, Error, ErrorOut>, Item, ErrorOut>
+            + Send
+            + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            observer: Mutex::new(None),
+            cancellables: Mutex::new((0..count).map(|_| None).collect()),
+            state: Mutex::new(State {
+                queue: VecDeque::new(),
+                done: vec![false; count],
+                errors: vec![],
+                is_cancelled: false,
+                is_terminated: false,
+            }),
+            delay_error: self.delay_error,
+            composite: self.composite,
+            wip: AtomicUsize::new(0),
+        });
+        observer
+            .on_subscribe(MergeCancellable {
+                shared: shared.clone(),
+            })
+            .await;
+        *shared.observer.lock().await = Some(Box::new(observer));
+        drain(&shared).await;
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Item, Error, ErrorOut> {
+    observer: Mutex<Option<BoxedObserver<Item, Error, ErrorOut>>>,
+    cancellables: Mutex<Vec<Option<Arc<BoxedCancel>>>>,
+    state: Mutex<State<Item, Error>>,
+    delay_error: bool,
+    composite: Composite<Error, ErrorOut>,
+    wip: AtomicUsize,
+}
+
+struct State<Item, Error> {
+    queue: VecDeque<Item>,
+    done: Vec<bool>,
+    errors: Vec<Error>,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Item, Error> State<Item, Error> {
+    fn is_completed(&self) -> bool {
+        self.done.iter().all(|done| *done) && self.queue.is_empty()
+    }
+}
+
+impl<Item, Error, ErrorOut> Shared<Item, Error, ErrorOut> {
+    async fn cancellables(&self) -> Vec<Arc<BoxedCancel>> {
+        self.cancellables
+            .lock()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect()
+    }
+}
+
+async fn drain<Item, Error, ErrorOut>(shared: &Shared<Item, Error, ErrorOut>)
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut observer = shared.observer.lock().await;
+        let mut is_terminated = false;
+        if let Some(downstream) = observer.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    state.is_terminated = true;
+                    is_terminated = true;
+                    break;
+                }
+                if !state.errors.is_empty() && (!shared.delay_error || state.is_completed()) {
+                    state.is_terminated = true;
+                    let errors = std::mem::take(&mut state.errors);
+                    drop(state);
+                    downstream.on_error((shared.composite)(errors)).await;
+                    is_terminated = true;
+                    break;
+                }
+                if state.is_completed() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_completed().await;
+                    is_terminated = true;
+                    break;
+                }
+                let item = match state.queue.pop_front() {
+                    Some(item) => item,
+                    None => break,
+                };
+                drop(state);
+                downstream.on_next(item).await;
+            }
+        }
+        if is_terminated {
+            *observer = None;
+        }
+        drop(observer);
+        if is_terminated {
+            for cancel in shared.cancellables().await {
+                cancel().await;
+            }
+        }
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+struct MergeObserver<Item, Error, ErrorOut> {
+    shared: Arc<Shared<Item, Error, ErrorOut>>,
+    index: usize,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, ErrorOut> core::Observer<Cancellable, Item, Error>
+    for MergeObserver<Item, Error, ErrorOut>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancel: BoxedCancel = Box::new(move || {
+            let cancellable = cancellable.clone();
+            Box::pin(async move { cancellable.cancel().await })
+        });
+        let cancel = Arc::new(cancel);
+        self.shared.cancellables.lock().await[self.index] = Some(cancel.clone());
+        if self.shared.state.lock().await.is_terminated {
+            cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.shared.state.lock().await.queue.push_back(item);
+        drain(&self.shared).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        {
+            let mut state = self.shared.state.lock().await;
+            state.errors.push(error);
+            state.done[self.index] = true;
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.done[self.index] = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct MergeCancellable<Item, Error, ErrorOut> {
+    shared: Arc<Shared<Item, Error, ErrorOut>>,
+}
+
+impl<Item, Error, ErrorOut> Clone for MergeCancellable<Item, Error, ErrorOut> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Item, Error, ErrorOut> core::Cancellable for MergeCancellable<Item, Error, ErrorOut>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorOut: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.state.lock().await.is_cancelled = true;
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn merge_with() {
+        let test_observer = TestObserver::default();
+        let a = TestObservable::default().annotate_error_type(());
+        let b = TestObservable::default();
+        a.clone()
+            .merge_with(b.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        a.emit(0).await;
+        b.emit(1).await;
+        a.emit(2).await;
+        a.emit_on_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Subscribed);
+        b.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_observer = TestObserver::default();
+        let a = TestObservable::default().annotate_item_type(0);
+        let b = TestObservable::default().annotate_item_type(0);
+        operators::merge(vec![a.clone(), b.clone()])
+            .subscribe(test_observer.clone())
+            .await;
+        a.emit_error(()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.error().await, Some(()));
+        assert!(b.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn merge_delay_error() {
+        let test_observer = TestObserver::default();
+        let a = TestObservable::default();
+        let b = TestObservable::default();
+        operators::merge_delay_error(vec![a.clone(), b.clone()])
+            .subscribe(test_observer.clone())
+            .await;
+        a.emit_error(0).await;
+        b.emit(1).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Subscribed);
+        b.emit_error(2).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await, vec![1]);
+        assert_eq!(test_observer.error().await, Some(vec![0, 2]));
+    }
+}
@@ -1,8 +1,51 @@
 reexport_all! {
+    mod aggregate;
+    mod amb;
+    mod any;
+    mod combine_latest;
+    mod concat;
+    mod debounce;
+    mod delay;
+    mod delay_subscription;
     mod dematerialize;
+    mod do_on_cancel;
+    mod do_on_complete;
+    mod do_on_error;
+    mod do_on_next;
+    mod do_on_subscribe;
+    mod element_at;
+    mod finally;
+    mod group_by;
+    mod last;
     mod map;
+    mod map_async;
     mod materialize;
+    mod merge;
     mod observe_on;
+    mod on_error_complete;
+    mod on_error_resume_next;
+    mod on_error_return;
+    mod pairwise;
+    mod repeat;
+    mod resubscribe;
+    mod retry;
+    mod running_aggregate;
+    mod sample;
+    mod sample_with;
     mod scan;
+    mod single;
+    mod sliding;
     mod subscribe_on;
+    mod switch_if_empty;
+    mod throttle_first;
+    mod throttle_latest;
+    mod time_interval;
+    mod timeout;
+    mod timestamp;
+    mod window;
+    mod window_boundary;
+    mod window_time;
+    mod with_items;
+    mod with_latest_from;
+    mod zip;
 }
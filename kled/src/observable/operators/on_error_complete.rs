@@ -0,0 +1,59 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct OnErrorComplete {}
+
+#[derive(new)]
+struct OnErrorCompleteObserver<Observer> {
+    observer: Observer,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error> core::Observer<Cancellable, Item, Error>
+    for OnErrorCompleteObserver<Observer>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, _: Error) {
+        self.observer.on_completed().await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn on_error_complete() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default();
+        test_observable
+            .clone()
+            .on_error_complete()
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::cancellable::*;
+use crate::core;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[operator(
+    type = "observable",
+    subscription = "OnErrorResumeNextCancellable<Cancellable, FallbackCancellable>"
+)]
+pub struct OnErrorResumeNext<Fallback, FallbackCancellable, ResumeFn>
+where
+    Fallback: core::Observable<FallbackCancellable, Item, Error>,
+    FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ResumeFn: FnMut(Error) -> Fallback + Send,
+{
+    resume_fn: ResumeFn,
+}
+
+struct OnErrorResumeNextObserver<Observer, FallbackCancellable, ResumeFn> {
+    observer: Option<Observer>,
+    stub: LazyCancellableStub<FallbackCancellable>,
+    resume_fn: ResumeFn,
+}
+
+impl<Observer, FallbackCancellable, ResumeFn>
+    OnErrorResumeNextObserver<Observer, FallbackCancellable, ResumeFn>
+{
+    fn new(observer: Observer, resume_fn: ResumeFn) -> Self {
+        Self {
+            observer: Some(observer),
+            stub: LazyCancellableStub::default(),
+            resume_fn,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Fallback, FallbackCancellable, ResumeFn>
+    core::Observer<Cancellable, Item, Error>
+    for OnErrorResumeNextObserver<Observer, FallbackCancellable, ResumeFn>
+where
+    Observer: core::Observer<OnErrorResumeNextCancellable<Cancellable, FallbackCancellable>, Item, Error>
+        + Send
+        + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Fallback: core::Observable<FallbackCancellable, Item, Error> + Send,
+    FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+    ResumeFn: FnMut(Error) -> Fallback + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer
+                .on_subscribe(OnErrorResumeNextCancellable::new(
+                    cancellable,
+                    self.stub.cancellable(),
+                ))
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if let Some(observer) = self.observer.take() {
+            (self.resume_fn)(error)
+                .subscribe(FallbackObserver {
+                    observer,
+                    stub: std::mem::take(&mut self.stub),
+                    phantom: PhantomData,
+                })
+                .await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_completed().await;
+        }
+    }
+}
+
+struct FallbackObserver<Observer, Cancellable, FallbackCancellable> {
+    observer: Observer,
+    stub: LazyCancellableStub<FallbackCancellable>,
+    phantom: PhantomData<Cancellable>,
+}
+
+#[async_trait]
+impl<Observer, Cancellable, Item, Error, FallbackCancellable>
+    core::Observer<FallbackCancellable, Item, Error>
+    for FallbackObserver<Observer, Cancellable, FallbackCancellable>
+where
+    Observer: core::Observer<OnErrorResumeNextCancellable<Cancellable, FallbackCancellable>, Item, Error>
+        + Send,
+    Cancellable: Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: FallbackCancellable) {
+        self.stub.set_cancellable(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[derive(new, Clone)]
+pub struct OnErrorResumeNextCancellable<Upstream, Fallback> {
+    upstream: Upstream,
+    fallback: LazyCancellable<Fallback>,
+}
+
+#[async_trait]
+impl<Upstream, Fallback> core::Cancellable for OnErrorResumeNextCancellable<Upstream, Fallback>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    Fallback: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.fallback.cancel().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn on_error_resume_next() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default();
+        let fallback = TestObservable::default();
+        let resume_fallback = fallback.clone();
+        test_observable
+            .clone()
+            .on_error_resume_next(move |_| resume_fallback.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(()).await;
+        fallback.emit_all(vec![1, 2]).await;
+        fallback.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(0);
+        let fallback = TestObservable::default();
+        let resume_fallback = fallback.clone();
+        test_observable
+            .clone()
+            .on_error_resume_next(move |_| resume_fallback.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_error(()).await;
+        test_observer.cancel().await;
+
+        assert!(fallback.is_cancelled().await);
+    }
+}
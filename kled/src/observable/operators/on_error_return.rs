@@ -0,0 +1,68 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct OnErrorReturn<ItemFn>
+where
+    ItemFn: FnMut(Error) -> Item + Send,
+{
+    item_fn: ItemFn,
+}
+
+#[derive(new)]
+struct OnErrorReturnObserver<Observer, ItemFn> {
+    observer: Observer,
+    item_fn: ItemFn,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, ItemFn> core::Observer<Cancellable, Item, Error>
+    for OnErrorReturnObserver<Observer, ItemFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ItemFn: FnMut(Error) -> Item + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let item = (self.item_fn)(error);
+        self.observer.on_next(item).await;
+        self.observer.on_completed().await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn on_error_return() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default();
+        test_observable
+            .clone()
+            .on_error_return(|error| error * 2)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(1).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 2]);
+    }
+}
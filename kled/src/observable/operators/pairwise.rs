@@ -0,0 +1,72 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable", item = "(Item, Item)")]
+pub struct Pairwise
+where
+    Item: Clone, {}
+
+#[derive(new)]
+struct PairwiseObserver<Observer, Item> {
+    observer: Observer,
+    #[new(default)]
+    previous: Option<Item>,
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error> core::Observer<Cancellable, Item, Error>
+    for PairwiseObserver<Observer, Item>
+where
+    Observer: core::Observer<Cancellable, (Item, Item), Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Clone + Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(previous) = self.previous.replace(item.clone()) {
+            self.observer.on_next((previous, item)).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn pairwise() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2]
+            .into_observable()
+            .pairwise()
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![(0, 1), (1, 2)]);
+    }
+
+    #[async_std::test]
+    async fn single_item() {
+        let test_observer = TestObserver::default();
+        vec![0]
+            .into_observable()
+            .pairwise()
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert!(test_observer.items().await.is_empty());
+    }
+}
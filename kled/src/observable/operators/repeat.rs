@@ -0,0 +1,218 @@
+use super::resubscribe::*;
+use crate::core;
+use crate::subject::UnicastSubject;
+use crate::Never;
+use async_trait::async_trait;
+
+pub type RepeatCancellable<Upstream> = ResubscribeCancellable<Upstream>;
+
+pub struct Repeat<Observable, Cancellable, Item, Error>(
+    Resubscribe<Observable, Cancellable, Item, Error>,
+);
+
+impl<Observable, Cancellable, Item, Error> Repeat<Observable, Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    /// Subscribes to `observable` `count` times in total, one subscription
+    /// after the completion of the previous one.
+    pub fn new(observable: Observable, count: usize) -> Self {
+        let mut repeats = 1;
+        Self(Resubscribe::with_setup(observable, move |resubscriber| {
+            Box::pin(async move {
+                if count == 0 {
+                    resubscriber.on_completed().await;
+                }
+                let on_termination: OnTermination<Cancellable, Item, Error> =
+                    Box::new(move |termination, resubscriber| match termination {
+                        Termination::Completed if repeats < count => {
+                            repeats += 1;
+                            Box::pin(resubscriber.resubscribe())
+                        }
+                        Termination::Completed => Box::pin(resubscriber.on_completed()),
+                        Termination::Failed(error) => Box::pin(resubscriber.on_error(error)),
+                    });
+                on_termination
+            })
+        }))
+    }
+
+    /// Resubscribes to `observable` on every completion.
+    pub fn forever(observable: Observable) -> Self {
+        Self(Resubscribe::new(
+            observable,
+            |termination, resubscriber| match termination {
+                Termination::Completed => Box::pin(resubscriber.resubscribe()),
+                Termination::Failed(error) => Box::pin(resubscriber.on_error(error)),
+            },
+        ))
+    }
+
+    /// Passes the observable of all completions to `repeat_when` and
+    /// subscribes to the returned notifier before subscribing to `observable`.
+    /// Each item of the notifier resubscribes to `observable` if it completed,
+    /// while the returned observable terminates once the notifier terminates.
+    pub fn when<RepeatWhen, Notifier, NotifierCancellable, NotifierItem>(
+        observable: Observable,
+        repeat_when: RepeatWhen,
+    ) -> Self
+    where
+        RepeatWhen: FnOnce(UnicastSubject<Never, (), Error>) -> Notifier + Send + 'static,
+        Notifier: core::Observable<NotifierCancellable, NotifierItem, Error> + Send + 'static,
+        NotifierCancellable: core::Cancellable + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Self(Resubscribe::with_setup(observable, move |resubscriber| {
+            Box::pin(async move {
+                let completions = UnicastSubject::default();
+                repeat_when(completions.clone())
+                    .subscribe(NotifierObserver::new(resubscriber))
+                    .await;
+                let on_termination: OnTermination<Cancellable, Item, Error> =
+                    Box::new(move |termination, resubscriber| match termination {
+                        Termination::Completed => {
+                            let mut completions = completions.clone();
+                            Box::pin(async move {
+                                core::Observer::<Never, _, _>::on_next(&mut completions, ()).await;
+                            })
+                        }
+                        Termination::Failed(error) => Box::pin(resubscriber.on_error(error)),
+                    });
+                on_termination
+            })
+        }))
+    }
+}
+
+#[async_trait]
+impl<Observable, Cancellable, Item, Error>
+    core::Observable<RepeatCancellable<Cancellable>, Item, Error>
+    for Repeat<Observable, Cancellable, Item, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Clone + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<RepeatCancellable<Cancellable>, Item, Error> + Send + 'static,
+    {
+        self.0.subscribe(observer).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancellable::*;
+    use crate::core;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Returns an observable which emits `0` and `1` and completes, counting
+    /// its subscriptions.
+    fn counting_observable(
+        subscriptions: Arc<AtomicUsize>,
+    ) -> impl core::Observable<ArcCancellable, i32, ()> + Clone {
+        observable::create(move |mut emitter| {
+            subscriptions.fetch_add(1, Ordering::SeqCst);
+            async move {
+                emitter.on_next(0).await;
+                emitter.on_next(1).await;
+                emitter.on_completed().await;
+            }
+        })
+    }
+
+    #[async_std::test]
+    async fn repeat() {
+        let test_observer = TestObserver::default();
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        counting_observable(subscriptions.clone())
+            .repeat(2)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 0, 1]);
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 2);
+    }
+
+    #[async_std::test]
+    async fn repeat_zero() {
+        let test_observer = TestObserver::default();
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        counting_observable(subscriptions.clone())
+            .repeat(0)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, Vec::<i32>::new());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 0);
+    }
+
+    #[async_std::test]
+    async fn repeat_forever() {
+        let test_observer = TestObserver::default();
+        counting_observable(Arc::default())
+            .repeat_forever()
+            .element_at(4)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn repeat_when() {
+        let test_observer = TestObserver::default();
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        counting_observable(subscriptions.clone())
+            .repeat_when(|completions| completions.map(|_| ()))
+            .element_at(4)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 3);
+    }
+
+    #[async_std::test]
+    async fn repeat_when_notifier() {
+        let test_observer = TestObserver::default();
+        let notifier = TestObservable::default().annotate_item_type(());
+        let repeat_notifier = notifier.clone();
+        counting_observable(Arc::default())
+            .repeat_when(move |_| repeat_notifier)
+            .subscribe(test_observer.clone())
+            .await;
+        notifier.emit(()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Subscribed);
+        notifier.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 0, 1]);
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_observer = TestObserver::default();
+        let notifier = TestObservable::default().annotate_item_type(());
+        let repeat_notifier = notifier.clone();
+        counting_observable(Arc::default())
+            .repeat_when(move |_| repeat_notifier)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+
+        assert!(notifier.is_cancelled().await);
+    }
+}
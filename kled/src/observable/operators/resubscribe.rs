@@ -0,0 +1,388 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::{self, BoxFuture};
+#[chronobreak]
+use std::sync::Arc;
+
+type BoxedObserver<Cancellable, Item, Error> =
+    Box<dyn core::Observer<ResubscribeCancellable<Cancellable>, Item, Error> + Send + 'static>;
+
+type BoxedSource<Cancellable, Item, Error> =
+    Box<dyn Fn(ResubscribeObserver<Cancellable, Item, Error>) -> BoxFuture<'static, ()> + Send>;
+
+pub(crate) type BoxedCancel = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+pub(crate) type OnTermination<Cancellable, Item, Error> = Box<
+    dyn FnMut(Termination<Error>, Resubscriber<Cancellable, Item, Error>) -> BoxFuture<'static, ()>
+        + Send,
+>;
+
+type Setup<Cancellable, Item, Error> = Box<
+    dyn FnOnce(
+            Resubscriber<Cancellable, Item, Error>,
+        ) -> BoxFuture<'static, OnTermination<Cancellable, Item, Error>>
+        + Send,
+>;
+
+/// How a subscription to the source of `retry` or `repeat` terminated.
+pub(crate) enum Termination<Error> {
+    Completed,
+    Failed(Error),
+}
+
+/// The resubscription loop shared by `retry` and `repeat`, which hands each
+/// termination of the source to the operator's `OnTermination`.
+pub(crate) struct Resubscribe<Observable, Cancellable, Item, Error> {
+    observable: Observable,
+    setup: Setup<Cancellable, Item, Error>,
+}
+
+impl<Observable, Cancellable, Item, Error> Resubscribe<Observable, Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub(crate) fn new<F>(observable: Observable, on_termination: F) -> Self
+    where
+        F: FnMut(
+                Termination<Error>,
+                Resubscriber<Cancellable, Item, Error>,
+            ) -> BoxFuture<'static, ()>
+            + Send
+            + 'static,
+    {
+        let on_termination: OnTermination<Cancellable, Item, Error> = Box::new(on_termination);
+        Self::with_setup(observable, move |_| Box::pin(future::ready(on_termination)))
+    }
+
+    /// Like `new`, but `setup` is called once the downstream subscribed and
+    /// before the first subscription to `observable`, which is skipped if
+    /// `setup` already terminated the downstream.
+    pub(crate) fn with_setup<F>(observable: Observable, setup: F) -> Self
+    where
+        F: FnOnce(
+                Resubscriber<Cancellable, Item, Error>,
+            ) -> BoxFuture<'static, OnTermination<Cancellable, Item, Error>>
+            + Send
+            + 'static,
+    {
+        Self {
+            observable,
+            setup: Box::new(setup),
+        }
+    }
+}
+
+#[async_trait]
+impl<Observable, Cancellable, Item, Error>
+    core::Observable<ResubscribeCancellable<Cancellable>, Item, Error>
+    for Resubscribe<Observable, Cancellable, Item, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Clone + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<ResubscribeCancellable<Cancellable>, Item, Error> + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(State {
+            upstream: None,
+            notifier: None,
+            has_emitted: false,
+            is_active: false,
+            is_subscribing: false,
+            is_pending: false,
+            is_done: false,
+            is_cancelled: false,
+        }));
+        observer
+            .on_subscribe(ResubscribeCancellable {
+                state: state.clone(),
+            })
+            .await;
+        let observable = self.observable;
+        let resubscriber = Resubscriber {
+            data: Arc::new(Mutex::new(Data {
+                observer: Box::new(observer),
+                source: Box::new(move |observer| observable.clone().subscribe(observer)),
+                on_termination: None,
+            })),
+            state,
+            has_emitted: false,
+        };
+        let on_termination = (self.setup)(resubscriber.clone()).await;
+        resubscriber.data.lock().await.on_termination = Some(on_termination);
+        resubscriber.resubscribe().await;
+    }
+}
+
+struct Data<Cancellable, Item, Error> {
+    observer: BoxedObserver<Cancellable, Item, Error>,
+    source: BoxedSource<Cancellable, Item, Error>,
+    on_termination: Option<OnTermination<Cancellable, Item, Error>>,
+}
+
+struct State<Upstream> {
+    upstream: Option<Upstream>,
+    /// Cancels the notifier or the delay which the next subscription waits
+    /// for.
+    notifier: Option<BoxedCancel>,
+    has_emitted: bool,
+    /// Whether the source was subscribed to and did not terminate yet.
+    is_active: bool,
+    is_subscribing: bool,
+    is_pending: bool,
+    is_done: bool,
+    is_cancelled: bool,
+}
+
+/// Decides how the subscription continues after the source terminated.
+pub(crate) struct Resubscriber<Cancellable, Item, Error> {
+    data: Arc<Mutex<Data<Cancellable, Item, Error>>>,
+    state: Arc<Mutex<State<Cancellable>>>,
+    /// Whether the terminated subscription emitted any items.
+    pub(crate) has_emitted: bool,
+}
+
+impl<Cancellable, Item, Error> Clone for Resubscriber<Cancellable, Item, Error> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            state: self.state.clone(),
+            has_emitted: self.has_emitted,
+        }
+    }
+}
+
+impl<Cancellable, Item, Error> Resubscriber<Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    /// Subscribes to the source again unless it is still active, or the
+    /// subscription was terminated or cancelled.
+    pub(crate) async fn resubscribe(self) {
+        {
+            let mut state = self.state.lock().await;
+            if state.is_active || state.is_done || state.is_cancelled {
+                return;
+            }
+            state.is_active = true;
+            // A source which terminates synchronously within subscribe is
+            // resubscribed to by the loop below instead of recursively.
+            if state.is_subscribing {
+                state.is_pending = true;
+                return;
+            }
+            state.is_subscribing = true;
+        }
+        loop {
+            let subscribe = (self.data.lock().await.source)(ResubscribeObserver {
+                data: self.data.clone(),
+                state: self.state.clone(),
+            });
+            subscribe.await;
+            let mut state = self.state.lock().await;
+            if !state.is_pending || state.is_done || state.is_cancelled {
+                state.is_subscribing = false;
+                break;
+            }
+            state.is_pending = false;
+        }
+    }
+
+    /// Registers `cancel` to be called if the subscription is cancelled or
+    /// terminated. Returns false if that happened already.
+    pub(crate) async fn wait_on(&self, cancel: BoxedCancel) -> bool {
+        let mut state = self.state.lock().await;
+        state.notifier = Some(cancel);
+        !state.is_done && !state.is_cancelled
+    }
+
+    pub(crate) async fn on_error(self, error: Error) {
+        if self.terminate().await {
+            self.data.lock().await.observer.on_error(error).await;
+        }
+    }
+
+    pub(crate) async fn on_completed(self) {
+        if self.terminate().await {
+            self.data.lock().await.observer.on_completed().await;
+        }
+    }
+
+    /// Marks the subscription as terminated and cancels both the notifier and
+    /// an active source. Returns false if it was terminated already.
+    async fn terminate(&self) -> bool {
+        let (upstream, notifier) = {
+            let mut state = self.state.lock().await;
+            if std::mem::replace(&mut state.is_done, true) {
+                return false;
+            }
+            let upstream = if state.is_active {
+                state.upstream.clone()
+            } else {
+                None
+            };
+            (upstream, state.notifier.take())
+        };
+        if let Some(upstream) = upstream {
+            upstream.cancel().await;
+        }
+        if let Some(cancel) = notifier {
+            cancel().await;
+        }
+        true
+    }
+}
+
+struct ResubscribeObserver<Cancellable, Item, Error> {
+    data: Arc<Mutex<Data<Cancellable, Item, Error>>>,
+    state: Arc<Mutex<State<Cancellable>>>,
+}
+
+impl<Cancellable, Item, Error> ResubscribeObserver<Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_termination(&self, termination: Termination<Error>) {
+        let has_emitted = {
+            let mut state = self.state.lock().await;
+            state.is_active = false;
+            std::mem::replace(&mut state.has_emitted, false)
+        };
+        let resubscriber = Resubscriber {
+            data: self.data.clone(),
+            state: self.state.clone(),
+            has_emitted,
+        };
+        let on_termination = self
+            .data
+            .lock()
+            .await
+            .on_termination
+            .as_mut()
+            .map(|on_termination| on_termination(termination, resubscriber));
+        if let Some(on_termination) = on_termination {
+            on_termination.await;
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Observer<Cancellable, Item, Error>
+    for ResubscribeObserver<Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let is_terminated = {
+            let mut state = self.state.lock().await;
+            state.upstream = Some(cancellable.clone());
+            state.is_done || state.is_cancelled
+        };
+        if is_terminated {
+            cancellable.cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.state.lock().await.has_emitted = true;
+        self.data.lock().await.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.on_termination(Termination::Failed(error)).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.on_termination(Termination::Completed).await;
+    }
+}
+
+/// Subscribes to the source again for each item of the notifier of
+/// `retry_when` or `repeat_when`, and terminates once the notifier does.
+pub(crate) struct NotifierObserver<Cancellable, Item, Error> {
+    resubscriber: Resubscriber<Cancellable, Item, Error>,
+}
+
+impl<Cancellable, Item, Error> NotifierObserver<Cancellable, Item, Error> {
+    pub(crate) fn new(resubscriber: Resubscriber<Cancellable, Item, Error>) -> Self {
+        Self { resubscriber }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, NotifierCancellable, NotifierItem>
+    core::Observer<NotifierCancellable, NotifierItem, Error>
+    for NotifierObserver<Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NotifierCancellable: core::Cancellable + Send + Sync + 'static,
+    NotifierItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: NotifierCancellable) {
+        let cancel: BoxedCancel = {
+            let cancellable = cancellable.clone();
+            Box::new(move || Box::pin(async move { cancellable.cancel().await }))
+        };
+        if !self.resubscriber.wait_on(cancel).await {
+            cancellable.cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, _: NotifierItem) {
+        self.resubscriber.clone().resubscribe().await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.resubscriber.clone().on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.resubscriber.clone().on_completed().await;
+    }
+}
+
+pub struct ResubscribeCancellable<Upstream> {
+    state: Arc<Mutex<State<Upstream>>>,
+}
+
+impl<Upstream> Clone for ResubscribeCancellable<Upstream> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for ResubscribeCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        let (upstream, notifier) = {
+            let mut state = self.state.lock().await;
+            state.is_cancelled = true;
+            (state.upstream.clone(), state.notifier.take())
+        };
+        if let Some(upstream) = upstream {
+            upstream.cancel().await;
+        }
+        if let Some(cancel) = notifier {
+            cancel().await;
+        }
+    }
+}
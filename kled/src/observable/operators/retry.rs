@@ -0,0 +1,369 @@
+use super::resubscribe::*;
+use crate::core;
+use crate::subject::UnicastSubject;
+use crate::Never;
+use async_trait::async_trait;
+use futures::future::{self, BoxFuture};
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::time::*;
+}
+use mock::*;
+
+pub type RetryCancellable<Upstream> = ResubscribeCancellable<Upstream>;
+
+pub struct Retry<Observable, Cancellable, Item, Error>(
+    Resubscribe<Observable, Cancellable, Item, Error>,
+);
+
+impl<Observable, Cancellable, Item, Error> Retry<Observable, Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    /// Resubscribes to `observable` on up to `count` errors in total.
+    pub fn new(observable: Observable, count: usize) -> Self {
+        let mut retries = 0;
+        Self::with_retry_fn(observable, move |error, resubscriber| {
+            if retries < count {
+                retries += 1;
+                Box::pin(resubscriber.resubscribe())
+            } else {
+                Box::pin(resubscriber.on_error(error))
+            }
+        })
+    }
+
+    /// Resubscribes to `observable` on each error for which `predicate`
+    /// returns true.
+    pub fn with_predicate<Predicate>(observable: Observable, mut predicate: Predicate) -> Self
+    where
+        Predicate: FnMut(&Error) -> bool + Send + 'static,
+    {
+        Self::with_retry_fn(observable, move |error, resubscriber| {
+            if predicate(&error) {
+                Box::pin(resubscriber.resubscribe())
+            } else {
+                Box::pin(resubscriber.on_error(error))
+            }
+        })
+    }
+
+    /// Resubscribes to `observable` on each error after an exponentially
+    /// growing delay, which is timed on the given scheduler. Once the upstream
+    /// emits an item, the delay starts over.
+    pub fn with_backoff<Scheduler>(
+        observable: Observable,
+        initial: Duration,
+        max: Duration,
+        factor: f64,
+        jitter: f64,
+        scheduler: Scheduler,
+    ) -> Self
+    where
+        Scheduler: core::Scheduler,
+    {
+        let mut attempts = 0;
+        Self::with_retry_fn(observable, move |_, resubscriber| {
+            if resubscriber.has_emitted {
+                attempts = 0;
+            }
+            let delay = backoff_delay(initial, max, factor, jitter, attempts);
+            attempts = attempts.saturating_add(1);
+            let (delay, handle) = future::abortable(Delay::new(delay));
+            let cancel: BoxedCancel = Box::new(move || {
+                handle.abort();
+                Box::pin(future::ready(()))
+            });
+            scheduler.schedule(async move {
+                if resubscriber.wait_on(cancel).await && delay.await.is_ok() {
+                    resubscriber.resubscribe().await;
+                }
+            });
+            Box::pin(future::ready(()))
+        })
+    }
+
+    /// Passes the observable of all errors to `retry_when` and subscribes to
+    /// the returned notifier before subscribing to `observable`. Each item of
+    /// the notifier resubscribes to `observable` if it failed, while the
+    /// returned observable terminates once the notifier terminates.
+    pub fn when<RetryWhen, Notifier, NotifierCancellable, NotifierItem>(
+        observable: Observable,
+        retry_when: RetryWhen,
+    ) -> Self
+    where
+        RetryWhen: FnOnce(UnicastSubject<Never, Error, Error>) -> Notifier + Send + 'static,
+        Notifier: core::Observable<NotifierCancellable, NotifierItem, Error> + Send + 'static,
+        NotifierCancellable: core::Cancellable + Send + Sync + 'static,
+        NotifierItem: Send + 'static,
+    {
+        Self(Resubscribe::with_setup(observable, move |resubscriber| {
+            Box::pin(async move {
+                let errors = UnicastSubject::default();
+                retry_when(errors.clone())
+                    .subscribe(NotifierObserver::new(resubscriber))
+                    .await;
+                let on_termination: OnTermination<Cancellable, Item, Error> =
+                    Box::new(move |termination, resubscriber| match termination {
+                        Termination::Completed => Box::pin(resubscriber.on_completed()),
+                        Termination::Failed(error) => {
+                            let mut errors = errors.clone();
+                            Box::pin(async move {
+                                core::Observer::<Never, _, _>::on_next(&mut errors, error).await;
+                            })
+                        }
+                    });
+                on_termination
+            })
+        }))
+    }
+
+    fn with_retry_fn<F>(observable: Observable, mut retry_fn: F) -> Self
+    where
+        F: FnMut(Error, Resubscriber<Cancellable, Item, Error>) -> BoxFuture<'static, ()>
+            + Send
+            + 'static,
+    {
+        Self(Resubscribe::new(
+            observable,
+            move |termination, resubscriber| match termination {
+                Termination::Completed => Box::pin(resubscriber.on_completed()),
+                Termination::Failed(error) => retry_fn(error, resubscriber),
+            },
+        ))
+    }
+}
+
+fn backoff_delay(
+    initial: Duration,
+    max: Duration,
+    factor: f64,
+    jitter: f64,
+    attempt: usize,
+) -> Duration {
+    let exponent = attempt.min(i32::MAX as usize) as i32;
+    let secs = initial.as_secs_f64()
+        * factor.powi(exponent)
+        * (1.0 + jitter * (2.0 * fastrand::f64() - 1.0));
+    Duration::from_secs_f64(secs.min(max.as_secs_f64()))
+}
+
+#[async_trait]
+impl<Observable, Cancellable, Item, Error>
+    core::Observable<RetryCancellable<Cancellable>, Item, Error>
+    for Retry<Observable, Cancellable, Item, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Clone + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<RetryCancellable<Cancellable>, Item, Error> + Send + 'static,
+    {
+        self.0.subscribe(observer).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::cancellable::*;
+    use crate::core;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Returns an observable which emits `0` unless `is_silent` and then fails
+    /// with the number of the attempt until it failed `failures` times, after
+    /// which it emits `1` and completes.
+    fn flaky_observable(
+        failures: usize,
+        is_silent: bool,
+    ) -> impl core::Observable<ArcCancellable, i32, usize> + Clone {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        observable::create(move |mut emitter| {
+            let attempts = attempts.clone();
+            async move {
+                if !is_silent {
+                    emitter.on_next(0).await;
+                }
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                if attempt < failures {
+                    emitter.on_error(attempt).await;
+                } else {
+                    emitter.on_next(1).await;
+                    emitter.on_completed().await;
+                }
+            }
+        })
+    }
+
+    #[async_std::test]
+    async fn retry() {
+        let test_observer = TestObserver::default();
+        flaky_observable(2, false)
+            .retry(2)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 0, 0, 1]);
+    }
+
+    #[async_std::test]
+    async fn retry_many() {
+        let test_observer = TestObserver::default();
+        flaky_observable(20000, true)
+            .retry(20000)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1]);
+    }
+
+    #[async_std::test]
+    async fn retry_if() {
+        let test_observer = TestObserver::default();
+        flaky_observable(2, false)
+            .retry_if(|attempt| *attempt == 0)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.items().await, vec![0, 0]);
+        assert_eq!(test_observer.error().await, Some(1));
+    }
+
+    #[chronobreak::test]
+    async fn retry_with_backoff() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let start = Instant::now();
+        flaky_observable(2, true)
+            .retry_with_backoff(
+                Duration::from_millis(10),
+                Duration::from_millis(15),
+                2.0,
+                0.0,
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() >= start + Duration::from_millis(25));
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1]);
+    }
+
+    #[chronobreak::test]
+    async fn retry_with_backoff_reset() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let start = Instant::now();
+        flaky_observable(3, false)
+            .retry_with_backoff(
+                Duration::from_millis(10),
+                Duration::from_secs(10),
+                1000.0,
+                0.0,
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        scheduler.join();
+
+        assert!(Instant::now() < start + Duration::from_secs(10));
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 0, 0, 0, 1]);
+    }
+
+    #[chronobreak::test]
+    async fn cancel_backoff() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_observer = TestObserver::default();
+        let start = Instant::now();
+        flaky_observable(1, true)
+            .retry_with_backoff(
+                Duration::from_secs(10),
+                Duration::from_secs(10),
+                1.0,
+                0.0,
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+        scheduler.join();
+
+        assert!(Instant::now() < start + Duration::from_secs(10));
+        assert_eq!(test_observer.items().await, Vec::<i32>::new());
+    }
+
+    #[async_std::test]
+    async fn retry_when() {
+        let test_observer = TestObserver::default();
+        flaky_observable(2, false)
+            .retry_when(|errors| errors.map(|attempt| attempt))
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 0, 0, 1]);
+    }
+
+    #[async_std::test]
+    async fn retry_when_notifier_completes() {
+        let test_observer = TestObserver::default();
+        let notifier = TestObservable::default().annotate_item_type(());
+        let retry_notifier = notifier.clone();
+        flaky_observable(2, false)
+            .retry_when(move |_| retry_notifier)
+            .subscribe(test_observer.clone())
+            .await;
+        notifier.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn retry_when_notifier() {
+        let test_observer = TestObserver::default();
+        let notifier = TestObservable::default().annotate_item_type(());
+        let retry_notifier = notifier.clone();
+        flaky_observable(1, false)
+            .retry_when(move |_| retry_notifier)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Subscribed);
+        notifier.emit(()).await;
+
+        assert!(notifier.is_cancelled().await);
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 0, 1]);
+    }
+
+    #[async_std::test]
+    async fn cancel_notifier() {
+        let mut test_observer = TestObserver::default();
+        let notifier = TestObservable::default().annotate_item_type(());
+        let retry_notifier = notifier.clone();
+        flaky_observable(1, false)
+            .retry_when(move |_| retry_notifier)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+
+        assert!(notifier.is_cancelled().await);
+    }
+}
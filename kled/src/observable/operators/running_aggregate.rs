@@ -0,0 +1,90 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable", item = "Output")]
+pub struct RunningAggregate<Aggregator, Output>
+where
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+{
+    aggregator: Aggregator,
+}
+
+#[derive(new)]
+struct RunningAggregateObserver<Observer, Aggregator> {
+    observer: Observer,
+    aggregator: Aggregator,
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Aggregator, Output>
+    core::Observer<Cancellable, Item, Error> for RunningAggregateObserver<Observer, Aggregator>
+where
+    Observer: core::Observer<Cancellable, Output, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Aggregator: core::Aggregator<Item, Output = Output> + Send,
+    Output: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.aggregator.update(item);
+        if let Some(output) = self.aggregator.output() {
+            self.observer.on_next(output).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn running_count() {
+        let test_observer = TestObserver::default();
+        vec!['a', 'b', 'c']
+            .into_observable()
+            .running_count()
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn running_average() {
+        let test_observer = TestObserver::default();
+        vec![2, 4, 0]
+            .into_observable()
+            .running_average()
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.items().await, vec![2.0, 3.0, 2.0]);
+    }
+
+    #[async_std::test]
+    async fn running_average_by() {
+        let test_observer = TestObserver::default();
+        vec![2usize, 4, 0]
+            .into_observable()
+            .running_average_by(|item| item as f64)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.items().await, vec![2.0, 3.0, 2.0]);
+    }
+}
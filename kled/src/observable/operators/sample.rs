@@ -0,0 +1,191 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(type = "observable", subscription = "SampleCancellable<Cancellable>")]
+pub struct Sample<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    period: Duration,
+    scheduler: Scheduler,
+}
+
+pub struct SampleObserver<Observer, Item, Scheduler> {
+    data: Arc<Mutex<Data<Observer, Item>>>,
+    cancelled: Arc<AtomicBool>,
+    start: Instant,
+    period: Duration,
+    scheduler: Scheduler,
+}
+
+struct Data<Observer, Item> {
+    observer: Observer,
+    latest: Option<Item>,
+    is_scheduled: bool,
+    is_done: bool,
+}
+
+impl<Observer, Item, Scheduler> SampleObserver<Observer, Item, Scheduler> {
+    pub fn new(observer: Observer, period: Duration, scheduler: Scheduler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                observer,
+                latest: None,
+                is_scheduled: false,
+                is_done: false,
+            })),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            start: Instant::now(),
+            period,
+            scheduler,
+        }
+    }
+
+    /// Returns the time until the end of the current sampling period.
+    fn until_next_sample(&self) -> Duration {
+        let period = self.period.as_nanos().max(1);
+        let elapsed = Instant::now().duration_since(self.start).as_nanos();
+        Duration::from_nanos((period - elapsed % period) as u64)
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for SampleObserver<Observer, Item, Scheduler>
+where
+    Observer: core::Observer<SampleCancellable<Cancellable>, Item, Error> + Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.data
+            .lock()
+            .await
+            .observer
+            .on_subscribe(SampleCancellable::new(cancellable, self.cancelled.clone()))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        data.latest = Some(item);
+        if data.is_scheduled {
+            return;
+        }
+        data.is_scheduled = true;
+        drop(data);
+
+        let data = self.data.clone();
+        let cancelled = self.cancelled.clone();
+        let delay = self.until_next_sample();
+        self.scheduler.schedule(async move {
+            Delay::new(delay).await;
+            let mut data = data.lock().await;
+            data.is_scheduled = false;
+            if data.is_done || cancelled.load(Ordering::Relaxed) {
+                return;
+            }
+            if let Some(item) = data.latest.take() {
+                data.observer.on_next(item).await;
+            }
+        });
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_completed().await;
+        }
+    }
+}
+
+#[derive(new, Clone)]
+pub struct SampleCancellable<Upstream> {
+    upstream: Upstream,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for SampleCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.upstream.cancel().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn sample() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .sample(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        test_observable.emit_all(vec![2, 3, 4]).await;
+        scheduler.join();
+        test_observable.emit(5).await;
+        test_observable.emit_on_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 4]);
+    }
+
+    #[chronobreak::test]
+    async fn throttle_last() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .throttle_last(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1, 2]).await;
+        scheduler.join();
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![2]);
+    }
+}
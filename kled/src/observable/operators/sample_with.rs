@@ -0,0 +1,238 @@
+use crate::cancellable::*;
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "observable",
+    subscription = "SampleWithCancellable<Cancellable, SamplerCancellable>"
+)]
+pub struct SampleWith<Sampler, SamplerCancellable, SamplerItem>
+where
+    Sampler: core::Observable<SamplerCancellable, SamplerItem, Error>,
+    SamplerCancellable: core::Cancellable + Send + Sync + 'static,
+    SamplerItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    sampler: Sampler,
+}
+
+struct SampleWithObserver<Observer, Item, Sampler, SamplerCancellable, SamplerItem> {
+    data: Arc<Mutex<Data<Observer, Item>>>,
+    sampler: Option<Sampler>,
+    stub: LazyCancellableStub<SamplerCancellable>,
+    phantom: PhantomData<SamplerItem>,
+    sampler_cancellable: Option<LazyCancellable<SamplerCancellable>>,
+}
+
+struct Data<Observer, Item> {
+    observer: Observer,
+    latest: Option<Item>,
+    is_done: bool,
+}
+
+impl<Observer, Item, Sampler, SamplerCancellable, SamplerItem>
+    SampleWithObserver<Observer, Item, Sampler, SamplerCancellable, SamplerItem>
+{
+    fn new(observer: Observer, sampler: Sampler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                observer,
+                latest: None,
+                is_done: false,
+            })),
+            sampler: Some(sampler),
+            stub: LazyCancellableStub::default(),
+            phantom: PhantomData,
+            sampler_cancellable: None,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Sampler, SamplerCancellable, SamplerItem>
+    core::Observer<Cancellable, Item, Error>
+    for SampleWithObserver<Observer, Item, Sampler, SamplerCancellable, SamplerItem>
+where
+    Observer: core::Observer<SampleWithCancellable<Cancellable, SamplerCancellable>, Item, Error>
+        + Send
+        + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Sampler: core::Observable<SamplerCancellable, SamplerItem, Error> + Send,
+    SamplerCancellable: core::Cancellable + Send + Sync + 'static,
+    SamplerItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.sampler_cancellable = Some(self.stub.cancellable());
+        self.data
+            .lock()
+            .await
+            .observer
+            .on_subscribe(SampleWithCancellable::new(
+                cancellable.clone(),
+                self.stub.cancellable(),
+            ))
+            .await;
+        if let Some(sampler) = self.sampler.take() {
+            sampler
+                .subscribe(SamplerObserver {
+                    data: self.data.clone(),
+                    upstream: cancellable,
+                    stub: std::mem::take(&mut self.stub),
+                })
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.latest = Some(item);
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_error(error).await;
+            if let Some(sampler) = self.sampler_cancellable.as_ref() {
+                core::Cancellable::cancel(sampler).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_completed().await;
+            if let Some(sampler) = self.sampler_cancellable.as_ref() {
+                core::Cancellable::cancel(sampler).await;
+            }
+        }
+    }
+}
+
+struct SamplerObserver<Observer, Cancellable, Item, SamplerCancellable> {
+    data: Arc<Mutex<Data<Observer, Item>>>,
+    upstream: Cancellable,
+    stub: LazyCancellableStub<SamplerCancellable>,
+}
+
+#[async_trait]
+impl<Observer, Cancellable, Item, Error, SamplerCancellable, SamplerItem>
+    core::Observer<SamplerCancellable, SamplerItem, Error>
+    for SamplerObserver<Observer, Cancellable, Item, SamplerCancellable>
+where
+    Observer:
+        core::Observer<SampleWithCancellable<Cancellable, SamplerCancellable>, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SamplerCancellable: core::Cancellable + Send + Sync + 'static,
+    SamplerItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: SamplerCancellable) {
+        self.stub.set_cancellable(cancellable).await;
+    }
+
+    async fn on_next(&mut self, _: SamplerItem) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        if let Some(item) = data.latest.take() {
+            data.observer.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_error(error).await;
+            self.upstream.cancel().await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_completed().await;
+            self.upstream.cancel().await;
+        }
+    }
+}
+
+#[derive(new, Clone)]
+pub struct SampleWithCancellable<Upstream, Sampler> {
+    upstream: Upstream,
+    sampler: LazyCancellable<Sampler>,
+}
+
+#[async_trait]
+impl<Upstream, Sampler> core::Cancellable for SampleWithCancellable<Upstream, Sampler>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    Sampler: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.sampler.cancel().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn sample_with() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let sampler = TestObservable::default();
+        test_observable
+            .clone()
+            .sample_with(sampler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        sampler.emit(()).await;
+        test_observable.emit_all(vec![0, 1]).await;
+        sampler.emit(()).await;
+        sampler.emit(()).await;
+        test_observable.emit(2).await;
+        sampler.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1]);
+        assert!(test_observable.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn completed() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let sampler = TestObservable::default().annotate_item_type(());
+        test_observable
+            .clone()
+            .sample_with(sampler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, Vec::<i32>::new());
+        assert!(sampler.is_cancelled().await);
+    }
+}
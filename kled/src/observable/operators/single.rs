@@ -0,0 +1,135 @@
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+
+#[operator(type = "observable", error = "observable::Error<Error>")]
+pub struct Single {}
+
+struct SingleObserver<Observer, Cancellable, Item> {
+    observer: Observer,
+    upstream: Option<Cancellable>,
+    item: Option<Item>,
+    is_done: bool,
+}
+
+impl<Observer, Cancellable, Item> SingleObserver<Observer, Cancellable, Item> {
+    fn new(observer: Observer) -> Self {
+        Self {
+            observer,
+            upstream: None,
+            item: None,
+            is_done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error> core::Observer<Cancellable, Item, Error>
+    for SingleObserver<Observer, Cancellable, Item>
+where
+    Observer: core::Observer<Cancellable, Item, observable::Error<Error>> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.upstream = Some(cancellable.clone());
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.is_done {
+            return;
+        }
+        if self.item.is_none() {
+            self.item = Some(item);
+            return;
+        }
+        self.is_done = true;
+        self.item = None;
+        self.observer
+            .on_error(observable::Error::TooManyElements)
+            .await;
+        if let Some(upstream) = self.upstream.as_ref() {
+            upstream.cancel().await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if !self.is_done {
+            self.is_done = true;
+            self.observer
+                .on_error(observable::Error::Upstream(error))
+                .await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if !self.is_done {
+            self.is_done = true;
+            match self.item.take() {
+                Some(item) => {
+                    self.observer.on_next(item).await;
+                    self.observer.on_completed().await;
+                }
+                None => {
+                    self.observer
+                        .on_error(observable::Error::NoSuchElement)
+                        .await
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::{self, *};
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn single() {
+        let test_observer = TestObserver::default();
+        vec![0]
+            .into_observable()
+            .single()
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn empty() {
+        let test_observer = TestObserver::default();
+        Vec::<i32>::new()
+            .into_observable()
+            .single()
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::NoSuchElement)
+        );
+    }
+
+    #[async_std::test]
+    async fn too_many_elements() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .single()
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+
+        assert_eq!(test_observer.items().await, Vec::<i32>::new());
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::TooManyElements)
+        );
+        assert!(test_observable.is_cancelled().await);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::core;
+use crate::sliding_window::{SlidingBuffer, SlidingWindow};
+use async_trait::async_trait;
+
+#[operator(type = "observable", item = "SlidingWindow<Item>")]
+pub struct Sliding
+where
+    Item: Sync,
+{
+    count: usize,
+}
+
+struct SlidingObserver<Observer, Item> {
+    observer: Observer,
+    buffer: SlidingBuffer<Item>,
+}
+
+impl<Observer, Item> SlidingObserver<Observer, Item> {
+    fn new(observer: Observer, count: usize) -> Self {
+        Self {
+            observer,
+            buffer: SlidingBuffer::new(count),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error> core::Observer<Cancellable, Item, Error>
+    for SlidingObserver<Observer, Item>
+where
+    Observer: core::Observer<Cancellable, SlidingWindow<Item>, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + Sync + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(window) = self.buffer.push(item) {
+            self.observer.on_next(window).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn sliding() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .sliding(3)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        let items: Vec<Vec<i32>> = test_observer
+            .items()
+            .await
+            .iter()
+            .map(|window| window.to_vec())
+            .collect();
+        assert_eq!(items, vec![vec![0, 1, 2], vec![1, 2, 3]]);
+    }
+
+    #[async_std::test]
+    async fn too_few_items() {
+        let test_observer = TestObserver::default();
+        vec![0, 1]
+            .into_observable()
+            .sliding(3)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert!(test_observer.items().await.is_empty());
+    }
+}
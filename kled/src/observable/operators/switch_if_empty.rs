@@ -0,0 +1,181 @@
+use crate::cancellable::*;
+use crate::core;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[operator(
+    type = "observable",
+    subscription = "SwitchIfEmptyCancellable<Cancellable, FallbackCancellable>"
+)]
+pub struct SwitchIfEmpty<Fallback, FallbackCancellable>
+where
+    Fallback: core::Observable<FallbackCancellable, Item, Error>,
+    FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fallback: Fallback,
+}
+
+struct SwitchIfEmptyObserver<Observer, Fallback, FallbackCancellable> {
+    observer: Option<Observer>,
+    fallback: Option<Fallback>,
+    stub: LazyCancellableStub<FallbackCancellable>,
+    has_items: bool,
+}
+
+impl<Observer, Fallback, FallbackCancellable>
+    SwitchIfEmptyObserver<Observer, Fallback, FallbackCancellable>
+{
+    fn new(observer: Observer, fallback: Fallback) -> Self {
+        Self {
+            observer: Some(observer),
+            fallback: Some(fallback),
+            stub: LazyCancellableStub::default(),
+            has_items: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Fallback, FallbackCancellable>
+    core::Observer<Cancellable, Item, Error>
+    for SwitchIfEmptyObserver<Observer, Fallback, FallbackCancellable>
+where
+    Observer: core::Observer<SwitchIfEmptyCancellable<Cancellable, FallbackCancellable>, Item, Error>
+        + Send
+        + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Fallback: core::Observable<FallbackCancellable, Item, Error> + Send,
+    FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer
+                .on_subscribe(SwitchIfEmptyCancellable::new(
+                    cancellable,
+                    self.stub.cancellable(),
+                ))
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.has_items = true;
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if self.has_items {
+            if let Some(observer) = self.observer.as_mut() {
+                observer.on_completed().await;
+            }
+            return;
+        }
+        if let (Some(observer), Some(fallback)) = (self.observer.take(), self.fallback.take()) {
+            fallback
+                .subscribe(FallbackObserver {
+                    observer,
+                    stub: std::mem::take(&mut self.stub),
+                    phantom: PhantomData,
+                })
+                .await;
+        }
+    }
+}
+
+struct FallbackObserver<Observer, Cancellable, FallbackCancellable> {
+    observer: Observer,
+    stub: LazyCancellableStub<FallbackCancellable>,
+    phantom: PhantomData<Cancellable>,
+}
+
+#[async_trait]
+impl<Observer, Cancellable, Item, Error, FallbackCancellable>
+    core::Observer<FallbackCancellable, Item, Error>
+    for FallbackObserver<Observer, Cancellable, FallbackCancellable>
+where
+    Observer: core::Observer<SwitchIfEmptyCancellable<Cancellable, FallbackCancellable>, Item, Error>
+        + Send,
+    Cancellable: Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: FallbackCancellable) {
+        self.stub.set_cancellable(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[derive(new, Clone)]
+pub struct SwitchIfEmptyCancellable<Upstream, Fallback> {
+    upstream: Upstream,
+    fallback: LazyCancellable<Fallback>,
+}
+
+#[async_trait]
+impl<Upstream, Fallback> core::Cancellable for SwitchIfEmptyCancellable<Upstream, Fallback>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    Fallback: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.fallback.cancel().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn switch_if_empty() {
+        let test_observer = TestObserver::default();
+        Vec::new()
+            .into_observable()
+            .switch_if_empty(vec![0, 1].into_observable())
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1]);
+    }
+
+    #[async_std::test]
+    async fn not_empty() {
+        let test_observer = TestObserver::default();
+        let fallback = TestObservable::default();
+        vec![0]
+            .into_observable()
+            .switch_if_empty(fallback.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert!(!fallback.has_observer().await);
+    }
+}
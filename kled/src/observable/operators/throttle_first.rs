@@ -0,0 +1,174 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(
+    type = "observable",
+    subscription = "ThrottleFirstCancellable<Cancellable>"
+)]
+pub struct ThrottleFirst<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+pub struct ThrottleFirstObserver<Observer, Scheduler> {
+    data: Arc<Mutex<Data<Observer>>>,
+    cancelled: Arc<AtomicBool>,
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+struct Data<Observer> {
+    observer: Observer,
+    is_gated: bool,
+    is_done: bool,
+}
+
+impl<Observer, Scheduler> ThrottleFirstObserver<Observer, Scheduler> {
+    pub fn new(observer: Observer, duration: Duration, scheduler: Scheduler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                observer,
+                is_gated: false,
+                is_done: false,
+            })),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            duration,
+            scheduler,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for ThrottleFirstObserver<Observer, Scheduler>
+where
+    Observer: core::Observer<ThrottleFirstCancellable<Cancellable>, Item, Error> + Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.data
+            .lock()
+            .await
+            .observer
+            .on_subscribe(ThrottleFirstCancellable::new(
+                cancellable,
+                self.cancelled.clone(),
+            ))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done || data.is_gated {
+            return;
+        }
+        data.is_gated = true;
+        data.observer.on_next(item).await;
+        drop(data);
+
+        let data = self.data.clone();
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            Delay::new(duration).await;
+            data.lock().await.is_gated = false;
+        });
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_completed().await;
+        }
+    }
+}
+
+#[derive(new, Clone)]
+pub struct ThrottleFirstCancellable<Upstream> {
+    upstream: Upstream,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for ThrottleFirstCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.upstream.cancel().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn throttle_first() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .throttle_first(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        scheduler.join();
+        test_observable.emit_all(vec![2, 3]).await;
+        test_observable.emit_on_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn error() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(0);
+        test_observable
+            .clone()
+            .throttle_first(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(()).await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert_eq!(test_observer.error().await, Some(()));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(
+    type = "observable",
+    subscription = "ThrottleLatestCancellable<Cancellable>"
+)]
+pub struct ThrottleLatest<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+    emit_last: bool,
+}
+
+pub struct ThrottleLatestObserver<Observer, Item, Scheduler> {
+    data: Arc<Mutex<Data<Observer, Item>>>,
+    cancelled: Arc<AtomicBool>,
+    duration: Duration,
+    scheduler: Scheduler,
+    emit_last: bool,
+}
+
+struct Data<Observer, Item> {
+    observer: Observer,
+    latest: Option<Item>,
+    is_gated: bool,
+    is_done: bool,
+}
+
+impl<Observer, Item, Scheduler> ThrottleLatestObserver<Observer, Item, Scheduler> {
+    pub fn new(
+        observer: Observer,
+        duration: Duration,
+        scheduler: Scheduler,
+        emit_last: bool,
+    ) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                observer,
+                latest: None,
+                is_gated: false,
+                is_done: false,
+            })),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            duration,
+            scheduler,
+            emit_last,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for ThrottleLatestObserver<Observer, Item, Scheduler>
+where
+    Observer: core::Observer<ThrottleLatestCancellable<Cancellable>, Item, Error> + Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.data
+            .lock()
+            .await
+            .observer
+            .on_subscribe(ThrottleLatestCancellable::new(
+                cancellable,
+                self.cancelled.clone(),
+            ))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        if data.is_gated {
+            data.latest = Some(item);
+            return;
+        }
+        data.is_gated = true;
+        data.observer.on_next(item).await;
+        drop(data);
+
+        let data = self.data.clone();
+        let cancelled = self.cancelled.clone();
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            loop {
+                Delay::new(duration).await;
+                let mut data = data.lock().await;
+                if data.is_done || cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+                match data.latest.take() {
+                    Some(item) => data.observer.on_next(item).await,
+                    None => {
+                        data.is_gated = false;
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if data.is_done {
+            return;
+        }
+        data.is_done = true;
+        if self.emit_last {
+            if let Some(item) = data.latest.take() {
+                data.observer.on_next(item).await;
+            }
+        }
+        data.observer.on_completed().await;
+    }
+}
+
+#[derive(new, Clone)]
+pub struct ThrottleLatestCancellable<Upstream> {
+    upstream: Upstream,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for ThrottleLatestCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.upstream.cancel().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn throttle_latest() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .throttle_latest(Duration::from_millis(10), scheduler.clone(), true)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1, 2]).await;
+        scheduler.join();
+        test_observable.emit_all(vec![3, 4]).await;
+        test_observable.emit_on_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 2, 3, 4]);
+    }
+
+    #[chronobreak::test]
+    async fn drop_last() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .throttle_latest(Duration::from_millis(10), scheduler.clone(), false)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        test_observable.emit_on_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0]);
+    }
+}
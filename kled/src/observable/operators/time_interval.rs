@@ -0,0 +1,91 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(type = "observable", item = "(Duration, Item)")]
+pub struct TimeInterval<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    scheduler: Scheduler,
+}
+
+#[derive(new)]
+struct TimeIntervalObserver<Observer, Scheduler> {
+    observer: Observer,
+    scheduler: Scheduler,
+    #[new(default)]
+    last: Option<Instant>,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for TimeIntervalObserver<Observer, Scheduler>
+where
+    Observer: core::Observer<Cancellable, (Duration, Item), Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.last = Some(self.scheduler.now());
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let now = self.scheduler.now();
+        let last = self.last.replace(now).unwrap_or(now);
+        self.observer.on_next((now - last, item)).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+
+    #[chronobreak::test]
+    async fn time_interval() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .time_interval(scheduler)
+            .subscribe(test_observer.clone())
+            .await;
+        clock::advance(Duration::from_millis(10));
+        test_observable.emit(0).await;
+        test_observable.emit(1).await;
+        clock::advance(Duration::from_millis(20));
+        test_observable.emit(2).await;
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(
+            test_observer.items().await,
+            vec![
+                (Duration::from_millis(10), 0),
+                (Duration::from_millis(0), 1),
+                (Duration::from_millis(20), 2),
+            ]
+        );
+    }
+}
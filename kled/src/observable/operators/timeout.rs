@@ -0,0 +1,450 @@
+use crate::core;
+use crate::observable;
+use crate::scheduler::{unbounded, DelaySender};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use futures::prelude::*;
+
+#[chronobreak]
+mod mock {
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+type BoxedSource<Cancellable, Item, Error> =
+    Box<dyn FnOnce(FallbackObserver<Cancellable, Item, Error>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedObserver<Cancellable, Item, Error> = Box<
+    dyn core::Observer<TimeoutCancellable<Cancellable>, Item, observable::Error<Error>>
+        + Send
+        + 'static,
+>;
+
+type BoxedCancel = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+pub type TimeoutFn<Item> = Box<dyn FnMut(&Item) -> Duration + Send>;
+
+/// A source which is switched to once the upstream timed out.
+pub struct TimeoutFallback<Cancellable, Item, Error> {
+    source: BoxedSource<Cancellable, Item, Error>,
+}
+
+impl<Cancellable, Item, Error> TimeoutFallback<Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn new<Observable, FallbackCancellable>(observable: Observable) -> Self
+    where
+        Observable: core::Observable<FallbackCancellable, Item, Error> + Send + 'static,
+        FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Self {
+            source: Box::new(move |observer| Box::pin(observable.subscribe(observer))),
+        }
+    }
+}
+
+#[operator(
+    type = "observable",
+    subscription = "TimeoutCancellable<Cancellable>",
+    error = "observable::Error<Error>"
+)]
+pub struct Timeout<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    first: Duration,
+    timeout_fn: TimeoutFn<Item>,
+    scheduler: Scheduler,
+    fallback: Option<TimeoutFallback<Cancellable, Item, Error>>,
+}
+
+pub struct TimeoutObserver<Cancellable, Item, Error, Scheduler> {
+    data: Arc<Mutex<Data<Cancellable, Item, Error>>>,
+    sender: Option<DelaySender<usize>>,
+    first: Duration,
+    timeout_fn: TimeoutFn<Item>,
+    scheduler: Scheduler,
+}
+
+struct Data<Cancellable, Item, Error> {
+    observer: BoxedObserver<Cancellable, Item, Error>,
+    shared: Option<Arc<Shared<Cancellable>>>,
+    fallback: Option<TimeoutFallback<Cancellable, Item, Error>>,
+    index: usize,
+    is_switched: bool,
+    is_done: bool,
+}
+
+struct Shared<Upstream> {
+    upstream: Upstream,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    fallback: Option<BoxedCancel>,
+    is_cancelled: bool,
+}
+
+impl<Cancellable, Item, Error, Scheduler> TimeoutObserver<Cancellable, Item, Error, Scheduler> {
+    pub fn new<Observer>(
+        observer: Observer,
+        first: Duration,
+        timeout_fn: TimeoutFn<Item>,
+        scheduler: Scheduler,
+        fallback: Option<TimeoutFallback<Cancellable, Item, Error>>,
+    ) -> Self
+    where
+        Observer: core::Observer<TimeoutCancellable<Cancellable>, Item, observable::Error<Error>>
+            + Send
+            + 'static,
+    {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                observer: Box::new(observer),
+                shared: None,
+                fallback,
+                index: 0,
+                is_switched: false,
+                is_done: false,
+            })),
+            sender: None,
+            first,
+            timeout_fn,
+            scheduler,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for TimeoutObserver<Cancellable, Item, Error, Scheduler>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let shared = Arc::new(Shared {
+            upstream: cancellable,
+            state: Mutex::new(State::default()),
+        });
+        {
+            let mut data = self.data.lock().await;
+            data.shared = Some(shared.clone());
+            data.observer
+                .on_subscribe(TimeoutCancellable { shared })
+                .await;
+        }
+
+        let (mut sender, mut receiver) = unbounded();
+        sender.send_delayed(self.first, 0).await.unwrap();
+        self.sender = Some(sender);
+        let data = self.data.clone();
+        self.scheduler.schedule(async move {
+            while let Some(index) = receiver.next().await {
+                let mut guard = data.lock().await;
+                let shared = match guard.shared.clone() {
+                    Some(shared) => shared,
+                    None => break,
+                };
+                if guard.is_done || guard.is_switched || shared.state.lock().await.is_cancelled {
+                    break;
+                }
+                if index != guard.index {
+                    continue;
+                }
+                guard.is_switched = true;
+                shared.upstream.cancel().await;
+                match guard.fallback.take() {
+                    Some(fallback) => {
+                        drop(guard);
+                        (fallback.source)(FallbackObserver { data }).await;
+                    }
+                    None => {
+                        guard.is_done = true;
+                        guard.observer.on_error(observable::Error::Timeout).await;
+                    }
+                }
+                break;
+            }
+        });
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if data.is_done || data.is_switched {
+            return;
+        }
+        let timeout = (self.timeout_fn)(&item);
+        data.index += 1;
+        // The timer task stops once the subscription was cancelled, in which
+        // case the item is dropped.
+        if let Some(sender) = self.sender.as_mut() {
+            if sender.send_delayed(timeout, data.index).await.is_err() {
+                self.sender = None;
+                return;
+            }
+        }
+        data.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.sender = None;
+        let mut data = self.data.lock().await;
+        if !data.is_done && !data.is_switched {
+            data.is_done = true;
+            data.observer
+                .on_error(observable::Error::Upstream(error))
+                .await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        self.sender = None;
+        let mut data = self.data.lock().await;
+        if !data.is_done && !data.is_switched {
+            data.is_done = true;
+            data.observer.on_completed().await;
+        }
+    }
+}
+
+struct FallbackObserver<Cancellable, Item, Error> {
+    data: Arc<Mutex<Data<Cancellable, Item, Error>>>,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, FallbackCancellable> core::Observer<FallbackCancellable, Item, Error>
+    for FallbackObserver<Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FallbackCancellable: core::Cancellable + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: FallbackCancellable) {
+        let shared = match self.data.lock().await.shared.clone() {
+            Some(shared) => shared,
+            None => return,
+        };
+        let cancel: BoxedCancel = Arc::new(move || {
+            let cancellable = cancellable.clone();
+            Box::pin(async move { cancellable.cancel().await })
+        });
+        let is_cancelled = {
+            let mut state = shared.state.lock().await;
+            state.fallback = Some(cancel.clone());
+            state.is_cancelled
+        };
+        if is_cancelled {
+            cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.observer.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer
+                .on_error(observable::Error::Upstream(error))
+                .await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.is_done = true;
+            data.observer.on_completed().await;
+        }
+    }
+}
+
+pub struct TimeoutCancellable<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+impl<Upstream> Clone for TimeoutCancellable<Upstream> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for TimeoutCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        let fallback = {
+            let mut state = self.shared.state.lock().await;
+            state.is_cancelled = true;
+            state.fallback.take()
+        };
+        self.shared.upstream.cancel().await;
+        if let Some(cancel) = fallback {
+            cancel().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::{self, *};
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn timeout() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .timeout(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        scheduler.join();
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await, vec![0, 1]);
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::Timeout)
+        );
+    }
+
+    #[chronobreak::test]
+    async fn upstream_error() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(0);
+        test_observable
+            .clone()
+            .timeout_first(
+                Duration::from_millis(100),
+                Duration::from_millis(10),
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_error(()).await;
+        scheduler.join();
+
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::Upstream(()))
+        );
+    }
+
+    #[chronobreak::test]
+    async fn timeout_with() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .timeout_with(
+                Duration::from_millis(100),
+                |item| Duration::from_millis(*item),
+                scheduler.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(10).await;
+        scheduler.join();
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await, vec![10]);
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::Timeout)
+        );
+    }
+
+    #[chronobreak::test]
+    async fn timeout_or() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let fallback = TestObservable::default();
+        test_observable
+            .clone()
+            .timeout_or(
+                Duration::from_millis(10),
+                scheduler.clone(),
+                fallback.clone(),
+            )
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        scheduler.join();
+
+        assert!(test_observable.is_cancelled().await);
+        fallback.emit_all(vec![1, 2]).await;
+        fallback.emit_on_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[chronobreak::test]
+    async fn emit_after_cancel() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .timeout(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        test_observer.cancel().await;
+        scheduler.join();
+        test_observable.emit(1).await;
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert_eq!(test_observer.error().await, None);
+    }
+
+    #[chronobreak::test]
+    async fn emit_after_timeout() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .timeout(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        scheduler.join();
+        test_observable.emit(1).await;
+
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert_eq!(
+            test_observer.error().await,
+            Some(observable::Error::Timeout)
+        );
+    }
+}
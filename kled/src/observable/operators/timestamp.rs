@@ -0,0 +1,82 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(type = "observable", item = "(Instant, Item)")]
+pub struct Timestamp<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    scheduler: Scheduler,
+}
+
+#[derive(new)]
+struct TimestampObserver<Observer, Scheduler> {
+    observer: Observer,
+    scheduler: Scheduler,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Observer, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for TimestampObserver<Observer, Scheduler>
+where
+    Observer: core::Observer<Cancellable, (Instant, Item), Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let now = self.scheduler.now();
+        self.observer.on_next((now, item)).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::*;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+
+    #[chronobreak::test]
+    async fn timestamp() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .timestamp(scheduler)
+            .subscribe(test_observer.clone())
+            .await;
+        let start = Instant::now();
+        test_observable.emit(0).await;
+        clock::advance(Duration::from_millis(10));
+        test_observable.emit(1).await;
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(
+            test_observer.items().await,
+            vec![(start, 0), (start + Duration::from_millis(10), 1)]
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::core;
+use crate::subject::UnicastSubject;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "observable",
+    subscription = "WindowCancellable<Cancellable>",
+    item = "UnicastSubject<Cancellable, Item, Error>"
+)]
+pub struct Window
+where
+    Error: Clone,
+{
+    count: usize,
+}
+
+#[derive(new)]
+struct WindowObserver<Observer, Cancellable, Item, Error> {
+    observer: Observer,
+    count: usize,
+    #[new(default)]
+    shared: Option<Arc<Shared<Cancellable>>>,
+    #[new(default)]
+    window: Option<UnicastSubject<Cancellable, Item, Error>>,
+    #[new(default)]
+    size: usize,
+}
+
+/// The upstream shared by the downstream and the open window, which is only
+/// cancelled once both of them were cancelled.
+struct Shared<Upstream> {
+    upstream: Upstream,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream> Shared<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    fn new(upstream: Upstream) -> Self {
+        Self {
+            upstream,
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        }
+    }
+
+    fn window<Item, Error>(self: &Arc<Self>) -> UnicastSubject<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastSubject::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.upstream.cancel().await;
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error> core::Observer<Cancellable, Item, Error>
+    for WindowObserver<Observer, Cancellable, Item, Error>
+where
+    Observer: core::Observer<
+            WindowCancellable<Cancellable>,
+            UnicastSubject<Cancellable, Item, Error>,
+            Error,
+        > + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let shared = Arc::new(Shared::new(cancellable));
+        self.shared = Some(shared.clone());
+        self.observer
+            .on_subscribe(WindowCancellable { shared })
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let shared = match self.shared.as_ref() {
+            Some(shared) => shared,
+            None => return,
+        };
+        if self.window.is_none() {
+            // Once the downstream cancelled, no further windows are opened.
+            if shared.cancelled.load(Ordering::SeqCst) {
+                return;
+            }
+            let window = shared.window();
+            self.window = Some(window.clone());
+            self.observer.on_next(window).await;
+        }
+        if let Some(window) = self.window.as_mut() {
+            core::Observer::on_next(window, item).await;
+        }
+        self.size += 1;
+        if self.size == self.count {
+            if let Some(mut window) = self.window.take() {
+                core::Observer::on_completed(&mut window).await;
+            }
+            self.size = 0;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if let Some(mut window) = self.window.take() {
+            core::Observer::on_error(&mut window, error.clone()).await;
+        }
+        if !self.is_cancelled() {
+            self.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(mut window) = self.window.take() {
+            core::Observer::on_completed(&mut window).await;
+        }
+        if !self.is_cancelled() {
+            self.observer.on_completed().await;
+        }
+    }
+}
+
+impl<Observer, Cancellable, Item, Error> WindowObserver<Observer, Cancellable, Item, Error> {
+    fn is_cancelled(&self) -> bool {
+        match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::SeqCst),
+            None => false,
+        }
+    }
+}
+
+pub struct WindowCancellable<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+impl<Upstream> Clone for WindowCancellable<Upstream> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for WindowCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn count() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3, 4]
+            .into_observable()
+            .window(2)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+
+        let mut items = vec![];
+        for window in test_observer.items().await {
+            let window_observer = TestObserver::default();
+            window.subscribe(window_observer.clone()).await;
+            assert_eq!(window_observer.status().await, ObserverStatus::Completed);
+            items.push(window_observer.items().await);
+        }
+        assert_eq!(items, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[async_std::test]
+    async fn cancel_window() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .window(3)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+
+        let mut window_observer = TestObserver::default();
+        let window = test_observer.items().await.remove(0);
+        window.subscribe(window_observer.clone()).await;
+        window_observer.cancel().await;
+        test_observable.emit_all(vec![1, 2, 3]).await;
+        test_observable.emit_error(()).await;
+
+        assert_eq!(window_observer.items().await, vec![0]);
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await.len(), 2);
+    }
+
+    #[async_std::test]
+    async fn cancel_with_open_window() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .window(3)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        let window_observer = TestObserver::default();
+        let window = test_observer.items().await.remove(0);
+        window.subscribe(window_observer.clone()).await;
+        test_observer.cancel().await;
+        assert!(!test_observable.is_cancelled().await);
+
+        test_observable.emit_all(vec![1, 2, 3]).await;
+        assert_eq!(window_observer.items().await, vec![0, 1, 2]);
+        assert_eq!(window_observer.status().await, ObserverStatus::Completed);
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await.len(), 1);
+    }
+}
@@ -0,0 +1,380 @@
+use crate::cancellable::*;
+use crate::core;
+use crate::subject::UnicastSubject;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct WindowBoundary<
+    Observable,
+    Cancellable,
+    Item,
+    Error,
+    Boundary,
+    BoundaryCancellable,
+    BoundaryItem,
+> {
+    observable: Observable,
+    boundary: Boundary,
+    phantom: PhantomData<(Cancellable, Item, Error, BoundaryCancellable, BoundaryItem)>,
+}
+
+type SharedData<Observer, Cancellable, Item, Error, BoundaryCancellable> =
+    Arc<Mutex<Data<Observer, Cancellable, Item, Error, BoundaryCancellable>>>;
+
+struct Data<Observer, Cancellable, Item, Error, BoundaryCancellable> {
+    observer: Observer,
+    shared: Arc<Shared<Cancellable, BoundaryCancellable>>,
+    window: Option<UnicastSubject<Cancellable, Item, Error>>,
+    is_done: bool,
+}
+
+/// The upstream and boundary shared by the downstream and the open window,
+/// which are only cancelled once both of them were cancelled.
+struct Shared<Upstream, Boundary> {
+    upstream: LazyCancellable<Upstream>,
+    boundary: LazyCancellable<Boundary>,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream, Boundary> Shared<Upstream, Boundary>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    Boundary: core::Cancellable + Send + Sync + 'static,
+{
+    fn window<Item, Error>(self: &Arc<Self>) -> UnicastSubject<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastSubject::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            core::Cancellable::cancel(&self.upstream).await;
+            core::Cancellable::cancel(&self.boundary).await;
+        }
+    }
+}
+
+impl<Observer, Cancellable, Item, Error, BoundaryCancellable>
+    Data<Observer, Cancellable, Item, Error, BoundaryCancellable>
+where
+    Observer: core::Observer<
+            WindowBoundaryCancellable<Cancellable, BoundaryCancellable>,
+            UnicastSubject<Cancellable, Item, Error>,
+            Error,
+        > + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    BoundaryCancellable: core::Cancellable + Send + Sync + 'static,
+{
+    async fn open_window(&mut self) {
+        let window = self.shared.window();
+        self.window = Some(window.clone());
+        self.observer.on_next(window).await;
+    }
+
+    async fn close_window(&mut self) {
+        if let Some(mut window) = self.window.take() {
+            core::Observer::on_completed(&mut window).await;
+        }
+    }
+
+    async fn error(&mut self, error: Error) {
+        if !self.is_done {
+            self.is_done = true;
+            if let Some(mut window) = self.window.take() {
+                core::Observer::on_error(&mut window, error.clone()).await;
+            }
+            if !self.shared.cancelled.load(Ordering::SeqCst) {
+                self.observer.on_error(error).await;
+            }
+        }
+    }
+
+    async fn complete(&mut self) {
+        if !self.is_done {
+            self.is_done = true;
+            self.close_window().await;
+            if !self.shared.cancelled.load(Ordering::SeqCst) {
+                self.observer.on_completed().await;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<Observable, Cancellable, Item, Error, Boundary, BoundaryCancellable, BoundaryItem>
+    core::Observable<
+        WindowBoundaryCancellable<Cancellable, BoundaryCancellable>,
+        UnicastSubject<Cancellable, Item, Error>,
+        Error,
+    >
+    for WindowBoundary<
+        Observable,
+        Cancellable,
+        Item,
+        Error,
+        Boundary,
+        BoundaryCancellable,
+        BoundaryItem,
+    >
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    Boundary: core::Observable<BoundaryCancellable, BoundaryItem, Error> + Send,
+    BoundaryCancellable: core::Cancellable + Send + Sync + 'static,
+    BoundaryItem: Send + 'static,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<
+                WindowBoundaryCancellable<Cancellable, BoundaryCancellable>,
+                UnicastSubject<Cancellable, Item, Error>,
+                Error,
+            > + Send
+            + 'static,
+    {
+        let stub = LazyCancellableStub::default();
+        let boundary_stub = LazyCancellableStub::default();
+        let cancellable = stub.cancellable();
+        let boundary_cancellable = boundary_stub.cancellable();
+        let shared = Arc::new(Shared {
+            upstream: cancellable.clone(),
+            boundary: boundary_cancellable.clone(),
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        });
+        observer
+            .on_subscribe(WindowBoundaryCancellable {
+                shared: shared.clone(),
+            })
+            .await;
+        let data = Arc::new(Mutex::new(Data {
+            observer,
+            shared,
+            window: None,
+            is_done: false,
+        }));
+        data.lock().await.open_window().await;
+        self.boundary
+            .subscribe(BoundaryObserver {
+                data: data.clone(),
+                stub: boundary_stub,
+                upstream: cancellable,
+                phantom: PhantomData,
+            })
+            .await;
+        self.observable
+            .subscribe(WindowBoundaryObserver {
+                data,
+                stub,
+                boundary: boundary_cancellable,
+            })
+            .await;
+    }
+}
+
+struct WindowBoundaryObserver<Observer, Cancellable, Item, Error, BoundaryCancellable> {
+    data: SharedData<Observer, Cancellable, Item, Error, BoundaryCancellable>,
+    stub: LazyCancellableStub<Cancellable>,
+    boundary: LazyCancellable<BoundaryCancellable>,
+}
+
+#[async_trait]
+impl<Observer, Cancellable, Item, Error, BoundaryCancellable>
+    core::Observer<Cancellable, Item, Error>
+    for WindowBoundaryObserver<Observer, Cancellable, Item, Error, BoundaryCancellable>
+where
+    Observer: core::Observer<
+            WindowBoundaryCancellable<Cancellable, BoundaryCancellable>,
+            UnicastSubject<Cancellable, Item, Error>,
+            Error,
+        > + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    BoundaryCancellable: core::Cancellable + Send + Sync + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.stub.set_cancellable(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if let Some(window) = data.window.as_mut() {
+            core::Observer::on_next(window, item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.data.lock().await.error(error).await;
+        core::Cancellable::cancel(&self.boundary).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.data.lock().await.complete().await;
+        core::Cancellable::cancel(&self.boundary).await;
+    }
+}
+
+struct BoundaryObserver<Observer, Cancellable, Item, Error, BoundaryCancellable, BoundaryItem> {
+    data: SharedData<Observer, Cancellable, Item, Error, BoundaryCancellable>,
+    stub: LazyCancellableStub<BoundaryCancellable>,
+    upstream: LazyCancellable<Cancellable>,
+    phantom: PhantomData<BoundaryItem>,
+}
+
+#[async_trait]
+impl<Observer, Cancellable, Item, Error, BoundaryCancellable, BoundaryItem>
+    core::Observer<BoundaryCancellable, BoundaryItem, Error>
+    for BoundaryObserver<Observer, Cancellable, Item, Error, BoundaryCancellable, BoundaryItem>
+where
+    Observer: core::Observer<
+            WindowBoundaryCancellable<Cancellable, BoundaryCancellable>,
+            UnicastSubject<Cancellable, Item, Error>,
+            Error,
+        > + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    BoundaryCancellable: core::Cancellable + Send + Sync + 'static,
+    BoundaryItem: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: BoundaryCancellable) {
+        self.stub.set_cancellable(cancellable).await;
+    }
+
+    async fn on_next(&mut self, _: BoundaryItem) {
+        let mut data = self.data.lock().await;
+        if !data.is_done {
+            data.close_window().await;
+            // Once the downstream cancelled, no further windows are opened.
+            if !data.shared.cancelled.load(Ordering::SeqCst) {
+                data.open_window().await;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.data.lock().await.error(error).await;
+        core::Cancellable::cancel(&self.upstream).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.data.lock().await.complete().await;
+        core::Cancellable::cancel(&self.upstream).await;
+    }
+}
+
+pub struct WindowBoundaryCancellable<Upstream, Boundary> {
+    shared: Arc<Shared<Upstream, Boundary>>,
+}
+
+impl<Upstream, Boundary> Clone for WindowBoundaryCancellable<Upstream, Boundary> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, Boundary> core::Cancellable for WindowBoundaryCancellable<Upstream, Boundary>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    Boundary: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn boundary() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let boundary = TestObservable::default().annotate_item_type(());
+        test_observable
+            .clone()
+            .window_boundary(boundary.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        boundary.emit(()).await;
+        test_observable.emit(2).await;
+        boundary.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert!(test_observable.is_cancelled().await);
+        let mut items = vec![];
+        for window in test_observer.items().await {
+            let window_observer = TestObserver::default();
+            window.subscribe(window_observer.clone()).await;
+            assert_eq!(window_observer.status().await, ObserverStatus::Completed);
+            items.push(window_observer.items().await);
+        }
+        assert_eq!(items, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[async_std::test]
+    async fn upstream_error() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(0);
+        let boundary = TestObservable::default().annotate_item_type(());
+        test_observable
+            .clone()
+            .window_boundary(boundary.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_error(1).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.error().await, Some(1));
+        assert!(boundary.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn cancel_with_open_window() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let boundary = TestObservable::default().annotate_item_type(());
+        test_observable
+            .clone()
+            .window_boundary(boundary.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        let window_observer = TestObserver::default();
+        let window = test_observer.items().await.remove(0);
+        window.subscribe(window_observer.clone()).await;
+        test_observer.cancel().await;
+        assert!(!test_observable.is_cancelled().await);
+        assert!(!boundary.is_cancelled().await);
+
+        test_observable.emit(1).await;
+        boundary.emit(()).await;
+        assert_eq!(window_observer.items().await, vec![0, 1]);
+        assert_eq!(window_observer.status().await, ObserverStatus::Completed);
+        assert!(test_observable.is_cancelled().await);
+        assert!(boundary.is_cancelled().await);
+        assert_eq!(test_observer.items().await.len(), 1);
+    }
+}
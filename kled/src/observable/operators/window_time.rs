@@ -0,0 +1,295 @@
+use crate::core;
+use crate::subject::UnicastSubject;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+
+#[chronobreak]
+mod mock {
+    pub use futures_timer::Delay;
+    pub use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    pub use std::sync::Arc;
+    pub use std::time::*;
+}
+use mock::*;
+
+#[operator(
+    type = "observable",
+    subscription = "WindowTimeCancellable<Cancellable>",
+    item = "UnicastSubject<Cancellable, Item, Error>"
+)]
+pub struct WindowTime<Scheduler>
+where
+    Error: Clone,
+    Scheduler: core::Scheduler,
+{
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+pub struct WindowTimeObserver<Observer, Cancellable, Item, Error, Scheduler> {
+    data: Arc<Mutex<Data<Observer, Cancellable, Item, Error>>>,
+    duration: Duration,
+    scheduler: Scheduler,
+}
+
+struct Data<Observer, Cancellable, Item, Error> {
+    observer: Observer,
+    shared: Option<Arc<Shared<Cancellable>>>,
+    window: Option<UnicastSubject<Cancellable, Item, Error>>,
+    is_done: bool,
+}
+
+/// The upstream shared by the downstream and the open window, which is only
+/// cancelled once both of them were cancelled.
+struct Shared<Upstream> {
+    upstream: Upstream,
+    refs: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream> Shared<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    fn window<Item, Error>(self: &Arc<Self>) -> UnicastSubject<Upstream, Item, Error> {
+        self.refs.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        UnicastSubject::with_on_terminate(move || Box::pin(async move { shared.release().await }))
+    }
+
+    async fn release(&self) {
+        if self.refs.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.upstream.cancel().await;
+        }
+    }
+}
+
+impl<Observer, Cancellable, Item, Error, Scheduler>
+    WindowTimeObserver<Observer, Cancellable, Item, Error, Scheduler>
+{
+    pub fn new(observer: Observer, duration: Duration, scheduler: Scheduler) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                observer,
+                shared: None,
+                window: None,
+                is_done: false,
+            })),
+            duration,
+            scheduler,
+        }
+    }
+}
+
+impl<Observer, Cancellable, Item, Error> Data<Observer, Cancellable, Item, Error>
+where
+    Observer: core::Observer<
+            WindowTimeCancellable<Cancellable>,
+            UnicastSubject<Cancellable, Item, Error>,
+            Error,
+        > + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn open_window(&mut self) {
+        let window = match self.shared.as_ref() {
+            Some(shared) => shared.window(),
+            None => return,
+        };
+        self.window = Some(window.clone());
+        self.observer.on_next(window).await;
+    }
+
+    async fn close_window(&mut self) {
+        if let Some(mut window) = self.window.take() {
+            core::Observer::on_completed(&mut window).await;
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        match self.shared.as_ref() {
+            Some(shared) => shared.cancelled.load(Ordering::SeqCst),
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for WindowTimeObserver<Observer, Cancellable, Item, Error, Scheduler>
+where
+    Observer: core::Observer<
+            WindowTimeCancellable<Cancellable>,
+            UnicastSubject<Cancellable, Item, Error>,
+            Error,
+        > + Send
+        + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let shared = Arc::new(Shared {
+            upstream: cancellable,
+            refs: AtomicUsize::new(1),
+            cancelled: AtomicBool::new(false),
+        });
+        let mut data = self.data.lock().await;
+        data.shared = Some(shared.clone());
+        data.observer
+            .on_subscribe(WindowTimeCancellable {
+                shared: shared.clone(),
+            })
+            .await;
+        data.open_window().await;
+        drop(data);
+
+        let data = self.data.clone();
+        let duration = self.duration;
+        self.scheduler.schedule(async move {
+            loop {
+                Delay::new(duration).await;
+                let mut data = data.lock().await;
+                if data.is_done {
+                    break;
+                }
+                data.close_window().await;
+                // Once the downstream cancelled, the open window is still
+                // completed but no further windows are opened.
+                if shared.cancelled.load(Ordering::SeqCst) {
+                    break;
+                }
+                data.open_window().await;
+            }
+        });
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let mut data = self.data.lock().await;
+        if let Some(window) = data.window.as_mut() {
+            core::Observer::on_next(window, item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let mut data = self.data.lock().await;
+        data.is_done = true;
+        if let Some(mut window) = data.window.take() {
+            core::Observer::on_error(&mut window, error.clone()).await;
+        }
+        if !data.is_cancelled() {
+            data.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let mut data = self.data.lock().await;
+        data.is_done = true;
+        data.close_window().await;
+        if !data.is_cancelled() {
+            data.observer.on_completed().await;
+        }
+    }
+}
+
+pub struct WindowTimeCancellable<Upstream> {
+    shared: Arc<Shared<Upstream>>,
+}
+
+impl<Upstream> Clone for WindowTimeCancellable<Upstream> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for WindowTimeCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::SeqCst) {
+            self.shared.release().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::time::*;
+
+    #[chronobreak::test]
+    async fn time() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .window_time(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit_all(vec![0, 1]).await;
+        test_observable.emit_on_completed().await;
+        scheduler.join();
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        let windows = test_observer.items().await;
+        assert_eq!(windows.len(), 1);
+        let window_observer = TestObserver::default();
+        windows[0].clone().subscribe(window_observer.clone()).await;
+        assert_eq!(window_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(window_observer.items().await, vec![0, 1]);
+    }
+
+    #[chronobreak::test]
+    async fn cancel() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .annotate_item_type(0)
+            .window_time(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+        scheduler.join();
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await.len(), 1);
+    }
+
+    #[chronobreak::test]
+    async fn cancel_with_open_window() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .window_time(Duration::from_millis(10), scheduler.clone())
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        let window_observer = TestObserver::default();
+        let window = test_observer.items().await.remove(0);
+        window.subscribe(window_observer.clone()).await;
+        test_observer.cancel().await;
+        assert!(!test_observable.is_cancelled().await);
+
+        test_observable.emit(1).await;
+        scheduler.join();
+        assert_eq!(window_observer.items().await, vec![0, 1]);
+        assert_eq!(window_observer.status().await, ObserverStatus::Completed);
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(test_observer.items().await.len(), 1);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::core;
+use async_trait::async_trait;
+
+/// Emits `prefix` before and `suffix` after the items of the upstream
+/// observable, followed by `default` if the upstream observable did not emit
+/// any items. This is the common implementation of
+/// [`Observable::start_with`], [`Observable::end_with`] and
+/// [`Observable::default_if_empty`].
+///
+/// [`Observable::start_with`]: ../../core/trait.Observable.html#method.start_with
+/// [`Observable::end_with`]: ../../core/trait.Observable.html#method.end_with
+/// [`Observable::default_if_empty`]: ../../core/trait.Observable.html#method.default_if_empty
+#[operator(type = "observable")]
+pub struct WithItems {
+    prefix: Vec<Item>,
+    suffix: Vec<Item>,
+    default: Option<Item>,
+}
+
+#[derive(new)]
+struct WithItemsObserver<Observer, Item> {
+    observer: Observer,
+    prefix: Vec<Item>,
+    suffix: Vec<Item>,
+    default: Option<Item>,
+    #[new(default)]
+    has_items: bool,
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error> core::Observer<Cancellable, Item, Error>
+    for WithItemsObserver<Observer, Item>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+        for item in std::mem::take(&mut self.prefix) {
+            self.observer.on_next(item).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.has_items = true;
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        for item in std::mem::take(&mut self.suffix) {
+            self.observer.on_next(item).await;
+        }
+        if let Some(default) = self.default.take() {
+            if !self.has_items {
+                self.observer.on_next(default).await;
+            }
+        }
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn start_with() {
+        let test_observer = TestObserver::default();
+        vec![2, 3]
+            .into_observable()
+            .start_with(vec![0, 1])
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn end_with() {
+        let test_observer = TestObserver::default();
+        vec![0, 1]
+            .into_observable()
+            .end_with(vec![2, 3])
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn default_if_empty() {
+        let test_observer = TestObserver::default();
+        Vec::new()
+            .into_observable()
+            .default_if_empty(0)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.items().await, vec![0]);
+
+        let test_observer = TestObserver::default();
+        vec![1]
+            .into_observable()
+            .default_if_empty(0)
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.items().await, vec![1]);
+    }
+}
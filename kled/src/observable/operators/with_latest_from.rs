@@ -0,0 +1,234 @@
+use crate::cancellable::*;
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "observable",
+    subscription = "WithLatestFromCancellable<Cancellable, OtherCancellable>",
+    item = "ItemOut"
+)]
+pub struct WithLatestFrom<Other, OtherCancellable, OtherItem, ItemOut, CombineFn>
+where
+    Other: core::Observable<OtherCancellable, OtherItem, Error>,
+    OtherCancellable: core::Cancellable + Send + Sync + 'static,
+    OtherItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CombineFn: FnMut(Item, &OtherItem) -> ItemOut,
+{
+    other: Other,
+    combiner: CombineFn,
+}
+
+struct WithLatestFromObserver<Observer, Other, OtherCancellable, OtherItem, CombineFn> {
+    observer: Arc<Mutex<Option<Observer>>>,
+    other: Option<Other>,
+    stub: LazyCancellableStub<OtherCancellable>,
+    other_cancellable: Option<LazyCancellable<OtherCancellable>>,
+    latest: Arc<Mutex<Option<OtherItem>>>,
+    combiner: CombineFn,
+}
+
+impl<Observer, Other, OtherCancellable, OtherItem, CombineFn>
+    WithLatestFromObserver<Observer, Other, OtherCancellable, OtherItem, CombineFn>
+{
+    fn new(observer: Observer, other: Other, combiner: CombineFn) -> Self {
+        Self {
+            observer: Arc::new(Mutex::new(Some(observer))),
+            other: Some(other),
+            stub: LazyCancellableStub::default(),
+            other_cancellable: None,
+            latest: Arc::new(Mutex::new(None)),
+            combiner,
+        }
+    }
+}
+
+#[async_trait]
+impl<
+        Cancellable,
+        Observer,
+        Item,
+        Error,
+        Other,
+        OtherCancellable,
+        OtherItem,
+        ItemOut,
+        CombineFn,
+    > core::Observer<Cancellable, Item, Error>
+    for WithLatestFromObserver<Observer, Other, OtherCancellable, OtherItem, CombineFn>
+where
+    Observer: core::Observer<WithLatestFromCancellable<Cancellable, OtherCancellable>, ItemOut, Error>
+        + Send
+        + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Other: core::Observable<OtherCancellable, OtherItem, Error> + Send,
+    OtherCancellable: core::Cancellable + Send + Sync + 'static,
+    OtherItem: Send + 'static,
+    ItemOut: Send + 'static,
+    CombineFn: FnMut(Item, &OtherItem) -> ItemOut + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.other_cancellable = Some(self.stub.cancellable());
+        if let Some(observer) = self.observer.lock().await.as_mut() {
+            observer
+                .on_subscribe(WithLatestFromCancellable::new(
+                    cancellable.clone(),
+                    self.stub.cancellable(),
+                ))
+                .await;
+        }
+        if let Some(other) = self.other.take() {
+            other
+                .subscribe(OtherObserver {
+                    observer: self.observer.clone(),
+                    upstream: cancellable,
+                    stub: std::mem::take(&mut self.stub),
+                    latest: self.latest.clone(),
+                    phantom: PhantomData,
+                })
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let item = match self.latest.lock().await.as_ref() {
+            Some(latest) => (self.combiner)(item, latest),
+            None => return,
+        };
+        if let Some(observer) = self.observer.lock().await.as_mut() {
+            observer.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let observer = self.observer.lock().await.take();
+        if let Some(mut observer) = observer {
+            observer.on_error(error).await;
+            if let Some(other) = self.other_cancellable.as_ref() {
+                core::Cancellable::cancel(other).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let observer = self.observer.lock().await.take();
+        if let Some(mut observer) = observer {
+            observer.on_completed().await;
+            if let Some(other) = self.other_cancellable.as_ref() {
+                core::Cancellable::cancel(other).await;
+            }
+        }
+    }
+}
+
+struct OtherObserver<Observer, Cancellable, OtherCancellable, OtherItem, ItemOut> {
+    observer: Arc<Mutex<Option<Observer>>>,
+    upstream: Cancellable,
+    stub: LazyCancellableStub<OtherCancellable>,
+    latest: Arc<Mutex<Option<OtherItem>>>,
+    phantom: PhantomData<(OtherCancellable, ItemOut)>,
+}
+
+#[async_trait]
+impl<Observer, Cancellable, OtherCancellable, OtherItem, ItemOut, Error>
+    core::Observer<OtherCancellable, OtherItem, Error>
+    for OtherObserver<Observer, Cancellable, OtherCancellable, OtherItem, ItemOut>
+where
+    Observer: core::Observer<WithLatestFromCancellable<Cancellable, OtherCancellable>, ItemOut, Error>
+        + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    OtherCancellable: core::Cancellable + Send + Sync + 'static,
+    OtherItem: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: OtherCancellable) {
+        self.stub.set_cancellable(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: OtherItem) {
+        *self.latest.lock().await = Some(item);
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let observer = self.observer.lock().await.take();
+        if let Some(mut observer) = observer {
+            observer.on_error(error).await;
+            self.upstream.cancel().await;
+        }
+    }
+
+    async fn on_completed(&mut self) {}
+}
+
+#[derive(new, Clone)]
+pub struct WithLatestFromCancellable<Upstream, Other> {
+    upstream: Upstream,
+    other: LazyCancellable<Other>,
+}
+
+#[async_trait]
+impl<Upstream, Other> core::Cancellable for WithLatestFromCancellable<Upstream, Other>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    Other: core::Cancellable + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.other.cancel().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn with_latest_from() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let other = TestObservable::default();
+        test_observable
+            .clone()
+            .with_latest_from(other.clone(), |item, latest: &i32| item + latest)
+            .subscribe(test_observer.clone())
+            .await;
+        test_observable.emit(0).await;
+        other.emit(10).await;
+        test_observable.emit(1).await;
+        other.emit(20).await;
+        other.emit(30).await;
+        test_observable.emit(2).await;
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![11, 32]);
+        assert!(other.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn other_error() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(0);
+        let other = TestObservable::default().annotate_item_type(0);
+        test_observable
+            .clone()
+            .with_latest_from(other.clone(), |item, latest| item + latest)
+            .subscribe(test_observer.clone())
+            .await;
+        other.emit_error(()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.error().await, Some(()));
+        assert!(test_observable.is_cancelled().await);
+    }
+}
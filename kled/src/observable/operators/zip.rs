@@ -0,0 +1,556 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The items taken from the sources of an operator such as
+/// [`Observable::zip`] to produce a single combined item. Each source pushes
+/// into its own typed queue of [`Queues`](ZipItems::Queues).
+///
+/// [`Observable::zip`]: ../../core/trait.Observable.html#method.zip
+pub trait ZipItems: Sized + Send + 'static {
+    type Queues: Send + 'static;
+
+    /// Returns whether any source is `done` and its queue is empty.
+    fn is_exhausted(queues: &Self::Queues, done: &[bool]) -> bool;
+
+    /// Pops the next item of each queue if none of them is empty.
+    fn pop(queues: &mut Self::Queues) -> Option<Self>;
+}
+
+impl<Item> ZipItems for Vec<Item>
+where
+    Item: Send + 'static,
+{
+    type Queues = Vec<VecDeque<Item>>;
+
+    fn is_exhausted(queues: &Self::Queues, done: &[bool]) -> bool {
+        queues.is_empty()
+            || queues
+                .iter()
+                .zip(done)
+                .any(|(queue, done)| *done && queue.is_empty())
+    }
+
+    fn pop(queues: &mut Self::Queues) -> Option<Self> {
+        if queues.iter().any(VecDeque::is_empty) {
+            return None;
+        }
+        Some(queues.iter_mut().filter_map(VecDeque::pop_front).collect())
+    }
+}
+
+macro_rules! zip_items {
+    ($($item:ident $index:tt),+) => {
+        impl<$($item),+> ZipItems for ($($item,)+)
+        where
+            $($item: Send + 'static,)+
+        {
+            type Queues = ($(VecDeque<$item>,)+);
+
+            fn is_exhausted(queues: &Self::Queues, done: &[bool]) -> bool {
+                $((done[$index] && queues.$index.is_empty()))||+
+            }
+
+            fn pop(queues: &mut Self::Queues) -> Option<Self> {
+                if $(queues.$index.is_empty())||+ {
+                    return None;
+                }
+                Some(($(queues.$index.pop_front()?,)+))
+            }
+        }
+    };
+}
+
+zip_items!(A 0, B 1);
+zip_items!(A 0, B 1, C 2);
+zip_items!(A 0, B 1, C 2, D 3);
+
+type BoxedSource<Items, Item, Error> =
+    Box<dyn FnOnce(Arc<Shared<Items, Item, Error>>) -> BoxFuture<'static, ()> + Send>;
+
+type BoxedObserver<Items, Item, Error> =
+    Box<dyn core::Observer<ZipCancellable<Items, Item, Error>, Item, Error> + Send + 'static>;
+
+type BoxedCancel = Box<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+type Combiner<Items, Item> = Box<dyn FnMut(Items) -> Item + Send>;
+
+/// Combines the items of multiple sources positionally. The zipped
+/// observable completes as soon as any source completed and all of its
+/// items were consumed.
+pub struct Zip<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    sources: Vec<BoxedSource<Items, Item, Error>>,
+    queues: Items::Queues,
+    combiner: Combiner<Items, Item>,
+}
+
+/// A [`Zip`] of a fixed number of sources, such as [`Observable::zip`], which emits
+/// the items of its sources as tuples.
+///
+/// [`Observable::zip`]: ../../core/trait.Observable.html#method.zip
+pub type ZipTuple<Items, Error> = Zip<Items, Items, Error>;
+
+impl<Items, Item, Error> Zip<Items, Item, Error>
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn new<CombineFn>(queues: Items::Queues, combiner: CombineFn) -> Self
+    where
+        CombineFn: FnMut(Items) -> Item + Send + 'static,
+    {
+        Self {
+            sources: vec![],
+            queues,
+            combiner: Box::new(combiner),
+        }
+    }
+
+    fn with_source<Observable, Cancellable, SourceItem, Push>(
+        mut self,
+        observable: Observable,
+        push: Push,
+    ) -> Self
+    where
+        Observable: core::Observable<Cancellable, SourceItem, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+        SourceItem: Send + 'static,
+        Push: FnMut(&mut Items::Queues, SourceItem) + Send + 'static,
+    {
+        let index = self.sources.len();
+        self.sources.push(Box::new(move |shared| {
+            Box::pin(async move {
+                observable
+                    .subscribe(ZipObserver {
+                        shared,
+                        index,
+                        push,
+                        phantom: PhantomData,
+                    })
+                    .await
+            })
+        }));
+        self
+    }
+}
+
+impl<SourceItem, Item, Error> Zip<Vec<SourceItem>, Item, Error>
+where
+    SourceItem: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip_all<Observable, Cancellable, CombineFn>(
+        sources: Vec<Observable>,
+        combiner: CombineFn,
+    ) -> Self
+    where
+        Observable: core::Observable<Cancellable, SourceItem, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+        CombineFn: FnMut(Vec<SourceItem>) -> Item + Send + 'static,
+    {
+        let queues = sources.iter().map(|_| VecDeque::new()).collect();
+        sources
+            .into_iter()
+            .enumerate()
+            .fold(Self::new(queues, combiner), |zip, (index, source)| {
+                zip.with_source(source, move |queues, item| queues[index].push_back(item))
+            })
+    }
+}
+
+impl<A, B, Error> Zip<(A, B), (A, B), Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip2<ObservableA, CancellableA, ObservableB, CancellableB>(
+        a: ObservableA,
+        b: ObservableB,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, A, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), |items| items)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+    }
+}
+
+impl<A, B, C, Error> Zip<(A, B, C), (A, B, C), Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    C: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip3<ObservableA, CancellableA, ObservableB, CancellableB, ObservableC, CancellableC>(
+        a: ObservableA,
+        b: ObservableB,
+        c: ObservableC,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, A, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), |items| items)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+    }
+}
+
+impl<A, B, C, D, Error> Zip<(A, B, C, D), (A, B, C, D), Error>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    C: Send + 'static,
+    D: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn zip4<
+        ObservableA,
+        CancellableA,
+        ObservableB,
+        CancellableB,
+        ObservableC,
+        CancellableC,
+        ObservableD,
+        CancellableD,
+    >(
+        a: ObservableA,
+        b: ObservableB,
+        c: ObservableC,
+        d: ObservableD,
+    ) -> Self
+    where
+        ObservableA: core::Observable<CancellableA, A, Error> + Send + 'static,
+        CancellableA: core::Cancellable + Send + Sync + 'static,
+        ObservableB: core::Observable<CancellableB, B, Error> + Send + 'static,
+        CancellableB: core::Cancellable + Send + Sync + 'static,
+        ObservableC: core::Observable<CancellableC, C, Error> + Send + 'static,
+        CancellableC: core::Cancellable + Send + Sync + 'static,
+        ObservableD: core::Observable<CancellableD, D, Error> + Send + 'static,
+        CancellableD: core::Cancellable + Send + Sync + 'static,
+    {
+        Self::new(Default::default(), |items| items)
+            .with_source(a, |queues, item| queues.0.push_back(item))
+            .with_source(b, |queues, item| queues.1.push_back(item))
+            .with_source(c, |queues, item| queues.2.push_back(item))
+            .with_source(d, |queues, item| queues.3.push_back(item))
+    }
+}
+
+/// Returns an [`Observable`] that combines the items of all `sources`
+/// positionally by applying `combiner` to the next item of each source.
+///
+/// [`Observable`]: ../../core/trait.Observable.html
+pub fn zip_all<Observable, Cancellable, Item, Error, ItemOut, CombineFn>(
+    sources: Vec<Observable>,
+    combiner: CombineFn,
+) -> Zip<Vec<Item>, ItemOut, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ItemOut: Send + 'static,
+    CombineFn: FnMut(Vec<Item>) -> ItemOut + Send + 'static,
+{
+    Zip::zip_all(sources, combiner)
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Observable<ZipCancellable<Items, Item, Error>, Item, Error>
+    for Zip<Items, Item, Error>
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, mut observer: Observer)
+    where
+        Observer: core::Observer<ZipCancellable<Items, Item, Error>, Item, Error> + Send + 'static,
+    {
+        let count = self.sources.len();
+        let shared = Arc::new(Shared {
+            observer: Mutex::new(None),
+            combiner: Mutex::new(self.combiner),
+            cancellables: Mutex::new((0..count).map(|_| None).collect()),
+            state: Mutex::new(State {
+                queues: self.queues,
+                done: vec![false; count],
+                error: None,
+                is_cancelled: false,
+                is_terminated: false,
+            }),
+            wip: AtomicUsize::new(0),
+        });
+        observer
+            .on_subscribe(ZipCancellable {
+                shared: shared.clone(),
+            })
+            .await;
+        *shared.observer.lock().await = Some(Box::new(observer));
+        drain(&shared).await;
+        for source in self.sources {
+            source(shared.clone()).await;
+        }
+    }
+}
+
+struct Shared<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    observer: Mutex<Option<BoxedObserver<Items, Item, Error>>>,
+    combiner: Mutex<Combiner<Items, Item>>,
+    cancellables: Mutex<Vec<Option<Arc<BoxedCancel>>>>,
+    state: Mutex<State<Items, Error>>,
+    wip: AtomicUsize,
+}
+
+struct State<Items, Error>
+where
+    Items: ZipItems,
+{
+    queues: Items::Queues,
+    done: Vec<bool>,
+    error: Option<Error>,
+    is_cancelled: bool,
+    is_terminated: bool,
+}
+
+impl<Items, Item, Error> Shared<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    async fn cancel_sources(&self) {
+        let cancellables: Vec<_> = self
+            .cancellables
+            .lock()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect();
+        for cancel in cancellables {
+            cancel().await;
+        }
+    }
+}
+
+async fn drain<Items, Item, Error>(shared: &Shared<Items, Item, Error>)
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    if shared.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut observer = shared.observer.lock().await;
+        let mut is_terminated = false;
+        if let Some(downstream) = observer.as_mut() {
+            loop {
+                let mut state = shared.state.lock().await;
+                if state.is_cancelled {
+                    state.is_terminated = true;
+                    is_terminated = true;
+                    break;
+                }
+                if let Some(error) = state.error.take() {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_error(error).await;
+                    is_terminated = true;
+                    break;
+                }
+                if Items::is_exhausted(&state.queues, &state.done) {
+                    state.is_terminated = true;
+                    drop(state);
+                    downstream.on_completed().await;
+                    is_terminated = true;
+                    break;
+                }
+                let items = match Items::pop(&mut state.queues) {
+                    Some(items) => items,
+                    None => break,
+                };
+                drop(state);
+                let item = (shared.combiner.lock().await)(items);
+                downstream.on_next(item).await;
+            }
+        }
+        if is_terminated {
+            *observer = None;
+        }
+        drop(observer);
+        if is_terminated {
+            shared.cancel_sources().await;
+        }
+        missed = shared.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+struct ZipObserver<Items, Item, Error, SourceItem, Push>
+where
+    Items: ZipItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+    index: usize,
+    push: Push,
+    phantom: PhantomData<SourceItem>,
+}
+
+#[async_trait]
+impl<Cancellable, Items, Item, Error, SourceItem, Push>
+    core::Observer<Cancellable, SourceItem, Error>
+    for ZipObserver<Items, Item, Error, SourceItem, Push>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SourceItem: Send + 'static,
+    Push: FnMut(&mut Items::Queues, SourceItem) + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancel: BoxedCancel = Box::new(move || {
+            let cancellable = cancellable.clone();
+            Box::pin(async move { cancellable.cancel().await })
+        });
+        let cancel = Arc::new(cancel);
+        self.shared.cancellables.lock().await[self.index] = Some(cancel.clone());
+        if self.shared.state.lock().await.is_terminated {
+            cancel().await;
+        }
+    }
+
+    async fn on_next(&mut self, item: SourceItem) {
+        (self.push)(&mut self.shared.state.lock().await.queues, item);
+        drain(&self.shared).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        {
+            let mut state = self.shared.state.lock().await;
+            if state.error.is_none() {
+                state.error = Some(error);
+            }
+        }
+        drain(&self.shared).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.shared.state.lock().await.done[self.index] = true;
+        drain(&self.shared).await;
+    }
+}
+
+pub struct ZipCancellable<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    shared: Arc<Shared<Items, Item, Error>>,
+}
+
+impl<Items, Item, Error> Clone for ZipCancellable<Items, Item, Error>
+where
+    Items: ZipItems,
+{
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Items, Item, Error> core::Cancellable for ZipCancellable<Items, Item, Error>
+where
+    Items: ZipItems,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.shared.state.lock().await.is_cancelled = true;
+        drain(&self.shared).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn zip() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2]
+            .into_observable()
+            .zip(vec!["a", "b"].into_observable())
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![(0, "a"), (1, "b")]);
+    }
+
+    #[async_std::test]
+    async fn zip3() {
+        let test_observer = TestObserver::default();
+        vec![0]
+            .into_observable()
+            .zip3(vec!['a'].into_observable(), vec![true].into_observable())
+            .subscribe(test_observer.clone())
+            .await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![(0, 'a', true)]);
+    }
+
+    #[async_std::test]
+    async fn zip_all() {
+        let test_observer = TestObserver::default();
+        let a = TestObservable::default().annotate_error_type(());
+        let b = TestObservable::default();
+        operators::zip_all(vec![a.clone(), b.clone()], |items| {
+            items.into_iter().sum::<i32>()
+        })
+        .subscribe(test_observer.clone())
+        .await;
+        a.emit_all(vec![1, 2]).await;
+        b.emit(10).await;
+        a.emit_on_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Subscribed);
+        b.emit(20).await;
+
+        assert_eq!(test_observer.items().await, vec![11, 22]);
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert!(b.is_cancelled().await);
+    }
+}
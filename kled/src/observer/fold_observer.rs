@@ -0,0 +1,128 @@
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+use futures::channel::oneshot;
+
+/// An observer which folds all items into a single value and sends the result
+/// to the given channel once the observable terminates.
+pub struct FoldObserver<Acc, BinaryOp, Error> {
+    value: Option<Acc>,
+    binary_op: BinaryOp,
+    sender: Option<oneshot::Sender<Result<Acc, observable::Error<Error>>>>,
+}
+
+impl<Acc, BinaryOp, Error> FoldObserver<Acc, BinaryOp, Error> {
+    pub fn new(
+        initial_value: Acc,
+        binary_op: BinaryOp,
+        sender: oneshot::Sender<Result<Acc, observable::Error<Error>>>,
+    ) -> Self {
+        Self {
+            value: Some(initial_value),
+            binary_op,
+            sender: Some(sender),
+        }
+    }
+
+    fn send(&mut self, result: Result<Acc, observable::Error<Error>>) {
+        if let Some(sender) = self.sender.take() {
+            // The receiver may already be gone, in which case nobody is
+            // interested in the result anymore.
+            let _ = sender.send(result);
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Acc, BinaryOp> core::Observer<Cancellable, Item, Error>
+    for FoldObserver<Acc, BinaryOp, Error>
+where
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Acc: Send,
+    BinaryOp: FnMut(Acc, Item) -> Acc + Send,
+{
+    async fn on_subscribe(&mut self, _: Cancellable) {}
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(value) = self.value.take() {
+            self.value = Some((self.binary_op)(value, item));
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.value = None;
+        self.send(Err(observable::Error::Upstream(error)));
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(value) = self.value.take() {
+            self.send(Ok(value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::collections::HashMap;
+
+    #[async_std::test]
+    async fn fold() {
+        let result = vec![0, 1, 2, 3]
+            .into_observable()
+            .fold(10, |a, b| a + b)
+            .await;
+        assert_eq!(result, Ok(16));
+    }
+
+    #[async_std::test]
+    async fn reduce() {
+        let result = vec![1, 2, 3].into_observable().reduce(|a, b| a * b).await;
+        assert_eq!(result, Ok(Some(6)));
+        let result = Vec::<i32>::new()
+            .into_observable()
+            .reduce(|a, b| a * b)
+            .await;
+        assert_eq!(result, Ok(None));
+    }
+
+    #[async_std::test]
+    async fn collect() {
+        let result: Result<String, _> = vec!['a', 'b', 'c'].into_observable().collect().await;
+        assert_eq!(result, Ok("abc".to_owned()));
+        let result = vec![0, 1].into_observable().to_vec().await;
+        assert_eq!(result, Ok(vec![0, 1]));
+    }
+
+    #[async_std::test]
+    async fn to_map() {
+        let result = vec![1, 2, 3, 4]
+            .into_observable()
+            .to_map(|item| item % 2)
+            .await;
+        let expected: HashMap<_, _> = vec![(0, 4), (1, 3)].into_iter().collect();
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let result = observable::create(|mut emitter| async move {
+            emitter.on_next(0).await;
+            emitter.on_error(1).await;
+        })
+        .to_vec()
+        .await;
+        assert_eq!(result, Err(observable::Error::Upstream(1)));
+    }
+
+    #[async_std::test]
+    async fn disconnected() {
+        let result: Result<Vec<i32>, observable::Error<()>> =
+            observable::create(|emitter| async move { drop(emitter) })
+                .to_vec()
+                .await;
+        assert_eq!(result, Err(observable::Error::Disconnected));
+    }
+}
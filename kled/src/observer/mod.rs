@@ -1,4 +1,5 @@
 reexport_all! {
+    mod fold_observer;
     mod lambda_observer;
     mod scheduled_observer;
     mod test_observer;
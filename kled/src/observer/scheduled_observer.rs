@@ -29,6 +29,9 @@ impl<Cancellable, Item, Error>
             while let Some(signal) = receiver.next().await {
                 is_error = signal.is_error();
                 observer.on_next(signal).await;
+                if is_error {
+                    break;
+                }
             }
             if !is_error {
                 observer.on_completed().await;
@@ -0,0 +1,3 @@
+reexport_all! {
+    mod unicast_processor;
+}
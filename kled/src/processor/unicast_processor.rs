@@ -0,0 +1,321 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// A processor which accepts exactly one subscriber and buffers all items it
+/// receives until that subscriber requests them. Any further subscriber is
+/// signalled [`flow::Error::AlreadySubscribed`].
+///
+/// [`flow::Error::AlreadySubscribed`]: ../flow/enum.Error.html#variant.AlreadySubscribed
+pub struct UnicastProcessor<Subscription, Item, Error> {
+    data: Arc<Data<Subscription, Item, Error>>,
+}
+
+type BoxedSubscriber<Subscription, Item, Error> = Box<
+    dyn core::Subscriber<UnicastProcessorSubscription<Subscription, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+type OnTerminate = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+struct Data<Subscription, Item, Error> {
+    upstream: Mutex<Option<Subscription>>,
+    subscriber: Mutex<Option<BoxedSubscriber<Subscription, Item, Error>>>,
+    state: Mutex<State<Item, Error>>,
+    on_terminate: Mutex<Option<OnTerminate>>,
+    wip: AtomicUsize,
+}
+
+struct State<Item, Error> {
+    queue: VecDeque<Item>,
+    requested: usize,
+    error: Option<flow::Error<Error>>,
+    has_subscriber: bool,
+    is_done: bool,
+    is_cancelled: bool,
+}
+
+impl<Subscription, Item, Error> Default for UnicastProcessor<Subscription, Item, Error> {
+    fn default() -> Self {
+        Self::with_terminate_fn(None)
+    }
+}
+
+impl<Subscription, Item, Error> UnicastProcessor<Subscription, Item, Error> {
+    /// Creates a processor which calls `on_terminate` once it either received
+    /// a terminal signal or its subscriber cancelled, whichever happens first.
+    pub fn with_on_terminate<OnTerminateFn>(on_terminate: OnTerminateFn) -> Self
+    where
+        OnTerminateFn: FnOnce() -> BoxFuture<'static, ()> + Send + 'static,
+    {
+        Self::with_terminate_fn(Some(Box::new(on_terminate)))
+    }
+
+    fn with_terminate_fn(on_terminate: Option<OnTerminate>) -> Self {
+        Self {
+            data: Arc::new(Data {
+                upstream: Mutex::new(None),
+                subscriber: Mutex::new(None),
+                state: Mutex::new(State {
+                    queue: VecDeque::new(),
+                    requested: 0,
+                    error: None,
+                    has_subscriber: false,
+                    is_done: false,
+                    is_cancelled: false,
+                }),
+                on_terminate: Mutex::new(on_terminate),
+                wip: AtomicUsize::new(0),
+            }),
+        }
+    }
+}
+
+impl<Subscription, Item, Error> Clone for UnicastProcessor<Subscription, Item, Error> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<Subscription, Item, Error> UnicastProcessor<Subscription, Item, Error> {
+    pub async fn has_subscriber(&self) -> bool {
+        self.data.state.lock().await.has_subscriber
+    }
+
+    pub async fn is_cancelled(&self) -> bool {
+        self.data.state.lock().await.is_cancelled
+    }
+}
+
+async fn terminate<Subscription, Item, Error>(data: &Data<Subscription, Item, Error>) {
+    let on_terminate = data.on_terminate.lock().await.take();
+    if let Some(on_terminate) = on_terminate {
+        on_terminate().await;
+    }
+}
+
+async fn drain<Subscription, Item, Error>(data: &Data<Subscription, Item, Error>) {
+    // Only one caller may emit at a time, reentrant calls from within the
+    // subscriber only mark that another iteration is required.
+    if data.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = data.subscriber.lock().await;
+        let mut is_terminated = false;
+        if let Some(subscriber) = subscriber.as_mut() {
+            loop {
+                let mut state = data.state.lock().await;
+                if state.is_cancelled {
+                    state.queue.clear();
+                    is_terminated = true;
+                    break;
+                }
+                if state.requested > 0 {
+                    if let Some(item) = state.queue.pop_front() {
+                        state.requested -= 1;
+                        drop(state);
+                        subscriber.on_next(item).await;
+                        continue;
+                    }
+                }
+                if state.is_done && state.queue.is_empty() {
+                    let error = state.error.take();
+                    drop(state);
+                    match error {
+                        Some(error) => subscriber.on_error(error).await,
+                        None => subscriber.on_completed().await,
+                    }
+                    is_terminated = true;
+                }
+                break;
+            }
+        }
+        if is_terminated {
+            *subscriber = None;
+        }
+        drop(subscriber);
+        missed = data.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for UnicastProcessor<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(usize::MAX).await;
+        *self.data.upstream.lock().await = Some(subscription);
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        {
+            let mut state = self.data.state.lock().await;
+            if state.is_cancelled || state.is_done {
+                return;
+            }
+            state.queue.push_back(item);
+        }
+        drain(&self.data).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        {
+            let mut state = self.data.state.lock().await;
+            state.error = Some(error);
+            state.is_done = true;
+        }
+        drain(&self.data).await;
+        terminate(&self.data).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.data.state.lock().await.is_done = true;
+        drain(&self.data).await;
+        terminate(&self.data).await;
+    }
+}
+
+impl<Subscription, Item, Error>
+    core::Processor<
+        Subscription,
+        UnicastProcessorSubscription<Subscription, Item, Error>,
+        Item,
+        Error,
+    > for UnicastProcessor<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+}
+
+#[async_trait]
+impl<Subscription, Item, Error>
+    core::Flow<UnicastProcessorSubscription<Subscription, Item, Error>, Item, Error>
+    for UnicastProcessor<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<UnicastProcessorSubscription<Subscription, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let has_subscriber = {
+            let mut state = self.data.state.lock().await;
+            std::mem::replace(&mut state.has_subscriber, true)
+        };
+        if has_subscriber {
+            // Any further subscriber is rejected by an already failed processor.
+            let mut rejected = Self::default();
+            core::Subscriber::on_error(&mut rejected, flow::Error::AlreadySubscribed).await;
+            rejected.subscribe(subscriber).await;
+            return;
+        }
+        subscriber
+            .on_subscribe(UnicastProcessorSubscription {
+                data: self.data.clone(),
+            })
+            .await;
+        *self.data.subscriber.lock().await = Some(Box::new(subscriber));
+        drain(&self.data).await;
+    }
+}
+
+pub struct UnicastProcessorSubscription<Upstream, Item, Error> {
+    data: Arc<Data<Upstream, Item, Error>>,
+}
+
+#[async_trait]
+impl<Upstream, Item, Error> core::Subscription
+    for UnicastProcessorSubscription<Upstream, Item, Error>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.data.state.lock().await.is_cancelled = true;
+        if let Some(upstream) = self.data.upstream.lock().await.as_ref() {
+            upstream.cancel().await;
+        }
+        drain(&self.data).await;
+        terminate(&self.data).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.state.lock().await.is_cancelled
+    }
+
+    async fn request(&self, count: usize) {
+        {
+            let mut state = self.data.state.lock().await;
+            state.requested = state.requested.saturating_add(count);
+        }
+        drain(&self.data).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnicastProcessor;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn buffer_until_requested() {
+        let processor = UnicastProcessor::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow.clone().subscribe(processor.clone()).await;
+        test_flow.emit_all(vec![0, 1, 2]).await;
+
+        let test_subscriber = TestSubscriber::new(1);
+        processor.subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        test_subscriber.request_direct(2).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let processor = UnicastProcessor::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow.clone().subscribe(processor.clone()).await;
+
+        let mut test_subscriber = TestSubscriber::new(1);
+        processor.subscribe(test_subscriber.clone()).await;
+        test_flow.emit(0).await;
+        test_subscriber.cancel().await;
+        test_flow.emit(1).await;
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Cancelled);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+}
@@ -121,6 +121,14 @@ impl<T> DelaySender<T> {
     ) -> Result<(), <Self as Sink<T>>::Error> {
         self.sender.send(Delayed::with_delay(t, delay)).await
     }
+
+    pub async fn send_at(
+        &mut self,
+        start_time: Instant,
+        t: T,
+    ) -> Result<(), <Self as Sink<T>>::Error> {
+        self.sender.send(Delayed::with_start_time(t, start_time)).await
+    }
 }
 
 impl<T> Sink<T> for DelaySender<T> {
@@ -203,34 +211,60 @@ impl<T> Data<T> {
     }
 
     fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
-        self.drain_channel();
-        if let Ok(task) = self.try_pop() {
-            return Poll::Ready(task);
-        }
-        if self.delay.is_some() {
-            match self.delay.as_mut().unwrap().poll_unpin(cx) {
-                Poll::Pending => return Poll::Pending,
-                Poll::Ready(()) => self.delay = None,
+        loop {
+            self.drain_channel();
+            if let Ok(task) = self.try_pop() {
+                return Poll::Ready(task);
             }
-        }
-        if self.queue.is_empty() {
-            if self.receiver.is_some() {
-                match self.receiver.as_mut().unwrap().poll_next_unpin(cx) {
-                    Poll::Ready(task) => self.push(task),
-                    Poll::Pending => return Poll::Pending,
+            if self.delay.is_some() {
+                match self.delay.as_mut().unwrap().poll_unpin(cx) {
+                    Poll::Pending => {
+                        if self.poll_receiver(cx) {
+                            continue;
+                        }
+                        return Poll::Pending;
+                    }
+                    Poll::Ready(()) => self.delay = None,
                 }
-            } else {
-                panic! {"DelayReceiver<T>::poll_next called after Poll::Ready(None) was returned"}
             }
+            if self.queue.is_empty() {
+                if self.receiver.is_some() {
+                    match self.receiver.as_mut().unwrap().poll_next_unpin(cx) {
+                        Poll::Ready(task) => self.push(task),
+                        Poll::Pending => return Poll::Pending,
+                    }
+                } else {
+                    panic! {"DelayReceiver<T>::poll_next called after Poll::Ready(None) was returned"}
+                }
+            }
+            debug_assert! {!self.queue.is_empty()}
+            match self.drain_queue(cx) {
+                Poll::Pending if self.poll_receiver(cx) => continue,
+                poll => return poll,
+            }
+        }
+    }
+
+    /// Moves a task that was sent while waiting for a delay into the queue,
+    /// as it may be due before the one that is waited for. Otherwise the
+    /// current task is woken up once the next one is sent.
+    fn poll_receiver(&mut self, cx: &mut Context<'_>) -> bool {
+        match self.receiver.as_mut().map(|receiver| receiver.poll_next_unpin(cx)) {
+            Some(Poll::Ready(task)) => {
+                self.push(task);
+                self.delay = None;
+                true
+            }
+            _ => false,
         }
-        debug_assert! {!self.queue.is_empty()}
-        self.drain_queue(cx)
     }
 
     fn drain_channel(&mut self) {
         while let Some(receiver) = &mut self.receiver {
             if let Ok(t) = receiver.try_next() {
-                self.push(t)
+                self.push(t);
+                // The new task may be due before the one that is waited for.
+                self.delay = None;
             } else {
                 break;
             }
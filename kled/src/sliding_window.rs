@@ -0,0 +1,131 @@
+use std::fmt;
+use std::ops::Index;
+#[chronobreak]
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+type Chunk<Item> = Arc<[OnceLock<Item>]>;
+
+fn chunk<Item>(count: usize) -> Chunk<Item> {
+    (0..count).map(|_| OnceLock::new()).collect()
+}
+
+/// A window of the last items emitted by the `sliding` operators.
+///
+/// Each item is stored once in a chunk of `count` slots which is shared by all
+/// windows that contain it, so creating or cloning a window never copies the
+/// items. A window spans the tail of one chunk starting at `offset`, followed
+/// by the head of the next one.
+pub struct SlidingWindow<Item> {
+    first: Chunk<Item>,
+    second: Option<Chunk<Item>>,
+    offset: usize,
+}
+
+impl<Item> SlidingWindow<Item> {
+    pub fn len(&self) -> usize {
+        self.first.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Item> {
+        let index = self.offset + index;
+        if index < self.first.len() {
+            self.first[index].get()
+        } else {
+            self.second.as_ref()?.get(index - self.first.len())?.get()
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        (0..self.len()).filter_map(move |index| self.get(index))
+    }
+
+    pub fn to_vec(&self) -> Vec<Item>
+    where
+        Item: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<Item> Clone for SlidingWindow<Item> {
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first.clone(),
+            second: self.second.clone(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<Item> Index<usize> for SlidingWindow<Item> {
+    type Output = Item;
+
+    fn index(&self, index: usize) -> &Item {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            ),
+        }
+    }
+}
+
+impl<Item> fmt::Debug for SlidingWindow<Item>
+where
+    Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// The chunks the items of the `sliding` operators are moved into. Only the
+/// current and the previous chunk are kept, older ones are dropped as soon as
+/// no window refers to them anymore.
+pub(crate) struct SlidingBuffer<Item> {
+    previous: Option<Chunk<Item>>,
+    current: Chunk<Item>,
+    len: usize,
+}
+
+impl<Item> SlidingBuffer<Item> {
+    pub(crate) fn new(count: usize) -> Self {
+        Self {
+            previous: None,
+            current: chunk(count),
+            len: 0,
+        }
+    }
+
+    /// Adds `item` and returns the window of the last `count` items, once
+    /// that many were added.
+    pub(crate) fn push(&mut self, item: Item) -> Option<SlidingWindow<Item>> {
+        if self.len == self.current.len() {
+            let next = chunk(self.current.len());
+            self.previous = Some(std::mem::replace(&mut self.current, next));
+            self.len = 0;
+        }
+        let _ = self.current[self.len].set(item);
+        self.len += 1;
+        if self.len == self.current.len() {
+            Some(SlidingWindow {
+                first: self.current.clone(),
+                second: None,
+                offset: 0,
+            })
+        } else {
+            self.previous.as_ref().map(|previous| SlidingWindow {
+                first: previous.clone(),
+                second: Some(self.current.clone()),
+                offset: self.len,
+            })
+        }
+    }
+}
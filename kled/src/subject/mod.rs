@@ -1,3 +1,4 @@
 reexport_all! {
     mod publish_subject;
+    mod unicast_subject;
 }
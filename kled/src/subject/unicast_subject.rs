@@ -0,0 +1,246 @@
+use crate::cancellable::*;
+use crate::core;
+use crate::observable;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::Arc;
+
+/// A subject which accepts exactly one observer and buffers all signals it
+/// receives until that observer subscribes.
+pub struct UnicastSubject<Cancellable, Item, Error> {
+    data: Arc<Mutex<Data<Cancellable, Item, Error>>>,
+}
+
+type OnTerminate = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+struct Data<Cancellable, Item, Error> {
+    cancellable: Option<Cancellable>,
+    emitter: Option<observable::BoxEmitter<Item, Error>>,
+    queue: VecDeque<Item>,
+    error: Option<Error>,
+    on_terminate: Option<OnTerminate>,
+    is_completed: bool,
+}
+
+impl<Cancellable, Item, Error> Data<Cancellable, Item, Error> {
+    /// Takes the termination callback once the observer cancelled, which is
+    /// only noticed on the next signal.
+    fn take_on_cancelled(&mut self) -> Option<OnTerminate> {
+        match self.emitter {
+            Some(ref emitter) if emitter.is_cancelled() => self.on_terminate.take(),
+            _ => None,
+        }
+    }
+}
+
+impl<Cancellable, Item, Error> Default for UnicastSubject<Cancellable, Item, Error> {
+    fn default() -> Self {
+        Self::with_terminate_fn(None)
+    }
+}
+
+impl<Cancellable, Item, Error> UnicastSubject<Cancellable, Item, Error> {
+    /// Creates a subject which calls `on_terminate` once it either received a
+    /// terminal signal or noticed that its observer cancelled, whichever
+    /// happens first.
+    pub fn with_on_terminate<OnTerminateFn>(on_terminate: OnTerminateFn) -> Self
+    where
+        OnTerminateFn: FnOnce() -> BoxFuture<'static, ()> + Send + 'static,
+    {
+        Self::with_terminate_fn(Some(Box::new(on_terminate)))
+    }
+
+    fn with_terminate_fn(on_terminate: Option<OnTerminate>) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data {
+                cancellable: None,
+                emitter: None,
+                queue: VecDeque::new(),
+                error: None,
+                on_terminate,
+                is_completed: false,
+            })),
+        }
+    }
+}
+
+impl<Cancellable, Item, Error> Clone for UnicastSubject<Cancellable, Item, Error> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<Cancellable, Item, Error> UnicastSubject<Cancellable, Item, Error> {
+    pub async fn has_observer(&self) -> bool {
+        self.data.lock().await.emitter.is_some()
+    }
+
+    pub async fn is_cancelled(&self) -> bool {
+        match self.data.lock().await.emitter {
+            Some(ref emitter) => emitter.is_cancelled(),
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Observer<Cancellable, Item, Error>
+    for UnicastSubject<Cancellable, Item, Error>
+where
+    Cancellable: Send,
+    Item: Send,
+    Error: Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.data.lock().await.cancellable = Some(cancellable);
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let on_terminate = {
+            let mut data = self.data.lock().await;
+            match data.emitter {
+                Some(ref mut emitter) => {
+                    if !emitter.is_cancelled() {
+                        emitter.on_next(item).await
+                    }
+                }
+                None => data.queue.push_back(item),
+            }
+            data.take_on_cancelled()
+        };
+        if let Some(on_terminate) = on_terminate {
+            on_terminate().await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        let on_terminate = {
+            let mut data = self.data.lock().await;
+            match data.emitter {
+                Some(ref mut emitter) => {
+                    if !emitter.is_cancelled() {
+                        emitter.on_error(error).await
+                    }
+                }
+                None => data.error = Some(error),
+            }
+            data.on_terminate.take()
+        };
+        if let Some(on_terminate) = on_terminate {
+            on_terminate().await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        let on_terminate = {
+            let mut data = self.data.lock().await;
+            match data.emitter {
+                Some(ref mut emitter) => {
+                    if !emitter.is_cancelled() {
+                        emitter.on_completed().await
+                    }
+                }
+                None => data.is_completed = true,
+            }
+            data.on_terminate.take()
+        };
+        if let Some(on_terminate) = on_terminate {
+            on_terminate().await;
+        }
+    }
+}
+
+impl<Cancellable, Item, Error> core::Subject<Cancellable, ArcCancellable, Item, Error>
+    for UnicastSubject<Cancellable, Item, Error>
+where
+    Cancellable: Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Observable<ArcCancellable, Item, Error>
+    for UnicastSubject<Cancellable, Item, Error>
+where
+    Cancellable: Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<ArcCancellable, Item, Error> + Send + 'static,
+    {
+        let mut data = self.data.lock().await;
+        assert!(
+            data.emitter.is_none(),
+            "UnicastSubject::subscribe: only a single observer may subscribe"
+        );
+        let mut emitter = observable::BoxEmitter::from(observer).await;
+        while let Some(item) = data.queue.pop_front() {
+            if emitter.is_cancelled() {
+                data.queue.clear();
+                break;
+            }
+            emitter.on_next(item).await;
+        }
+        if !emitter.is_cancelled() {
+            if let Some(error) = data.error.take() {
+                emitter.on_error(error).await;
+            } else if data.is_completed {
+                emitter.on_completed().await;
+            }
+        }
+        data.emitter = Some(emitter);
+        let on_terminate = data.take_on_cancelled();
+        drop(data);
+        if let Some(on_terminate) = on_terminate {
+            on_terminate().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnicastSubject;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn buffer_until_subscribed() {
+        let subject = UnicastSubject::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable.clone().subscribe(subject.clone()).await;
+        test_observable.emit_all(vec![0, 1]).await;
+
+        let test_observer = TestObserver::default();
+        subject.subscribe(test_observer.clone()).await;
+        test_observable.emit(2).await;
+        test_observable.emit_on_completed().await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn buffered_error() {
+        let subject = UnicastSubject::default();
+        let test_observable = TestObservable::default();
+        test_observable.clone().subscribe(subject.clone()).await;
+        test_observable.emit(0).await;
+        test_observable.emit_error(1).await;
+
+        let test_observer = TestObserver::default();
+        subject.subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await, vec![0]);
+        assert_eq!(test_observer.error().await, Some(1));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+use futures::channel::oneshot;
+
+/// A subscriber which folds all items into a single value and sends the
+/// result to the given channel once the flow terminates. Items are requested
+/// in batches of `batch_size`.
+pub struct FoldSubscriber<Subscription, Acc, BinaryOp, Error> {
+    subscription: Option<Subscription>,
+    batch_size: usize,
+    received: usize,
+    value: Option<Acc>,
+    binary_op: BinaryOp,
+    sender: Option<oneshot::Sender<Result<Acc, flow::Error<Error>>>>,
+}
+
+impl<Subscription, Acc, BinaryOp, Error> FoldSubscriber<Subscription, Acc, BinaryOp, Error> {
+    pub fn new(
+        initial_value: Acc,
+        binary_op: BinaryOp,
+        batch_size: usize,
+        sender: oneshot::Sender<Result<Acc, flow::Error<Error>>>,
+    ) -> Self {
+        assert!(
+            batch_size > 0,
+            "FoldSubscriber::new: batch_size must be greater than zero"
+        );
+        Self {
+            subscription: None,
+            batch_size,
+            received: 0,
+            value: Some(initial_value),
+            binary_op,
+            sender: Some(sender),
+        }
+    }
+
+    fn send(&mut self, result: Result<Acc, flow::Error<Error>>) {
+        if let Some(sender) = self.sender.take() {
+            // The receiver may already be gone, in which case nobody is
+            // interested in the result anymore.
+            let _ = sender.send(result);
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Acc, BinaryOp> core::Subscriber<Subscription, Item, Error>
+    for FoldSubscriber<Subscription, Acc, BinaryOp, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Acc: Send,
+    BinaryOp: FnMut(Acc, Item) -> Acc + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        subscription.request(self.batch_size).await;
+        self.subscription = Some(subscription);
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(value) = self.value.take() {
+            self.value = Some((self.binary_op)(value, item));
+        }
+        if self.batch_size != usize::MAX {
+            self.received += 1;
+            if self.received == self.batch_size {
+                self.received = 0;
+                if let Some(subscription) = self.subscription.as_ref() {
+                    subscription.request(self.batch_size).await;
+                }
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.value = None;
+        self.send(Err(error));
+    }
+
+    async fn on_completed(&mut self) {
+        if let Some(value) = self.value.take() {
+            self.send(Ok(value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::{self, *};
+    use crate::prelude::*;
+    use std::collections::HashMap;
+
+    #[async_std::test]
+    async fn fold() {
+        let result = vec![0, 1, 2, 3].into_flow().fold(10, |a, b| a + b).await;
+        assert_eq!(result, Ok(16));
+    }
+
+    #[async_std::test]
+    async fn fold_with_batch_size() {
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let result = async_std::task::spawn(test_flow.clone().fold_with_batch_size(
+            vec![],
+            |mut items, item| {
+                items.push(item);
+                items
+            },
+            2,
+        ));
+        while !test_flow.has_observer().await {
+            async_std::task::yield_now().await;
+        }
+        assert_eq!(test_flow.requested().await, 2);
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        assert_eq!(test_flow.requested().await, 4);
+        test_flow.emit_completed().await;
+        assert_eq!(result.await, Ok(vec![0, 1, 2]));
+    }
+
+    #[async_std::test]
+    async fn reduce() {
+        let result = vec![1, 2, 3].into_flow().reduce(|a, b| a * b).await;
+        assert_eq!(result, Ok(Some(6)));
+    }
+
+    #[async_std::test]
+    async fn collect() {
+        let result: Result<String, _> = vec!['a', 'b', 'c'].into_flow().collect().await;
+        assert_eq!(result, Ok("abc".to_owned()));
+        let result = vec![0, 1].into_flow().to_vec().await;
+        assert_eq!(result, Ok(vec![0, 1]));
+    }
+
+    #[async_std::test]
+    async fn to_map() {
+        let result = vec![1, 2, 3, 4].into_flow().to_map(|item| item % 2).await;
+        let expected: HashMap<_, _> = vec![(0, 4), (1, 3)].into_iter().collect();
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[async_std::test]
+    async fn error() {
+        let test_flow = TestFlow::default().annotate_item_type(0);
+        let result = async_std::task::spawn(test_flow.clone().to_vec());
+        while !test_flow.has_observer().await {
+            async_std::task::yield_now().await;
+        }
+        test_flow.emit(0).await;
+        test_flow.emit_error(1).await;
+        assert_eq!(result.await, Err(flow::Error::Upstream(1)));
+    }
+
+    #[async_std::test]
+    async fn disconnected() {
+        let result: Result<Vec<i32>, flow::Error<()>> = flow::create(drop).to_vec().await;
+        assert_eq!(result, Err(flow::Error::Disconnected));
+    }
+
+    #[async_std::test]
+    async fn to_vec_with_batch_size() {
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let result = async_std::task::spawn(test_flow.clone().to_vec_with_batch_size(2));
+        while !test_flow.has_observer().await {
+            async_std::task::yield_now().await;
+        }
+        assert_eq!(test_flow.requested().await, 2);
+        test_flow.emit_all(vec![0, 1]).await;
+        assert_eq!(test_flow.requested().await, 4);
+        test_flow.emit_completed().await;
+        assert_eq!(result.await, Ok(vec![0, 1]));
+    }
+}
@@ -1,4 +1,5 @@
 reexport_all! {
+    mod fold_subscriber;
     mod lambda_subscriber;
     mod scheduled_subscriber;
     mod test_subscriber;
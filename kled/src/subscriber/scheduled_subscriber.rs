@@ -29,6 +29,9 @@ impl<Subscription, Item, Error>
             while let Some(signal) = receiver.next().await {
                 is_error = signal.is_error();
                 subscriber.on_next(signal).await;
+                if is_error {
+                    break;
+                }
             }
             if !is_error {
                 subscriber.on_completed().await;
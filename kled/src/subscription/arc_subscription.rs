@@ -31,6 +31,10 @@ impl ArcSubscriptionStub {
         self.data.requested.swap(0, Ordering::Relaxed)
     }
 
+    pub fn requested(&self) -> usize {
+        self.data.requested.load(Ordering::Relaxed)
+    }
+
     pub fn is_cancelled(&self) -> bool {
         self.data.cancelled.load(Ordering::Relaxed)
     }
@@ -27,13 +27,24 @@ where
     }
 
     pub async fn set_subscription(&mut self, subscription: Subscription) {
+        let subscription = Arc::new(subscription);
         let mut data = self.data.lock().await;
-        if data.cancelled {
+        data.subscription = Some(subscription.clone());
+        let cancelled = data.cancelled;
+        let requested = data.requested;
+        // The lock must not be held while calling into the subscription, as it
+        // may synchronously emit items which in turn issue further requests.
+        drop(data);
+        if cancelled {
             subscription.cancel().await;
-        } else {
-            subscription.request(data.requested).await;
+        } else if requested > 0 {
+            subscription.request(requested).await;
         }
-        data.subscription = Some(subscription);
+    }
+
+    /// Whether the subscription was cancelled before it was set.
+    pub async fn is_cancelled(&self) -> bool {
+        self.data.lock().await.cancelled
     }
 }
 
@@ -48,29 +59,37 @@ where
     Subscription: core::Subscription + Send + Sync,
 {
     async fn cancel(&self) {
-        let mut data = self.data.lock().await;
-        if let Some(subscription) = &data.subscription {
-            subscription.cancel().await;
-        } else {
+        let subscription = {
+            let mut data = self.data.lock().await;
             data.cancelled = true;
+            data.subscription.clone()
+        };
+        if let Some(subscription) = subscription {
+            subscription.cancel().await;
         }
     }
 
     async fn is_cancelled(&self) -> bool {
-        let data = self.data.lock().await;
-        if let Some(subscription) = &data.subscription {
-            subscription.is_cancelled().await
-        } else {
-            data.cancelled
+        let (subscription, cancelled) = {
+            let data = self.data.lock().await;
+            (data.subscription.clone(), data.cancelled)
+        };
+        match subscription {
+            Some(subscription) => subscription.is_cancelled().await,
+            None => cancelled,
         }
     }
 
     async fn request(&self, count: usize) {
-        let mut data = self.data.lock().await;
-        if let Some(subscription) = &data.subscription {
+        let subscription = {
+            let mut data = self.data.lock().await;
+            if data.subscription.is_none() {
+                data.requested = data.requested.saturating_add(count);
+            }
+            data.subscription.clone()
+        };
+        if let Some(subscription) = subscription {
             subscription.request(count).await;
-        } else {
-            data.requested += count;
         }
     }
 }
@@ -78,7 +97,7 @@ where
 struct Data<Subscription> {
     cancelled: bool,
     requested: usize,
-    subscription: Option<Subscription>,
+    subscription: Option<Arc<Subscription>>,
 }
 
 impl<Subscription> Default for Data<Subscription> {